@@ -1,15 +1,20 @@
 #define _CRT_SECURE_NO_WARNINGS
 #include <tensorflow/c/c_api.h>
+#include <tensorflow/c/eager/c_api.h>
 #include <string>
 #include <cstring>
+#include <stdexcept>
+#include <atomic>
+#include <cstdarg>
+#include <sstream>
 #include <torch/torch.h>
 #include <torch/script.h>
 #include <ATen/ATen.h>
 #include <ATen/Tensor.h>
 #include <vector>
+#include <memory>
 #include <numeric>
-
-extern "C" {
+#include <limits>
 
 #if defined(_WIN32)
   #define EXPORT __declspec(dllexport)
@@ -19,6 +24,350 @@ extern "C" {
   #define EXPORT
 #endif
 
+// ==================== Logging ====================
+// O shim não escreve em stdout/stderr. As mensagens são entregues a um callback
+// registrado pelo Rust (CopperSetLogCallback), que as repassa para o crate `log`.
+// Sem callback registrado o shim fica em silêncio.
+enum CopperLogLevel {
+    COPPER_LOG_TRACE = 0,
+    COPPER_LOG_DEBUG = 1,
+    COPPER_LOG_INFO = 2,
+    COPPER_LOG_WARN = 3,
+    COPPER_LOG_ERROR = 4,
+    COPPER_LOG_OFF = 5,
+};
+
+typedef void (*CopperLogCallback)(int level, const char* message);
+
+namespace {
+
+std::atomic<CopperLogCallback> log_callback{nullptr};
+std::atomic<int> log_level{COPPER_LOG_OFF};
+
+bool log_enabled(int level) {
+    return level >= log_level.load(std::memory_order_relaxed)
+        && log_callback.load(std::memory_order_relaxed) != nullptr;
+}
+
+void copper_log(int level, const char* format, ...) {
+    CopperLogCallback callback = log_callback.load();
+    if (!callback) {
+        return;
+    }
+    char buffer[1024];
+    va_list args;
+    va_start(args, format);
+    vsnprintf(buffer, sizeof(buffer), format, args);
+    va_end(args);
+    callback(level, buffer);
+}
+
+std::string shape_string(const at::Tensor& tensor) {
+    std::ostringstream out;
+    out << tensor.sizes();
+    return out.str();
+}
+
+} // namespace
+
+// Só formata a mensagem quando o nível está habilitado
+#define COPPER_LOG(level, ...) \
+    do { if (log_enabled(level)) copper_log(level, __VA_ARGS__); } while (0)
+
+// ==================== Error Handling ====================
+// Cada thread guarda a mensagem do último erro ocorrido no shim. O lado Rust
+// lê essa mensagem (CopperLastError) quando uma chamada devolve nullptr, em
+// vez de depender do que foi impresso no stderr.
+namespace {
+
+thread_local std::string last_error;
+thread_local bool has_error = false;
+
+void set_last_error(const char* where, const std::string& message) {
+    last_error = std::string(where) + ": " + message;
+    has_error = true;
+    COPPER_LOG(COPPER_LOG_ERROR, "%s", last_error.c_str());
+}
+
+template <typename F>
+void record_exception(const char* where, F&& rethrow) {
+    try {
+        rethrow();
+    } catch (const c10::Error& e) {
+        set_last_error(where, e.what_without_backtrace());
+    } catch (const std::exception& e) {
+        set_last_error(where, e.what());
+    } catch (...) {
+        set_last_error(where, "exceção C++ desconhecida");
+    }
+}
+
+// Executa `body` e converte qualquer exceção em last_error + nullptr.
+template <typename F>
+void* guard_ptr(const char* where, F&& body) {
+    try {
+        return body();
+    } catch (...) {
+        record_exception(where, [] { throw; });
+        return nullptr;
+    }
+}
+
+// Igual a guard_ptr, para funções que devolvem um valor escalar.
+template <typename T, typename F>
+T guard_value(const char* where, T fallback, F&& body) {
+    try {
+        return body();
+    } catch (...) {
+        record_exception(where, [] { throw; });
+        return fallback;
+    }
+}
+
+template <typename F>
+void guard_void(const char* where, F&& body) {
+    try {
+        body();
+    } catch (...) {
+        record_exception(where, [] { throw; });
+    }
+}
+
+at::Tensor& tensor_ref(void* ptr, const char* name) {
+    if (!ptr) {
+        throw std::invalid_argument(std::string("ponteiro de tensor inválido (") + name + ")");
+    }
+    return *static_cast<at::Tensor*>(ptr);
+}
+
+void* wrap_tensor(at::Tensor tensor) {
+    return static_cast<void*>(new at::Tensor(std::move(tensor)));
+}
+
+void check_dims(int rows, int cols) {
+    if (rows <= 0 || cols <= 0) {
+        throw std::invalid_argument("dimensões inválidas: " + std::to_string(rows) + "x" + std::to_string(cols));
+    }
+}
+
+// Converte o par (dims, ndim) recebido do Rust em uma forma do ATen.
+// ndim == 0 representa um tensor escalar (0-D).
+at::IntArrayRef shape_ref(const int64_t* dims, int ndim) {
+    if (ndim < 0 || (ndim > 0 && !dims)) {
+        throw std::invalid_argument("forma inválida (ndim = " + std::to_string(ndim) + ")");
+    }
+    for (int i = 0; i < ndim; ++i) {
+        if (dims[i] < 0) {
+            throw std::invalid_argument("dimensão negativa na posição " + std::to_string(i));
+        }
+    }
+    return at::IntArrayRef(dims, static_cast<size_t>(ndim));
+}
+
+// Códigos de dtype compartilhados com o enum DType do Rust (src/dtype.rs)
+enum CopperDType {
+    COPPER_FLOAT32 = 0,
+    COPPER_FLOAT64 = 1,
+    COPPER_INT64 = 2,
+    COPPER_INT32 = 3,
+    COPPER_UINT8 = 4,
+    COPPER_BOOL = 5,
+};
+
+at::ScalarType scalar_type(int dtype) {
+    switch (dtype) {
+        case COPPER_FLOAT32: return at::kFloat;
+        case COPPER_FLOAT64: return at::kDouble;
+        case COPPER_INT64: return at::kLong;
+        case COPPER_INT32: return at::kInt;
+        case COPPER_UINT8: return at::kByte;
+        case COPPER_BOOL: return at::kBool;
+    }
+    throw std::invalid_argument("dtype desconhecido: " + std::to_string(dtype));
+}
+
+int dtype_code(at::ScalarType type) {
+    switch (type) {
+        case at::kFloat: return COPPER_FLOAT32;
+        case at::kDouble: return COPPER_FLOAT64;
+        case at::kLong: return COPPER_INT64;
+        case at::kInt: return COPPER_INT32;
+        case at::kByte: return COPPER_UINT8;
+        case at::kBool: return COPPER_BOOL;
+        default: break;
+    }
+    throw std::invalid_argument(std::string("dtype não suportado: ") + c10::toString(type));
+}
+
+TF_DataType tf_data_type(int dtype) {
+    switch (dtype) {
+        case COPPER_FLOAT32: return TF_FLOAT;
+        case COPPER_FLOAT64: return TF_DOUBLE;
+        case COPPER_INT64: return TF_INT64;
+        case COPPER_INT32: return TF_INT32;
+        case COPPER_UINT8: return TF_UINT8;
+        case COPPER_BOOL: return TF_BOOL;
+    }
+    throw std::invalid_argument("dtype desconhecido: " + std::to_string(dtype));
+}
+
+int tf_dtype_code(TF_DataType type) {
+    switch (type) {
+        case TF_FLOAT: return COPPER_FLOAT32;
+        case TF_DOUBLE: return COPPER_FLOAT64;
+        case TF_INT64: return COPPER_INT64;
+        case TF_INT32: return COPPER_INT32;
+        case TF_UINT8: return COPPER_UINT8;
+        case TF_BOOL: return COPPER_BOOL;
+        default: break;
+    }
+    throw std::invalid_argument("TF_DataType não suportado: " + std::to_string(static_cast<int>(type)));
+}
+
+} // namespace
+
+// ==================== TensorFlow Eager ====================
+// As operações sobre FlowTensors são executadas como ops eager (TFE_*) em um
+// contexto compartilhado, sem precisar montar um grafo.
+
+// Atributo de uma op eager; `kind` indica qual campo é usado
+enum CopperTFAttrKind {
+    COPPER_ATTR_INT = 0,
+    COPPER_ATTR_FLOAT = 1,
+    COPPER_ATTR_BOOL = 2,
+    COPPER_ATTR_TYPE = 3,  // `i` contém um código CopperDType
+};
+
+struct CopperTFAttr {
+    const char* name;
+    int kind;
+    int64_t i;
+    float f;
+};
+
+namespace {
+
+using StatusPtr = std::unique_ptr<TF_Status, decltype(&TF_DeleteStatus)>;
+using HandlePtr = std::unique_ptr<TFE_TensorHandle, decltype(&TFE_DeleteTensorHandle)>;
+using OpPtr = std::unique_ptr<TFE_Op, decltype(&TFE_DeleteOp)>;
+
+StatusPtr new_status() {
+    return StatusPtr(TF_NewStatus(), TF_DeleteStatus);
+}
+
+void check_status(TF_Status* status, const std::string& what) {
+    if (TF_GetCode(status) != TF_OK) {
+        throw std::runtime_error(what + ": " + TF_Message(status));
+    }
+}
+
+TFE_Context* eager_context() {
+    static TFE_Context* context = [] {
+        StatusPtr status = new_status();
+        TFE_ContextOptions* options = TFE_NewContextOptions();
+        TFE_Context* ctx = TFE_NewContext(options, status.get());
+        TFE_DeleteContextOptions(options);
+        check_status(status.get(), "falha ao criar contexto eager");
+        return ctx;
+    }();
+    return context;
+}
+
+TF_Tensor* tf_tensor_ref(void* ptr, const char* name) {
+    if (!ptr) {
+        throw std::invalid_argument(std::string("ponteiro de TF_Tensor inválido (") + name + ")");
+    }
+    return static_cast<TF_Tensor*>(ptr);
+}
+
+HandlePtr tensor_handle(TF_Tensor* tensor) {
+    StatusPtr status = new_status();
+    HandlePtr handle(TFE_NewTensorHandle(tensor, status.get()), TFE_DeleteTensorHandle);
+    check_status(status.get(), "TFE_NewTensorHandle");
+    return handle;
+}
+
+OpPtr new_op(const char* op_name) {
+    if (!op_name) {
+        throw std::invalid_argument("nome de op nulo");
+    }
+    StatusPtr status = new_status();
+    OpPtr op(TFE_NewOp(eager_context(), op_name, status.get()), TFE_DeleteOp);
+    check_status(status.get(), std::string("TFE_NewOp(") + op_name + ")");
+    return op;
+}
+
+void set_attrs(TFE_Op* op, const CopperTFAttr* attrs, int num_attrs) {
+    for (int i = 0; i < num_attrs; ++i) {
+        const CopperTFAttr& attr = attrs[i];
+        switch (attr.kind) {
+            case COPPER_ATTR_INT: TFE_OpSetAttrInt(op, attr.name, attr.i); break;
+            case COPPER_ATTR_FLOAT: TFE_OpSetAttrFloat(op, attr.name, attr.f); break;
+            case COPPER_ATTR_BOOL: TFE_OpSetAttrBool(op, attr.name, attr.i != 0); break;
+            case COPPER_ATTR_TYPE:
+                TFE_OpSetAttrType(op, attr.name, tf_data_type(static_cast<int>(attr.i)));
+                break;
+            default:
+                throw std::invalid_argument(std::string("tipo de atributo inválido para ") + attr.name);
+        }
+    }
+}
+
+// Executa a op e resolve as saídas em novos TF_Tensor* (posse passa ao chamador)
+void execute_op(TFE_Op* op, const char* op_name, void** outputs, int num_outputs) {
+    StatusPtr status = new_status();
+    std::vector<TFE_TensorHandle*> retvals(num_outputs, nullptr);
+    int num_retvals = num_outputs;
+    TFE_Execute(op, retvals.data(), &num_retvals, status.get());
+    check_status(status.get(), op_name);
+
+    std::vector<HandlePtr> handles;
+    for (int i = 0; i < num_retvals; ++i) {
+        handles.emplace_back(retvals[i], TFE_DeleteTensorHandle);
+    }
+    if (num_retvals != num_outputs) {
+        throw std::runtime_error(std::string(op_name) + " devolveu " + std::to_string(num_retvals)
+                                 + " saídas, esperado " + std::to_string(num_outputs));
+    }
+    for (int i = 0; i < num_outputs; ++i) {
+        outputs[i] = TFE_TensorHandleResolve(handles[i].get(), status.get());
+        if (TF_GetCode(status.get()) != TF_OK) {
+            for (int j = 0; j < i; ++j) {
+                TF_DeleteTensor(static_cast<TF_Tensor*>(outputs[j]));
+                outputs[j] = nullptr;
+            }
+            check_status(status.get(), "TFE_TensorHandleResolve");
+        }
+    }
+}
+
+} // namespace
+
+extern "C" {
+
+EXPORT const char* CopperLastError() {
+    return has_error ? last_error.c_str() : nullptr;
+}
+
+EXPORT void CopperClearError() {
+    last_error.clear();
+    has_error = false;
+}
+
+// Usado pelos callbacks Rust (Function::forward/backward) para reportar falhas
+EXPORT void CopperSetError(const char* message) {
+    last_error = message ? message : "erro desconhecido";
+    has_error = true;
+}
+
+EXPORT void CopperSetLogCallback(CopperLogCallback callback) {
+    log_callback.store(callback);
+}
+
+EXPORT void CopperSetLogLevel(int level) {
+    log_level.store(level);
+}
+
 
 struct ModelHandle {
     TF_Session* session;
@@ -26,12 +375,12 @@ struct ModelHandle {
 };
 
 // TensorFlow C API functions
-EXPORT const char* VersionTF() { 
+EXPORT const char* VersionTF() {
     return TF_Version();
 }
 
 EXPORT void* LoadSavedModel(const char* model_path, const char* tags) {
-    try {
+    return guard_ptr("LoadSavedModel", [&]() -> void* {
         TF_Status* status = TF_NewStatus();
         TF_SessionOptions* session_opts = TF_NewSessionOptions();
         TF_Graph* graph = TF_NewGraph();
@@ -41,11 +390,11 @@ EXPORT void* LoadSavedModel(const char* model_path, const char* tags) {
             session_opts, nullptr, model_path, tag_array, 1, graph, nullptr, status);
 
         if (TF_GetCode(status) != TF_OK) {
-            fprintf(stderr, "Erro ao carregar SavedModel: %s\n", TF_Message(status));
+            std::string message = std::string("erro ao carregar SavedModel: ") + TF_Message(status);
             TF_DeleteStatus(status);
             TF_DeleteSessionOptions(session_opts);
             TF_DeleteGraph(graph);
-            return nullptr;
+            throw std::runtime_error(message);
         }
 
         TF_DeleteStatus(status);
@@ -53,37 +402,32 @@ EXPORT void* LoadSavedModel(const char* model_path, const char* tags) {
 
         ModelHandle* handle = new ModelHandle{session, graph};
         return static_cast<void*>(handle);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em LoadSavedModel: %s\n", e.what());
-        return nullptr;
-    }
+    });
 }
 
 EXPORT void* RunSession(void* model_handle, const char** input_names, void** input_tensors, int num_inputs,
                         const char** output_names, void** output_tensors, int num_outputs) {
-    try {
+    return guard_ptr("RunSession", [&]() -> void* {
         auto* handle = static_cast<ModelHandle*>(model_handle);
         if (!handle || !handle->session || !handle->graph) {
-            fprintf(stderr, "Erro: ModelHandle inválido\n");
-            return nullptr;
+            throw std::invalid_argument("ModelHandle inválido");
         }
         TF_Session* session = handle->session;
         TF_Graph* graph = handle->graph;
-        TF_Status* status = TF_NewStatus();
 
         std::vector<TF_Tensor*> inputs(num_inputs);
         for (int i = 0; i < num_inputs; ++i) {
             inputs[i] = static_cast<TF_Tensor*>(input_tensors[i]);
             if (!inputs[i]) {
-                fprintf(stderr, "Erro: Tensor de entrada %d inválido\n", i);
-                TF_DeleteStatus(status);
-                return nullptr;
+                throw std::invalid_argument("tensor de entrada " + std::to_string(i) + " inválido");
             }
-            // Depuração da entrada
-            int num_dims = TF_NumDims(inputs[i]);
-            printf("Entrada %d: %d dimensões\n", i, num_dims);
-            for (int j = 0; j < num_dims; ++j) {
-                printf("Dimensão %d: %lld\n", j, TF_Dim(inputs[i], j));
+            if (log_enabled(COPPER_LOG_TRACE)) {
+                std::ostringstream dims;
+                for (int j = 0; j < TF_NumDims(inputs[i]); ++j) {
+                    dims << (j ? ", " : "") << TF_Dim(inputs[i], j);
+                }
+                COPPER_LOG(COPPER_LOG_TRACE, "RunSession - entrada %d (%s): [%s]",
+                           i, input_names[i], dims.str().c_str());
             }
         }
 
@@ -91,9 +435,7 @@ EXPORT void* RunSession(void* model_handle, const char** input_names, void** inp
         for (int i = 0; i < num_inputs; ++i) {
             TF_Operation* op = TF_GraphOperationByName(graph, input_names[i]);
             if (!op) {
-                fprintf(stderr, "Erro: Operação de entrada %s não encontrada\n", input_names[i]);
-                TF_DeleteStatus(status);
-                return nullptr;
+                throw std::invalid_argument(std::string("operação de entrada não encontrada: ") + input_names[i]);
             }
             input_ops[i] = {op, 0};
         }
@@ -102,9 +444,7 @@ EXPORT void* RunSession(void* model_handle, const char** input_names, void** inp
         for (int i = 0; i < num_outputs; ++i) {
             TF_Operation* op = TF_GraphOperationByName(graph, output_names[i]);
             if (!op) {
-                fprintf(stderr, "Erro: Operação de saída %s não encontrada\n", output_names[i]);
-                TF_DeleteStatus(status);
-                return nullptr;
+                throw std::invalid_argument(std::string("operação de saída não encontrada: ") + output_names[i]);
             }
             output_ops[i] = {op, 0};
         }
@@ -114,6 +454,7 @@ EXPORT void* RunSession(void* model_handle, const char** input_names, void** inp
             output_tensors[i] = nullptr;
         }
 
+        TF_Status* status = TF_NewStatus();
         TF_SessionRun(
             session, nullptr,
             input_ops.data(), inputs.data(), num_inputs,
@@ -121,32 +462,21 @@ EXPORT void* RunSession(void* model_handle, const char** input_names, void** inp
             nullptr, 0, nullptr, status);
 
         if (TF_GetCode(status) != TF_OK) {
-            fprintf(stderr, "Erro ao executar sessão: %s\n", TF_Message(status));
+            std::string message = std::string("erro ao executar sessão: ") + TF_Message(status);
             TF_DeleteStatus(status);
-            return nullptr;
+            throw std::runtime_error(message);
         }
 
-        // Depuração da saída
         for (int i = 0; i < num_outputs; ++i) {
-            if (tf_output_tensors[i]) {
-                int num_dims = TF_NumDims(tf_output_tensors[i]);
-                printf("Saída %d: %d dimensões\n", i, num_dims);
-                for (int j = 0; j < num_dims; ++j) {
-                    printf("Dimensão %d: %lld\n", j, TF_Dim(tf_output_tensors[i], j));
+            if (!tf_output_tensors[i]) {
+                COPPER_LOG(COPPER_LOG_WARN, "RunSession - saída %d (%s) é nula", i, output_names[i]);
+            } else if (log_enabled(COPPER_LOG_TRACE)) {
+                std::ostringstream dims;
+                for (int j = 0; j < TF_NumDims(tf_output_tensors[i]); ++j) {
+                    dims << (j ? ", " : "") << TF_Dim(tf_output_tensors[i], j);
                 }
-                size_t num_elements = 1;
-                for (int j = 0; j < num_dims; ++j) {
-                    num_elements *= TF_Dim(tf_output_tensors[i], j);
-                }
-                float* data = static_cast<float*>(TF_TensorData(tf_output_tensors[i]));
-                printf("Primeiros 10 valores (máx %zu): [", num_elements);
-                for (size_t k = 0; k < std::min(num_elements, size_t(10)); ++k) {
-                    printf("%f", data[k]);
-                    if (k < std::min(num_elements, size_t(10)) - 1) printf(", ");
-                }
-                printf("]\n");
-            } else {
-                printf("Saída %d: Tensor nulo\n", i);
+                COPPER_LOG(COPPER_LOG_TRACE, "RunSession - saída %d (%s): [%s]",
+                           i, output_names[i], dims.str().c_str());
             }
         }
 
@@ -155,100 +485,131 @@ EXPORT void* RunSession(void* model_handle, const char** input_names, void** inp
         }
 
         TF_DeleteStatus(status);
-        return static_cast<void*>(tf_output_tensors.data());
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em RunSession: %s\n", e.what());
-        return nullptr;
-    }
+        return static_cast<void*>(output_tensors);
+    });
 }
 
-EXPORT void* CreateTFTensor(float* values, int64_t* dims, int num_dims) {
-    try {
-        if (!values || !dims || num_dims <= 0) {
-            fprintf(stderr, "Erro: Parâmetros inválidos em CreateTFTensor\n");
-            return nullptr;
+EXPORT void* CreateTFTensor(const void* values, const int64_t* dims, int num_dims, int dtype) {
+    return guard_ptr("CreateTFTensor", [&]() -> void* {
+        if (!values || num_dims < 0 || (num_dims > 0 && !dims)) {
+            throw std::invalid_argument("parâmetros inválidos");
         }
-        
+        TF_DataType type = tf_data_type(dtype);
+
         // Calcula o número total de elementos
         size_t num_elements = std::accumulate(dims, dims + num_dims, size_t(1), std::multiplies<size_t>());
-        size_t data_size = sizeof(float) * num_elements;
-        
+        size_t data_size = TF_DataTypeSize(type) * num_elements;
+
         // Aloca memória que será gerenciada pelo TensorFlow
-        float* tensor_data = static_cast<float*>(malloc(data_size));
+        void* tensor_data = malloc(data_size > 0 ? data_size : 1);
         if (!tensor_data) {
-            fprintf(stderr, "Erro: Falha ao alocar memória para TF_Tensor\n");
-            return nullptr;
+            throw std::bad_alloc();
         }
-        
+
         // Copia os dados do ponteiro temporário do Rust para memória própria
         memcpy(tensor_data, values, data_size);
-        
+
         // Cria tensor com deallocator que libera a memória alocada
         TF_Tensor* tensor = TF_NewTensor(
-            TF_FLOAT,
+            type,
             dims,
             num_dims,
             tensor_data,
             data_size,
             [](void* data, size_t, void*) { free(data); },
             nullptr);
-            
+
         if (!tensor) {
-            fprintf(stderr, "Erro: Falha ao criar TF_Tensor\n");
             free(tensor_data);
-            return nullptr;
+            throw std::runtime_error("falha ao criar TF_Tensor");
         }
         return static_cast<void*>(tensor);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em CreateTFTensor: %s\n", e.what());
-        return nullptr;
-    }
+    });
 }
 
-EXPORT float* GetTensorData(void* tensor_ptr) {
-    try {
+EXPORT void* GetTensorData(void* tensor_ptr) {
+    return guard_ptr("GetTensorData", [&]() -> void* {
         TF_Tensor* tensor = static_cast<TF_Tensor*>(tensor_ptr);
         if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em GetTensorData\n");
-            return nullptr;
+            throw std::invalid_argument("tensor inválido");
         }
-        
-        // Verifica se o tipo do tensor é float
-        if (TF_TensorType(tensor) != TF_FLOAT) {
-            fprintf(stderr, "Erro: Tensor não é do tipo TF_FLOAT em GetTensorData\n");
-            return nullptr;
-        }
-        
-        float* data = static_cast<float*>(TF_TensorData(tensor));
+
+        void* data = TF_TensorData(tensor);
         if (!data) {
-            fprintf(stderr, "Erro: TF_TensorData retornou nullptr\n");
-            return nullptr;
+            throw std::runtime_error("TF_TensorData retornou nullptr");
         }
-        
+
         return data;
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em GetTensorData: %s\n", e.what());
-        return nullptr;
-    }
+    });
+}
+
+EXPORT int TFTensorDType(void* tensor_ptr) {
+    return guard_value("TFTensorDType", -1, [&] {
+        TF_Tensor* tensor = static_cast<TF_Tensor*>(tensor_ptr);
+        if (!tensor) {
+            throw std::invalid_argument("tensor inválido");
+        }
+        return tf_dtype_code(TF_TensorType(tensor));
+    });
+}
+
+EXPORT int TFTensorNumDims(void* tensor_ptr) {
+    return guard_value("TFTensorNumDims", -1, [&] {
+        TF_Tensor* tensor = static_cast<TF_Tensor*>(tensor_ptr);
+        if (!tensor) {
+            throw std::invalid_argument("tensor inválido");
+        }
+        return TF_NumDims(tensor);
+    });
+}
+
+EXPORT int64_t TFTensorDim(void* tensor_ptr, int index) {
+    return guard_value<int64_t>("TFTensorDim", -1, [&] {
+        TF_Tensor* tensor = static_cast<TF_Tensor*>(tensor_ptr);
+        if (!tensor || index < 0 || index >= TF_NumDims(tensor)) {
+            throw std::invalid_argument("tensor ou índice de dimensão inválido");
+        }
+        return TF_Dim(tensor, index);
+    });
 }
 
 EXPORT void FreeTFTensor(void* tensor_ptr) {
-    try {
+    guard_void("FreeTFTensor", [&] {
         TF_Tensor* tensor = static_cast<TF_Tensor*>(tensor_ptr);
         if (tensor) {
             TF_DeleteTensor(tensor);
         }
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em FreeTFTensor: %s\n", e.what());
-    }
+    });
+}
+
+// Executa uma op eager com `num_inputs` entradas. As saídas são gravadas em
+// `outputs`; em caso de sucesso devolve `outputs`, senão nullptr.
+EXPORT void* TFExecuteOp(const char* op_name, void** inputs, int num_inputs,
+                         const CopperTFAttr* attrs, int num_attrs, void** outputs, int num_outputs) {
+    return guard_ptr("TFExecuteOp", [&]() -> void* {
+        if (num_inputs < 0 || num_attrs < 0 || num_outputs <= 0 || !outputs) {
+            throw std::invalid_argument("parâmetros inválidos");
+        }
+        OpPtr op = new_op(op_name);
+        std::vector<HandlePtr> handles;
+        StatusPtr status = new_status();
+        for (int i = 0; i < num_inputs; ++i) {
+            handles.push_back(tensor_handle(tf_tensor_ref(inputs[i], "input")));
+            TFE_OpAddInput(op.get(), handles.back().get(), status.get());
+            check_status(status.get(), std::string(op_name) + " - entrada " + std::to_string(i));
+        }
+        set_attrs(op.get(), attrs, num_attrs);
+        execute_op(op.get(), op_name, outputs, num_outputs);
+        COPPER_LOG(COPPER_LOG_TRACE, "TFExecuteOp - %s (%d entradas, %d saídas)", op_name, num_inputs, num_outputs);
+        return static_cast<void*>(outputs);
+    });
 }
 
 EXPORT void FreeModel(void* model_handle) {
-    try {
+    guard_void("FreeModel", [&] {
         auto* handle = static_cast<ModelHandle*>(model_handle);
         if (!handle) {
-            fprintf(stderr, "Erro: ModelHandle inválido em FreeModel\n");
-            return;
+            throw std::invalid_argument("ModelHandle inválido");
         }
         TF_Status* status = TF_NewStatus();
         if (handle->session) {
@@ -260,600 +621,2085 @@ EXPORT void FreeModel(void* model_handle) {
         }
         TF_DeleteStatus(status);
         delete handle;
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em FreeModel: %s\n", e.what());
+    });
+}
+
+
+
+
+
+
+
+
+// ==================== Modules ====================
+// Camadas do torch::nn atravessam a fronteira como torch::nn::Module*; cada
+// função converte de volta para o tipo concreto com dynamic_cast.
+namespace {
+
+template <typename T>
+T& module_as(void* ptr, const char* name) {
+    auto* module = dynamic_cast<T*>(static_cast<torch::nn::Module*>(ptr));
+    if (!module) {
+        throw std::invalid_argument(std::string("ponteiro de módulo inválido (") + name + ")");
     }
+    return *module;
+}
+
+void* wrap_module(torch::nn::Module* module) {
+    return static_cast<void*>(module);
 }
 
+thread_local std::string parameter_name;
 
+} // namespace
 
+EXPORT void FreeModule(void* ptr) {
+    guard_void("FreeModule", [&] {
+        delete static_cast<torch::nn::Module*>(ptr);
+    });
+}
+
+// Parâmetros do módulo (incluindo submódulos C++), na ordem de named_parameters()
+EXPORT void* ModuleParameters(void* module_ptr) {
+    return guard_ptr("ModuleParameters", [&] {
+        auto params = module_as<torch::nn::Module>(module_ptr, "module").named_parameters().values();
+        return static_cast<void*>(new torch::autograd::variable_list(std::move(params)));
+    });
+}
+
+// O nome fica válido até a próxima chamada nesta thread
+EXPORT const char* ModuleParameterName(void* module_ptr, int index) {
+    return guard_value<const char*>("ModuleParameterName", nullptr, [&] {
+        auto keys = module_as<torch::nn::Module>(module_ptr, "module").named_parameters().keys();
+        if (index < 0 || static_cast<size_t>(index) >= keys.size()) {
+            throw std::out_of_range("índice de parâmetro fora do intervalo: " + std::to_string(index));
+        }
+        parameter_name = keys[index];
+        return parameter_name.c_str();
+    });
+}
 
+// Buffers (ex.: running_mean da BatchNorm), na ordem de named_buffers()
+EXPORT void* ModuleBuffers(void* module_ptr) {
+    return guard_ptr("ModuleBuffers", [&] {
+        auto buffers = module_as<torch::nn::Module>(module_ptr, "module").named_buffers().values();
+        return static_cast<void*>(new torch::autograd::variable_list(std::move(buffers)));
+    });
+}
 
+// O nome fica válido até a próxima chamada nesta thread
+EXPORT const char* ModuleBufferName(void* module_ptr, int index) {
+    return guard_value<const char*>("ModuleBufferName", nullptr, [&] {
+        auto keys = module_as<torch::nn::Module>(module_ptr, "module").named_buffers().keys();
+        if (index < 0 || static_cast<size_t>(index) >= keys.size()) {
+            throw std::out_of_range("índice de buffer fora do intervalo: " + std::to_string(index));
+        }
+        parameter_name = keys[index];
+        return parameter_name.c_str();
+    });
+}
 
+EXPORT void ModuleTrain(void* module_ptr, bool on) {
+    guard_void("ModuleTrain", [&] {
+        module_as<torch::nn::Module>(module_ptr, "module").train(on);
+    });
+}
 
+EXPORT int ModuleIsTraining(void* module_ptr) {
+    return guard_value("ModuleIsTraining", -1, [&] {
+        return module_as<torch::nn::Module>(module_ptr, "module").is_training() ? 1 : 0;
+    });
+}
 
 // Torch C++ API functions
 EXPORT void* CreateLinear(int in_features, int out_features) {
-    try {
+    return guard_ptr("CreateLinear", [&]() -> void* {
+        check_dims(in_features, out_features);
         auto* linear = new torch::nn::LinearImpl(in_features, out_features);
         // Ensure gradients are enabled for parameters
         for (auto& param : linear->parameters()) {
             param.set_requires_grad(true);
         }
-        return static_cast<void*>(linear);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em CreateLinear: %s\n", e.what());
-        return nullptr;
-    }
+        return wrap_module(linear);
+    });
 }
 
 EXPORT void* LinearForward(void* linear_ptr, void* input_tensor_ptr) {
-    try {
-        auto* linear = static_cast<torch::nn::LinearImpl*>(linear_ptr);
-        auto* input = static_cast<at::Tensor*>(input_tensor_ptr);
-        if (!linear || !input) {
-            fprintf(stderr, "Erro: Ponteiros inválidos em LinearForward\n");
-            return nullptr;
-        }
-        
-        // Debug: Print input shape
-        printf("LinearForward - Input shape: [");
-        for (int64_t i = 0; i < input->dim(); ++i) {
-            printf("%lld", input->size(i));
-            if (i < input->dim() - 1) printf(", ");
-        }
-        printf("]\n");
-        
-        at::Tensor* output = new at::Tensor(linear->forward(*input));
-        
-        // Debug: Print output shape
-        printf("LinearForward - Output shape: [");
-        for (int64_t i = 0; i < output->dim(); ++i) {
-            printf("%lld", output->size(i));
-            if (i < output->dim() - 1) printf(", ");
-        }
-        printf("]\n");
-        
-        return static_cast<void*>(output);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em LinearForward: %s\n", e.what());
-        return nullptr;
-    }
+    return guard_ptr("LinearForward", [&]() -> void* {
+        auto& linear = module_as<torch::nn::LinearImpl>(linear_ptr, "linear");
+        at::Tensor& input = tensor_ref(input_tensor_ptr, "input");
+
+        at::Tensor output = linear.forward(input);
+        COPPER_LOG(COPPER_LOG_TRACE, "LinearForward - input %s -> output %s",
+                   shape_string(input).c_str(), shape_string(output).c_str());
+        return wrap_tensor(std::move(output));
+    });
 }
 
-EXPORT void* MSELoss(void* prediction_tensor_ptr, void* target_tensor_ptr) {
-    try {
-        auto* prediction = static_cast<at::Tensor*>(prediction_tensor_ptr);
-        auto* target = static_cast<at::Tensor*>(target_tensor_ptr);
-        if (!prediction || !target) {
-            fprintf(stderr, "Erro: Tensores inválidos em MSELoss\n");
-            return nullptr;
-        }
-        
-        // Debug: Print shapes
-        printf("MSELoss - Prediction shape: [");
-        for (int64_t i = 0; i < prediction->dim(); ++i) {
-            printf("%lld", prediction->size(i));
-            if (i < prediction->dim() - 1) printf(", ");
-        }
-        printf("], Target shape: [");
-        for (int64_t i = 0; i < target->dim(); ++i) {
-            printf("%lld", target->size(i));
-            if (i < target->dim() - 1) printf(", ");
-        }
-        printf("]\n");
-        
-        at::Tensor* loss = new at::Tensor(torch::mse_loss(*prediction, *target));
-        
-        // Debug: Print loss info
-        printf("Loss computed - shape: [");
-        for (int64_t i = 0; i < loss->dim(); ++i) {
-            printf("%lld", loss->size(i));
-            if (i < loss->dim() - 1) printf(", ");
-        }
-        printf("], value: %f\n", loss->item<float>());
-        
-        return static_cast<void*>(loss);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em MSELoss: %s\n", e.what());
-        return nullptr;
+// Convolution and Pooling
+// Os arrays (kernel, stride, ...) chegam como ponteiros com `ndim` elementos,
+// um por dimensão espacial (1, 2 ou 3).
+namespace {
+
+at::IntArrayRef spatial_ref(const int64_t* values, int ndim, const char* name) {
+    if (ndim < 1 || ndim > 3) {
+        throw std::invalid_argument("número de dimensões espaciais inválido: " + std::to_string(ndim));
+    }
+    if (!values) {
+        throw std::invalid_argument(std::string("array inválido (") + name + ")");
     }
+    return at::IntArrayRef(values, static_cast<size_t>(ndim));
 }
 
-EXPORT void* CreateSGD(void* linear_ptr, float lr) {
-    try {
-        auto* linear = static_cast<torch::nn::LinearImpl*>(linear_ptr);
-        if (!linear) {
-            fprintf(stderr, "Erro: Ponteiro linear inválido em CreateSGD\n");
-            return nullptr;
-        }
-        auto* optimizer = new torch::optim::SGD(linear->parameters(), lr);
-        return static_cast<void*>(optimizer);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em CreateSGD: %s\n", e.what());
-        return nullptr;
-    }
+template <size_t D>
+torch::ExpandingArray<D> expanding(const int64_t* values, const char* name) {
+    return torch::ExpandingArray<D>(spatial_ref(values, static_cast<int>(D), name));
 }
 
-EXPORT void Backward(void* loss_ptr) {
-    try {
-        auto* loss = static_cast<at::Tensor*>(loss_ptr);
-        if (!loss) {
-            fprintf(stderr, "Erro: Tensor de perda inválido em Backward\n");
-            return;
-        }
-        loss->backward();
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em Backward: %s\n", e.what());
-    }
+template <typename Impl, size_t D>
+void* create_conv(int64_t in_channels, int64_t out_channels, const int64_t* kernel, const int64_t* stride,
+                  const int64_t* padding, const int64_t* dilation, int64_t groups, bool bias) {
+    auto options = torch::nn::ConvOptions<D>(in_channels, out_channels, expanding<D>(kernel, "kernel_size"))
+        .stride(expanding<D>(stride, "stride"))
+        .padding(expanding<D>(padding, "padding"))
+        .dilation(expanding<D>(dilation, "dilation"))
+        .groups(groups)
+        .bias(bias);
+    return wrap_module(new Impl(options));
 }
 
-EXPORT void OptimizerStep(void* optimizer_ptr) {
-    try {
-        auto* optimizer = static_cast<torch::optim::Optimizer*>(optimizer_ptr);
-        if (!optimizer) {
-            fprintf(stderr, "Erro: Otimizador inválido em OptimizerStep\n");
-            return;
-        }
-        optimizer->step();
-        optimizer->zero_grad();  // Reset gradients after step
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em OptimizerStep: %s\n", e.what());
-    }
+template <typename Impl, size_t D>
+void* create_conv_transpose(int64_t in_channels, int64_t out_channels, const int64_t* kernel, const int64_t* stride,
+                            const int64_t* padding, const int64_t* output_padding, const int64_t* dilation,
+                            int64_t groups, bool bias) {
+    auto options = torch::nn::ConvTransposeOptions<D>(in_channels, out_channels, expanding<D>(kernel, "kernel_size"))
+        .stride(expanding<D>(stride, "stride"))
+        .padding(expanding<D>(padding, "padding"))
+        .output_padding(expanding<D>(output_padding, "output_padding"))
+        .dilation(expanding<D>(dilation, "dilation"))
+        .groups(groups)
+        .bias(bias);
+    return wrap_module(new Impl(options));
 }
 
-EXPORT void OptimizerZeroGrad(void* optimizer_ptr) {
-    try {
-        auto* optimizer = static_cast<torch::optim::Optimizer*>(optimizer_ptr);
-        if (!optimizer) {
-            fprintf(stderr, "Erro: Otimizador inválido em OptimizerZeroGrad\n");
-            return;
+} // namespace
+
+// `output_padding` só é usado quando `transposed` é verdadeiro
+EXPORT void* CreateConv(
+    int ndim,
+    bool transposed,
+    int64_t in_channels,
+    int64_t out_channels,
+    const int64_t* kernel_size,
+    const int64_t* stride,
+    const int64_t* padding,
+    const int64_t* output_padding,
+    const int64_t* dilation,
+    int64_t groups,
+    bool bias) {
+    return guard_ptr("CreateConv", [&]() -> void* {
+        if (in_channels <= 0 || out_channels <= 0 || groups <= 0) {
+            throw std::invalid_argument("canais e groups devem ser positivos");
+        }
+        if (!transposed) {
+            switch (ndim) {
+                case 1: return create_conv<torch::nn::Conv1dImpl, 1>(in_channels, out_channels, kernel_size, stride, padding, dilation, groups, bias);
+                case 2: return create_conv<torch::nn::Conv2dImpl, 2>(in_channels, out_channels, kernel_size, stride, padding, dilation, groups, bias);
+                case 3: return create_conv<torch::nn::Conv3dImpl, 3>(in_channels, out_channels, kernel_size, stride, padding, dilation, groups, bias);
+            }
+        } else {
+            switch (ndim) {
+                case 1: return create_conv_transpose<torch::nn::ConvTranspose1dImpl, 1>(in_channels, out_channels, kernel_size, stride, padding, output_padding, dilation, groups, bias);
+                case 2: return create_conv_transpose<torch::nn::ConvTranspose2dImpl, 2>(in_channels, out_channels, kernel_size, stride, padding, output_padding, dilation, groups, bias);
+                case 3: return create_conv_transpose<torch::nn::ConvTranspose3dImpl, 3>(in_channels, out_channels, kernel_size, stride, padding, output_padding, dilation, groups, bias);
+            }
         }
-        optimizer->zero_grad();
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em OptimizerZeroGrad: %s\n", e.what());
-    }
+        throw std::invalid_argument("número de dimensões espaciais inválido: " + std::to_string(ndim));
+    });
 }
 
-EXPORT void FreeOptimizer(void* ptr) {
-    try {
-        auto* optimizer = static_cast<torch::optim::Optimizer*>(ptr);
-        if (optimizer) {
-            delete optimizer;
-        }
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em FreeOptimizer: %s\n", e.what());
-    }
+EXPORT void* ConvForward(void* module_ptr, void* input_ptr) {
+    return guard_ptr("ConvForward", [&] {
+        auto& module = module_as<torch::nn::Module>(module_ptr, "conv");
+        at::Tensor& input = tensor_ref(input_ptr, "input");
+        if (auto* conv = dynamic_cast<torch::nn::Conv1dImpl*>(&module)) return wrap_tensor(conv->forward(input));
+        if (auto* conv = dynamic_cast<torch::nn::Conv2dImpl*>(&module)) return wrap_tensor(conv->forward(input));
+        if (auto* conv = dynamic_cast<torch::nn::Conv3dImpl*>(&module)) return wrap_tensor(conv->forward(input));
+        if (auto* conv = dynamic_cast<torch::nn::ConvTranspose1dImpl*>(&module)) return wrap_tensor(conv->forward(input));
+        if (auto* conv = dynamic_cast<torch::nn::ConvTranspose2dImpl*>(&module)) return wrap_tensor(conv->forward(input));
+        if (auto* conv = dynamic_cast<torch::nn::ConvTranspose3dImpl*>(&module)) return wrap_tensor(conv->forward(input));
+        throw std::invalid_argument("o módulo não é uma convolução");
+    });
 }
 
-EXPORT void* CreateMatrixTensor(float* values, int rows, int cols) {
-    try {
-        if (!values || rows <= 0 || cols <= 0) {
-            fprintf(stderr, "Erro: Parâmetros inválidos em CreateMatrixTensor\n");
-            return nullptr;
+// Formas funcionais; `bias_ptr` pode ser nullptr
+EXPORT void* TensorConv(
+    void* input_ptr,
+    void* weight_ptr,
+    void* bias_ptr,
+    const int64_t* stride,
+    const int64_t* padding,
+    const int64_t* output_padding,
+    const int64_t* dilation,
+    int ndim,
+    bool transposed,
+    int64_t groups) {
+    return guard_ptr("TensorConv", [&] {
+        at::Tensor& input = tensor_ref(input_ptr, "input");
+        at::Tensor& weight = tensor_ref(weight_ptr, "weight");
+        c10::optional<at::Tensor> bias;
+        if (bias_ptr) {
+            bias = tensor_ref(bias_ptr, "bias");
+        }
+        auto s = spatial_ref(stride, ndim, "stride");
+        auto p = spatial_ref(padding, ndim, "padding");
+        auto d = spatial_ref(dilation, ndim, "dilation");
+        if (!transposed) {
+            switch (ndim) {
+                case 1: return wrap_tensor(torch::conv1d(input, weight, bias, s, p, d, groups));
+                case 2: return wrap_tensor(torch::conv2d(input, weight, bias, s, p, d, groups));
+                default: return wrap_tensor(torch::conv3d(input, weight, bias, s, p, d, groups));
+            }
         }
-        at::Tensor* tensor = new at::Tensor(torch::from_blob(values, {rows, cols}, torch::kFloat32).clone());
-        return static_cast<void*>(tensor);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em CreateMatrixTensor: %s\n", e.what());
-        return nullptr;
-    }
+        auto op = spatial_ref(output_padding, ndim, "output_padding");
+        switch (ndim) {
+            case 1: return wrap_tensor(torch::conv_transpose1d(input, weight, bias, s, p, op, groups, d));
+            case 2: return wrap_tensor(torch::conv_transpose2d(input, weight, bias, s, p, op, groups, d));
+            default: return wrap_tensor(torch::conv_transpose3d(input, weight, bias, s, p, op, groups, d));
+        }
+    });
 }
 
-EXPORT void* CreateTensorOnes(int rows, int cols) {
-    try {
-        if (rows <= 0 || cols <= 0) {
-            fprintf(stderr, "Erro: Dimensões inválidas em CreateTensorOnes\n");
-            return nullptr;
-        }
-        at::Tensor* tensor = new at::Tensor(torch::ones({rows, cols}, torch::kFloat32));
-        return static_cast<void*>(tensor);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em CreateTensorOnes: %s\n", e.what());
-        return nullptr;
-    }
+EXPORT void* TensorMaxPool(
+    void* input_ptr,
+    const int64_t* kernel_size,
+    const int64_t* stride,
+    const int64_t* padding,
+    const int64_t* dilation,
+    int ndim,
+    bool ceil_mode) {
+    return guard_ptr("TensorMaxPool", [&] {
+        at::Tensor& input = tensor_ref(input_ptr, "input");
+        auto k = spatial_ref(kernel_size, ndim, "kernel_size");
+        auto s = spatial_ref(stride, ndim, "stride");
+        auto p = spatial_ref(padding, ndim, "padding");
+        auto d = spatial_ref(dilation, ndim, "dilation");
+        switch (ndim) {
+            case 1: return wrap_tensor(torch::max_pool1d(input, k, s, p, d, ceil_mode));
+            case 2: return wrap_tensor(torch::max_pool2d(input, k, s, p, d, ceil_mode));
+            default: return wrap_tensor(torch::max_pool3d(input, k, s, p, d, ceil_mode));
+        }
+    });
 }
 
-EXPORT void* CreateTensorRand(int rows, int cols) {
-    try {
-        if (rows <= 0 || cols <= 0) {
-            fprintf(stderr, "Erro: Dimensões inválidas em CreateTensorRand\n");
-            return nullptr;
-        }
-        at::Tensor* tensor = new at::Tensor(torch::rand({rows, cols}, torch::kFloat32));
-        return static_cast<void*>(tensor);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em CreateTensorRand: %s\n", e.what());
-        return nullptr;
-    }
+EXPORT void* TensorAvgPool(
+    void* input_ptr,
+    const int64_t* kernel_size,
+    const int64_t* stride,
+    const int64_t* padding,
+    int ndim,
+    bool ceil_mode,
+    bool count_include_pad) {
+    return guard_ptr("TensorAvgPool", [&] {
+        at::Tensor& input = tensor_ref(input_ptr, "input");
+        auto k = spatial_ref(kernel_size, ndim, "kernel_size");
+        auto s = spatial_ref(stride, ndim, "stride");
+        auto p = spatial_ref(padding, ndim, "padding");
+        switch (ndim) {
+            case 1: return wrap_tensor(torch::avg_pool1d(input, k, s, p, ceil_mode, count_include_pad));
+            case 2: return wrap_tensor(torch::avg_pool2d(input, k, s, p, ceil_mode, count_include_pad));
+            default: return wrap_tensor(torch::avg_pool3d(input, k, s, p, ceil_mode, count_include_pad));
+        }
+    });
 }
 
-EXPORT void FreeTensor(void* ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(ptr);
-        if (tensor) {
-            delete tensor;
+EXPORT void* TensorAdaptiveAvgPool(void* input_ptr, const int64_t* output_size, int ndim) {
+    return guard_ptr("TensorAdaptiveAvgPool", [&] {
+        at::Tensor& input = tensor_ref(input_ptr, "input");
+        auto size = spatial_ref(output_size, ndim, "output_size");
+        switch (ndim) {
+            case 1: return wrap_tensor(torch::adaptive_avg_pool1d(input, size));
+            case 2: return wrap_tensor(torch::adaptive_avg_pool2d(input, size));
+            default: return wrap_tensor(torch::adaptive_avg_pool3d(input, size));
         }
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em FreeTensor: %s\n", e.what());
-    }
+    });
 }
 
-EXPORT float* TensorData(void* ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorData\n");
-            return nullptr;
+// Recurrent Layers
+// `mode`: 0 = RNN (tanh), 1 = RNN (relu), 2 = LSTM, 3 = GRU
+EXPORT void* CreateRNN(
+    int mode,
+    int64_t input_size,
+    int64_t hidden_size,
+    int64_t num_layers,
+    bool bias,
+    bool batch_first,
+    double dropout,
+    bool bidirectional) {
+    return guard_ptr("CreateRNN", [&]() -> void* {
+        if (input_size <= 0 || hidden_size <= 0 || num_layers <= 0) {
+            throw std::invalid_argument("input_size, hidden_size e num_layers devem ser positivos");
+        }
+        switch (mode) {
+            case 0:
+            case 1: {
+                auto options = torch::nn::RNNOptions(input_size, hidden_size)
+                    .num_layers(num_layers).bias(bias).batch_first(batch_first)
+                    .dropout(dropout).bidirectional(bidirectional);
+                if (mode == 1) {
+                    options.nonlinearity(torch::kReLU);
+                }
+                return wrap_module(new torch::nn::RNNImpl(options));
+            }
+            case 2:
+                return wrap_module(new torch::nn::LSTMImpl(torch::nn::LSTMOptions(input_size, hidden_size)
+                    .num_layers(num_layers).bias(bias).batch_first(batch_first)
+                    .dropout(dropout).bidirectional(bidirectional)));
+            case 3:
+                return wrap_module(new torch::nn::GRUImpl(torch::nn::GRUOptions(input_size, hidden_size)
+                    .num_layers(num_layers).bias(bias).batch_first(batch_first)
+                    .dropout(dropout).bidirectional(bidirectional)));
+        }
+        throw std::invalid_argument("tipo de camada recorrente desconhecido: " + std::to_string(mode));
+    });
+}
+
+// Devolve [output, h_n] (RNN/GRU) ou [output, h_n, c_n] (LSTM); estados nulos
+// começam em zero. O LSTM exige h0 e c0 juntos.
+EXPORT void* RNNForward(void* module_ptr, void* input_ptr, void* h0_ptr, void* c0_ptr) {
+    return guard_ptr("RNNForward", [&] {
+        auto& module = module_as<torch::nn::Module>(module_ptr, "rnn");
+        at::Tensor& input = tensor_ref(input_ptr, "input");
+        at::Tensor h0 = h0_ptr ? tensor_ref(h0_ptr, "h0") : at::Tensor();
+        torch::autograd::variable_list result;
+        if (auto* lstm = dynamic_cast<torch::nn::LSTMImpl*>(&module)) {
+            torch::optional<std::tuple<at::Tensor, at::Tensor>> state;
+            if (h0_ptr || c0_ptr) {
+                state = std::make_tuple(tensor_ref(h0_ptr, "h0"), tensor_ref(c0_ptr, "c0"));
+            }
+            auto [output, hidden] = lstm->forward(input, state);
+            result = {output, std::get<0>(hidden), std::get<1>(hidden)};
+        } else if (auto* gru = dynamic_cast<torch::nn::GRUImpl*>(&module)) {
+            auto [output, h_n] = gru->forward(input, h0);
+            result = {output, h_n};
+        } else if (auto* rnn = dynamic_cast<torch::nn::RNNImpl*>(&module)) {
+            auto [output, h_n] = rnn->forward(input, h0);
+            result = {output, h_n};
+        } else {
+            throw std::invalid_argument("o módulo não é uma camada recorrente");
+        }
+        return static_cast<void*>(new torch::autograd::variable_list(std::move(result)));
+    });
+}
+
+// Embeddings
+// `padding_idx` e `max_norm` são opcionais (nullptr). Com `weight_ptr` os pesos
+// pré-treinados (compartilhados, não copiados) definem num_embeddings e
+// embedding_dim, e `freeze` desliga o gradiente deles.
+namespace {
+
+template <typename Options>
+Options embedding_options(Options options, const int64_t* padding_idx, const double* max_norm, void* weight_ptr) {
+    if (padding_idx) {
+        options.padding_idx(*padding_idx);
+    }
+    if (max_norm) {
+        options.max_norm(*max_norm);
+    }
+    if (weight_ptr) {
+        at::Tensor& weight = tensor_ref(weight_ptr, "weight");
+        if (weight.dim() != 2) {
+            throw std::invalid_argument("pesos pré-treinados devem ser 2-D, recebido " + shape_string(weight));
         }
-        return tensor->data_ptr<float>();
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorData: %s\n", e.what());
-        return nullptr;
+        options._weight(weight);
     }
+    return options;
 }
 
-EXPORT int TensorRows(void* ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorRows\n");
-            return -1;
+std::pair<int64_t, int64_t> embedding_size(int64_t num_embeddings, int64_t embedding_dim, void* weight_ptr) {
+    if (weight_ptr) {
+        at::Tensor& weight = tensor_ref(weight_ptr, "weight");
+        return {weight.size(0), weight.size(weight.dim() - 1)};
+    }
+    if (num_embeddings <= 0 || embedding_dim <= 0) {
+        throw std::invalid_argument("num_embeddings e embedding_dim devem ser positivos");
+    }
+    return {num_embeddings, embedding_dim};
+}
+
+} // namespace
+
+EXPORT void* CreateEmbedding(
+    int64_t num_embeddings,
+    int64_t embedding_dim,
+    const int64_t* padding_idx,
+    const double* max_norm,
+    void* weight_ptr,
+    bool freeze) {
+    return guard_ptr("CreateEmbedding", [&]() -> void* {
+        auto [rows, cols] = embedding_size(num_embeddings, embedding_dim, weight_ptr);
+        auto options = embedding_options(torch::nn::EmbeddingOptions(rows, cols), padding_idx, max_norm, weight_ptr);
+        auto* embedding = new torch::nn::EmbeddingImpl(options);
+        if (weight_ptr) {
+            embedding->weight.set_requires_grad(!freeze);
+        }
+        return wrap_module(embedding);
+    });
+}
+
+EXPORT void* EmbeddingForward(void* module_ptr, void* input_ptr) {
+    return guard_ptr("EmbeddingForward", [&] {
+        auto& embedding = module_as<torch::nn::EmbeddingImpl>(module_ptr, "embedding");
+        return wrap_tensor(embedding.forward(tensor_ref(input_ptr, "input")));
+    });
+}
+
+// `mode`: 0 = sum, 1 = mean, 2 = max
+EXPORT void* CreateEmbeddingBag(
+    int64_t num_embeddings,
+    int64_t embedding_dim,
+    int mode,
+    const int64_t* padding_idx,
+    const double* max_norm,
+    void* weight_ptr,
+    bool freeze) {
+    return guard_ptr("CreateEmbeddingBag", [&]() -> void* {
+        auto [rows, cols] = embedding_size(num_embeddings, embedding_dim, weight_ptr);
+        auto options = embedding_options(torch::nn::EmbeddingBagOptions(rows, cols), padding_idx, max_norm, weight_ptr);
+        switch (mode) {
+            case 0: options.mode(torch::kSum); break;
+            case 1: options.mode(torch::kMean); break;
+            case 2: options.mode(torch::kMax); break;
+            default: throw std::invalid_argument("modo de EmbeddingBag desconhecido: " + std::to_string(mode));
+        }
+        auto* bag = new torch::nn::EmbeddingBagImpl(options);
+        if (weight_ptr) {
+            bag->weight.set_requires_grad(!freeze);
+        }
+        return wrap_module(bag);
+    });
+}
+
+// `offsets` e `per_sample_weights` podem ser nullptr (entrada 2-D: uma bolsa por linha)
+EXPORT void* EmbeddingBagForward(void* module_ptr, void* input_ptr, void* offsets_ptr, void* per_sample_weights_ptr) {
+    return guard_ptr("EmbeddingBagForward", [&] {
+        auto& bag = module_as<torch::nn::EmbeddingBagImpl>(module_ptr, "embedding_bag");
+        at::Tensor offsets = offsets_ptr ? tensor_ref(offsets_ptr, "offsets") : at::Tensor();
+        at::Tensor weights = per_sample_weights_ptr ? tensor_ref(per_sample_weights_ptr, "per_sample_weights") : at::Tensor();
+        return wrap_tensor(bag.forward(tensor_ref(input_ptr, "input"), offsets, weights));
+    });
+}
+
+// Normalization
+// `ndim` escolhe BatchNorm1d/2d/3d e InstanceNorm1d/2d/3d
+EXPORT void* CreateBatchNorm(
+    int ndim,
+    bool instance,
+    int64_t num_features,
+    double eps,
+    double momentum,
+    bool affine,
+    bool track_running_stats) {
+    return guard_ptr("CreateBatchNorm", [&]() -> void* {
+        if (num_features <= 0) {
+            throw std::invalid_argument("num_features deve ser positivo");
+        }
+        if (!instance) {
+            auto options = torch::nn::BatchNormOptions(num_features)
+                .eps(eps).momentum(momentum).affine(affine).track_running_stats(track_running_stats);
+            switch (ndim) {
+                case 1: return wrap_module(new torch::nn::BatchNorm1dImpl(options));
+                case 2: return wrap_module(new torch::nn::BatchNorm2dImpl(options));
+                case 3: return wrap_module(new torch::nn::BatchNorm3dImpl(options));
+            }
+        } else {
+            auto options = torch::nn::InstanceNormOptions(num_features)
+                .eps(eps).momentum(momentum).affine(affine).track_running_stats(track_running_stats);
+            switch (ndim) {
+                case 1: return wrap_module(new torch::nn::InstanceNorm1dImpl(options));
+                case 2: return wrap_module(new torch::nn::InstanceNorm2dImpl(options));
+                case 3: return wrap_module(new torch::nn::InstanceNorm3dImpl(options));
+            }
         }
-        // Verificar se o tensor tem dimensões suficientes
-        if (tensor->dim() == 0) {
-            // Tensor escalar (0-D) - retorna 1
-            return 1;
+        throw std::invalid_argument("número de dimensões espaciais inválido: " + std::to_string(ndim));
+    });
+}
+
+EXPORT void* CreateLayerNorm(const int64_t* normalized_shape, int ndim, double eps, bool elementwise_affine) {
+    return guard_ptr("CreateLayerNorm", [&]() -> void* {
+        if (ndim <= 0) {
+            throw std::invalid_argument("normalized_shape não pode ser vazio");
         }
-        if (tensor->dim() == 1) {
-            // Tensor 1-D - retorna o tamanho da primeira dimensão
-            return tensor->size(0);
+        auto shape = shape_ref(normalized_shape, ndim).vec();
+        return wrap_module(new torch::nn::LayerNormImpl(
+            torch::nn::LayerNormOptions(shape).eps(eps).elementwise_affine(elementwise_affine)));
+    });
+}
+
+EXPORT void* CreateGroupNorm(int64_t num_groups, int64_t num_channels, double eps, bool affine) {
+    return guard_ptr("CreateGroupNorm", [&]() -> void* {
+        if (num_groups <= 0 || num_channels <= 0 || num_channels % num_groups != 0) {
+            throw std::invalid_argument("num_channels deve ser um múltiplo positivo de num_groups");
         }
-        // Tensor 2-D ou maior - retorna o tamanho da primeira dimensão
-        return tensor->size(0);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorRows: %s\n", e.what());
-        return -1;
-    }
+        return wrap_module(new torch::nn::GroupNormImpl(
+            torch::nn::GroupNormOptions(num_groups, num_channels).eps(eps).affine(affine)));
+    });
 }
 
-EXPORT int TensorCols(void* ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorCols\n");
-            return -1;
+// Em modo de treino a BatchNorm usa as estatísticas do batch e atualiza os buffers
+// running_*; em avaliação usa os buffers
+EXPORT void* NormForward(void* module_ptr, void* input_ptr) {
+    return guard_ptr("NormForward", [&] {
+        auto& module = module_as<torch::nn::Module>(module_ptr, "norm");
+        at::Tensor& input = tensor_ref(input_ptr, "input");
+        if (auto* norm = dynamic_cast<torch::nn::BatchNorm1dImpl*>(&module)) return wrap_tensor(norm->forward(input));
+        if (auto* norm = dynamic_cast<torch::nn::BatchNorm2dImpl*>(&module)) return wrap_tensor(norm->forward(input));
+        if (auto* norm = dynamic_cast<torch::nn::BatchNorm3dImpl*>(&module)) return wrap_tensor(norm->forward(input));
+        if (auto* norm = dynamic_cast<torch::nn::InstanceNorm1dImpl*>(&module)) return wrap_tensor(norm->forward(input));
+        if (auto* norm = dynamic_cast<torch::nn::InstanceNorm2dImpl*>(&module)) return wrap_tensor(norm->forward(input));
+        if (auto* norm = dynamic_cast<torch::nn::InstanceNorm3dImpl*>(&module)) return wrap_tensor(norm->forward(input));
+        if (auto* norm = dynamic_cast<torch::nn::LayerNormImpl*>(&module)) return wrap_tensor(norm->forward(input));
+        if (auto* norm = dynamic_cast<torch::nn::GroupNormImpl*>(&module)) return wrap_tensor(norm->forward(input));
+        throw std::invalid_argument("o módulo não é uma camada de normalização");
+    });
+}
+
+// Serialization
+// Os tensores são gravados por nome (o caminho do state_dict) em um arquivo torch::save
+EXPORT void SaveTensors(const char* const* names, void* const* tensors, int count, const char* path) {
+    guard_void("SaveTensors", [&] {
+        if (!path || (count > 0 && (!names || !tensors))) {
+            throw std::invalid_argument("argumentos inválidos para SaveTensors");
         }
-        // Verificar se o tensor tem dimensões suficientes
-        if (tensor->dim() == 0) {
-            // Tensor escalar (0-D) - retorna 1
-            return 1;
+        torch::serialize::OutputArchive archive;
+        for (int i = 0; i < count; ++i) {
+            archive.write(names[i], tensor_ref(tensors[i], "tensors[i]").detach(), /*is_buffer=*/true);
         }
-        if (tensor->dim() == 1) {
-            // Tensor 1-D - consideramos como vetor coluna
-            return 1;
+        archive.save_to(path);
+    });
+}
+
+// Copia no lugar os tensores gravados para `tensors`, que devem ter as mesmas formas;
+// o storage é mantido, então otimizadores criados antes continuam válidos
+EXPORT void LoadTensors(const char* const* names, void* const* tensors, int count, const char* path) {
+    guard_void("LoadTensors", [&] {
+        if (!path || (count > 0 && (!names || !tensors))) {
+            throw std::invalid_argument("argumentos inválidos para LoadTensors");
+        }
+        torch::serialize::InputArchive archive;
+        archive.load_from(path);
+        torch::NoGradGuard no_grad;
+        for (int i = 0; i < count; ++i) {
+            at::Tensor& tensor = tensor_ref(tensors[i], "tensors[i]");
+            at::Tensor loaded;
+            if (!archive.try_read(names[i], loaded, /*is_buffer=*/true)) {
+                throw std::invalid_argument(std::string("tensor ausente no arquivo: ") + names[i]);
+            }
+            if (loaded.sizes() != tensor.sizes()) {
+                throw std::invalid_argument(std::string("forma incompatível para ") + names[i] + ": "
+                    + shape_string(loaded) + " no arquivo, " + shape_string(tensor) + " no módulo");
+            }
+            tensor.copy_(loaded);
         }
-        // Tensor 2-D ou maior - retorna o tamanho da segunda dimensão
-        return tensor->size(1);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorCols: %s\n", e.what());
-        return -1;
-    }
+    });
 }
 
-// Activation Functions
-EXPORT void* TensorReLU(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorReLU\n");
-            return nullptr;
+// Dropout
+// `kind`: 0 = dropout por elemento, 1 = dropout2d (canais inteiros), 2 = alpha dropout
+EXPORT void* TensorDropout(void* tensor_ptr, double p, bool train, int kind) {
+    return guard_ptr("TensorDropout", [&] {
+        at::Tensor& input = tensor_ref(tensor_ptr, "tensor");
+        switch (kind) {
+            case 0: return wrap_tensor(torch::dropout(input, p, train));
+            case 1: {
+                // Entrada [C, H, W] sem batch: o canal é a primeira dimensão
+                if (input.dim() != 3 && input.dim() != 4) {
+                    throw std::invalid_argument("dropout2d espera entrada 3-D ou 4-D, recebeu " + shape_string(input));
+                }
+                if (input.dim() == 3) {
+                    return wrap_tensor(torch::feature_dropout(input.unsqueeze(0), p, train).squeeze(0));
+                }
+                return wrap_tensor(torch::feature_dropout(input, p, train));
+            }
+            case 2: return wrap_tensor(torch::alpha_dropout(input, p, train));
         }
-        at::Tensor* result = new at::Tensor(torch::relu(*tensor));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorReLU: %s\n", e.what());
-        return nullptr;
+        throw std::invalid_argument("tipo de dropout inválido: " + std::to_string(kind));
+    });
+}
+
+// Attention
+// softmax(Q·Kᵀ / √E + máscaras)·V com q [..., L, E], k [..., S, E] e v [..., S, Ev].
+// Máscaras bool marcam as posições ignoradas (true = ignorar); as de ponto flutuante
+// são somadas aos scores. Devolve [output, pesos de atenção] com os pesos já depois do
+// dropout, os mesmos multiplicados por V, como o MultiheadAttention do PyTorch.
+EXPORT void* TensorAttention(
+    void* query_ptr,
+    void* key_ptr,
+    void* value_ptr,
+    void* attn_mask_ptr,
+    void* key_padding_mask_ptr,
+    double dropout_p,
+    bool train) {
+    return guard_ptr("TensorAttention", [&] {
+        at::Tensor& query = tensor_ref(query_ptr, "query");
+        at::Tensor& key = tensor_ref(key_ptr, "key");
+        at::Tensor& value = tensor_ref(value_ptr, "value");
+        auto scores = at::matmul(query, key.transpose(-2, -1)) / std::sqrt(static_cast<double>(query.size(-1)));
+        auto apply_mask = [&](void* mask_ptr, const char* name) {
+            if (!mask_ptr) return;
+            at::Tensor& mask = tensor_ref(mask_ptr, name);
+            if (mask.scalar_type() == at::kBool) {
+                scores = scores.masked_fill(mask, -std::numeric_limits<double>::infinity());
+            } else {
+                scores = scores + mask;
+            }
+        };
+        apply_mask(attn_mask_ptr, "attn_mask");
+        apply_mask(key_padding_mask_ptr, "key_padding_mask");
+        auto weights = torch::dropout(torch::softmax(scores, -1), dropout_p, train);
+        auto output = at::matmul(weights, value);
+        return static_cast<void*>(new torch::autograd::variable_list{output, weights});
+    });
+}
+
+// `reduction` das funções de perda: 0 = none, 1 = mean, 2 = sum (os códigos de at::Reduction)
+static int64_t reduction_code(int reduction) {
+    if (reduction < 0 || reduction > 2) {
+        throw std::invalid_argument("reduction inválida: " + std::to_string(reduction));
     }
+    return reduction;
 }
 
-EXPORT void* TensorSigmoid(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorSigmoid\n");
-            return nullptr;
+// Tensores opcionais (weight, pos_weight) chegam como nullptr e viram tensores indefinidos
+static at::Tensor optional_tensor(void* tensor_ptr, const char* name) {
+    return tensor_ptr ? tensor_ref(tensor_ptr, name) : at::Tensor();
+}
+
+EXPORT void* MSELoss(void* prediction_tensor_ptr, void* target_tensor_ptr, int reduction) {
+    return guard_ptr("MSELoss", [&]() -> void* {
+        at::Tensor& prediction = tensor_ref(prediction_tensor_ptr, "prediction");
+        at::Tensor& target = tensor_ref(target_tensor_ptr, "target");
+
+        at::Tensor loss = torch::mse_loss(prediction, target, reduction_code(reduction));
+        COPPER_LOG(COPPER_LOG_TRACE, "MSELoss - prediction %s, target %s",
+                   shape_string(prediction).c_str(), shape_string(target).c_str());
+        if (log_enabled(COPPER_LOG_DEBUG) && loss.numel() == 1) {
+            copper_log(COPPER_LOG_DEBUG, "MSELoss - value %f", loss.item<float>());
         }
-        at::Tensor* result = new at::Tensor(torch::sigmoid(*tensor));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorSigmoid: %s\n", e.what());
-        return nullptr;
-    }
+        return wrap_tensor(std::move(loss));
+    });
 }
 
-EXPORT void* TensorTanh(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorTanh\n");
-            return nullptr;
+EXPORT void Backward(void* loss_ptr) {
+    guard_void("Backward", [&] {
+        tensor_ref(loss_ptr, "loss").backward();
+    });
+}
+
+// Autograd
+EXPORT void TensorSetRequiresGrad(void* ptr, bool requires_grad) {
+    guard_void("TensorSetRequiresGrad", [&] {
+        tensor_ref(ptr, "tensor").requires_grad_(requires_grad);
+    });
+}
+
+EXPORT int TensorRequiresGrad(void* ptr) {
+    return guard_value("TensorRequiresGrad", -1, [&] {
+        return tensor_ref(ptr, "tensor").requires_grad() ? 1 : 0;
+    });
+}
+
+EXPORT int TensorIsLeaf(void* ptr) {
+    return guard_value("TensorIsLeaf", -1, [&] {
+        return tensor_ref(ptr, "tensor").is_leaf() ? 1 : 0;
+    });
+}
+
+// Devolve nullptr sem registrar erro quando o gradiente ainda não existe
+EXPORT void* TensorGrad(void* ptr) {
+    return guard_value<void*>("TensorGrad", nullptr, [&]() -> void* {
+        const at::Tensor& grad = tensor_ref(ptr, "tensor").grad();
+        return grad.defined() ? wrap_tensor(grad) : nullptr;
+    });
+}
+
+EXPORT void* TensorDetach(void* ptr) {
+    return guard_ptr("TensorDetach", [&] {
+        return wrap_tensor(tensor_ref(ptr, "tensor").detach());
+    });
+}
+
+EXPORT void TensorRetainGrad(void* ptr) {
+    guard_void("TensorRetainGrad", [&] {
+        tensor_ref(ptr, "tensor").retain_grad();
+    });
+}
+
+// O modo de gradiente é por thread, como no PyTorch
+EXPORT void SetGradEnabled(bool enabled) {
+    guard_void("SetGradEnabled", [&] {
+        at::GradMode::set_enabled(enabled);
+    });
+}
+
+EXPORT int IsGradEnabled() {
+    return guard_value("IsGradEnabled", -1, [&] {
+        return at::GradMode::is_enabled() ? 1 : 0;
+    });
+}
+
+// ==================== Custom Functions ====================
+// Funções diferenciáveis definidas em Rust. O estado da Function fica em um
+// capsule no AutogradContext e os callbacks Rust são chamados no forward e no
+// backward; listas de tensores atravessam a fronteira como std::vector<at::Tensor>*.
+typedef int (*CopperForwardFn)(void* state, void* ctx, void* const* inputs, int num_inputs, void* outputs);
+typedef int (*CopperBackwardFn)(void* state, void* ctx, void* const* grad_outputs, int num_grads, void* grad_inputs);
+typedef void (*CopperDropFn)(void* state);
+
+namespace {
+
+struct RustFunctionState : torch::CustomClassHolder {
+    void* state;
+    CopperForwardFn forward;
+    CopperBackwardFn backward;
+    CopperDropFn drop;
+    int64_t num_inputs;
+
+    RustFunctionState(void* state, CopperForwardFn forward, CopperBackwardFn backward, CopperDropFn drop)
+        : state(state), forward(forward), backward(backward), drop(drop), num_inputs(0) {}
+
+    ~RustFunctionState() override {
+        if (drop) {
+            drop(state);
         }
-        at::Tensor* result = new at::Tensor(torch::tanh(*tensor));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorTanh: %s\n", e.what());
-        return nullptr;
+    }
+};
+
+const char* const FUNCTION_STATE_KEY = "copper_function";
+
+// Converte a falha reportada pelo callback Rust (CopperSetError) em exceção
+void check_callback(int status) {
+    if (status != 0) {
+        throw std::runtime_error(has_error ? last_error : std::string("callback Rust falhou"));
     }
 }
 
-// Mathematical Functions
-EXPORT void* TensorSin(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorSin\n");
-            return nullptr;
-        }
-        at::Tensor* result = new at::Tensor(torch::sin(*tensor));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorSin: %s\n", e.what());
-        return nullptr;
+std::vector<void*> wrap_all(const torch::autograd::variable_list& tensors) {
+    std::vector<void*> ptrs;
+    ptrs.reserve(tensors.size());
+    for (const auto& tensor : tensors) {
+        ptrs.push_back(wrap_tensor(tensor));
     }
+    return ptrs;
 }
 
-EXPORT void* TensorCos(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorCos\n");
-            return nullptr;
-        }
-        at::Tensor* result = new at::Tensor(torch::cos(*tensor));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorCos: %s\n", e.what());
-        return nullptr;
+class RustFunction : public torch::autograd::Function<RustFunction> {
+public:
+    static torch::autograd::variable_list forward(
+        torch::autograd::AutogradContext* ctx,
+        c10::intrusive_ptr<RustFunctionState> function,
+        at::TensorList inputs) {
+        function->num_inputs = static_cast<int64_t>(inputs.size());
+        ctx->saved_data[FUNCTION_STATE_KEY] = at::IValue::make_capsule(function);
+
+        // O Rust assume a posse dos ponteiros das entradas
+        std::vector<void*> input_ptrs = wrap_all(inputs.vec());
+        torch::autograd::variable_list outputs;
+        check_callback(function->forward(
+            function->state, ctx, input_ptrs.data(), static_cast<int>(input_ptrs.size()), &outputs));
+        return outputs;
     }
+
+    static torch::autograd::variable_list backward(
+        torch::autograd::AutogradContext* ctx,
+        torch::autograd::variable_list grad_outputs) {
+        auto function = c10::static_intrusive_pointer_cast<RustFunctionState>(
+            ctx->saved_data[FUNCTION_STATE_KEY].toCapsule());
+
+        std::vector<void*> grad_ptrs = wrap_all(grad_outputs);
+        torch::autograd::variable_list grad_inputs;
+        check_callback(function->backward(
+            function->state, ctx, grad_ptrs.data(), static_cast<int>(grad_ptrs.size()), &grad_inputs));
+        if (static_cast<int64_t>(grad_inputs.size()) != function->num_inputs) {
+            throw std::invalid_argument(
+                "backward devolveu " + std::to_string(grad_inputs.size()) + " gradientes para "
+                + std::to_string(function->num_inputs) + " entradas");
+        }
+
+        // O primeiro argumento do forward (o estado) não tem gradiente
+        grad_inputs.insert(grad_inputs.begin(), at::Tensor());
+        return grad_inputs;
+    }
+};
+
+torch::autograd::variable_list& tensor_list_ref(void* ptr) {
+    if (!ptr) {
+        throw std::invalid_argument("lista de tensores inválida");
+    }
+    return *static_cast<torch::autograd::variable_list*>(ptr);
 }
 
-EXPORT void* TensorExp(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorExp\n");
-            return nullptr;
-        }
-        at::Tensor* result = new at::Tensor(torch::exp(*tensor));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorExp: %s\n", e.what());
-        return nullptr;
+torch::autograd::AutogradContext& context_ref(void* ptr) {
+    if (!ptr) {
+        throw std::invalid_argument("AutogradContext inválido");
     }
+    return *static_cast<torch::autograd::AutogradContext*>(ptr);
 }
 
-EXPORT void* TensorLog(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorLog\n");
-            return nullptr;
+} // namespace
+
+// `state` passa a pertencer ao shim, que chama `drop` quando o grafo o libera
+// (ou imediatamente, se o forward falhar)
+EXPORT void* CopperFunctionApply(
+    void* state,
+    CopperForwardFn forward,
+    CopperBackwardFn backward,
+    CopperDropFn drop,
+    void* const* inputs,
+    int num_inputs) {
+    return guard_ptr("CopperFunctionApply", [&] {
+        auto function = c10::make_intrusive<RustFunctionState>(state, forward, backward, drop);
+        if (num_inputs < 0 || (num_inputs > 0 && !inputs)) {
+            throw std::invalid_argument("entradas inválidas");
+        }
+        std::vector<at::Tensor> input_list;
+        input_list.reserve(static_cast<size_t>(num_inputs));
+        for (int i = 0; i < num_inputs; ++i) {
+            input_list.push_back(tensor_ref(inputs[i], "inputs[i]"));
         }
-        at::Tensor* result = new at::Tensor(torch::log(*tensor));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorLog: %s\n", e.what());
-        return nullptr;
-    }
+        auto outputs = RustFunction::apply(function, at::TensorList(input_list));
+        return static_cast<void*>(new torch::autograd::variable_list(std::move(outputs)));
+    });
 }
 
-EXPORT void* TensorSqrt(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorSqrt\n");
-            return nullptr;
+// Tensores nulos viram tensores indefinidos (gradiente ausente)
+EXPORT void TensorListPush(void* list_ptr, void* tensor_ptr) {
+    guard_void("TensorListPush", [&] {
+        tensor_list_ref(list_ptr).push_back(tensor_ptr ? tensor_ref(tensor_ptr, "tensor") : at::Tensor());
+    });
+}
+
+EXPORT int TensorListSize(void* list_ptr) {
+    return guard_value("TensorListSize", -1, [&] {
+        return static_cast<int>(tensor_list_ref(list_ptr).size());
+    });
+}
+
+EXPORT void* TensorListGet(void* list_ptr, int index) {
+    return guard_ptr("TensorListGet", [&] {
+        const auto& list = tensor_list_ref(list_ptr);
+        if (index < 0 || static_cast<size_t>(index) >= list.size() || !list[index].defined()) {
+            throw std::out_of_range("posição " + std::to_string(index) + " inválida na lista de tensores");
         }
-        at::Tensor* result = new at::Tensor(torch::sqrt(*tensor));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorSqrt: %s\n", e.what());
-        return nullptr;
-    }
+        return wrap_tensor(list[index]);
+    });
 }
 
-EXPORT void* TensorAbs(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorAbs\n");
-            return nullptr;
+EXPORT void FreeTensorList(void* list_ptr) {
+    guard_void("FreeTensorList", [&] {
+        delete static_cast<torch::autograd::variable_list*>(list_ptr);
+    });
+}
+
+EXPORT void AutogradContextSaveForBackward(void* ctx_ptr, void* const* tensors, int count) {
+    guard_void("AutogradContextSaveForBackward", [&] {
+        torch::autograd::variable_list saved;
+        for (int i = 0; i < count; ++i) {
+            saved.push_back(tensor_ref(tensors[i], "tensors[i]"));
         }
-        at::Tensor* result = new at::Tensor(torch::abs(*tensor));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorAbs: %s\n", e.what());
-        return nullptr;
-    }
+        context_ref(ctx_ptr).save_for_backward(std::move(saved));
+    });
 }
 
-EXPORT void* TensorPow(void* tensor_ptr, float exponent) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorPow\n");
-            return nullptr;
+// Só pode ser chamado durante o backward
+EXPORT void* AutogradContextSavedTensors(void* ctx_ptr) {
+    return guard_ptr("AutogradContextSavedTensors", [&] {
+        return static_cast<void*>(new torch::autograd::variable_list(context_ref(ctx_ptr).get_saved_variables()));
+    });
+}
+
+// torch::autograd::grad; entradas sem caminho até as saídas recebem zeros.
+// `grad_outputs` may be nullptr (scalar outputs); returns a tensor list
+EXPORT void* AutogradGrad(
+    void* const* outputs,
+    int num_outputs,
+    void* const* inputs,
+    int num_inputs,
+    void* const* grad_outputs,
+    bool retain_graph,
+    bool create_graph) {
+    return guard_ptr("AutogradGrad", [&] {
+        if (num_outputs <= 0 || num_inputs <= 0) {
+            throw std::invalid_argument("grad exige ao menos uma saída e uma entrada");
+        }
+        torch::autograd::variable_list outs, ins, grads;
+        for (int i = 0; i < num_outputs; ++i) {
+            outs.push_back(tensor_ref(outputs[i], "outputs[i]"));
+            if (grad_outputs) {
+                grads.push_back(tensor_ref(grad_outputs[i], "grad_outputs[i]"));
+            }
         }
-        at::Tensor* result = new at::Tensor(torch::pow(*tensor, exponent));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorPow: %s\n", e.what());
-        return nullptr;
-    }
+        for (int i = 0; i < num_inputs; ++i) {
+            ins.push_back(tensor_ref(inputs[i], "inputs[i]"));
+        }
+        auto result = torch::autograd::grad(outs, ins, grads, retain_graph, create_graph, /*allow_unused=*/true);
+        for (size_t i = 0; i < result.size(); ++i) {
+            if (!result[i].defined()) {
+                result[i] = torch::zeros_like(ins[i]);
+            }
+        }
+        return static_cast<void*>(new torch::autograd::variable_list(std::move(result)));
+    });
 }
 
-// Tensor Creation Functions
-EXPORT void* CreateTensorRandn(int rows, int cols) {
-    try {
-        if (rows <= 0 || cols <= 0) {
-            fprintf(stderr, "Erro: Dimensões inválidas em CreateTensorRandn\n");
-            return nullptr;
+EXPORT int AutogradContextNeedsInputGrad(void* ctx_ptr, int index) {
+    return guard_value("AutogradContextNeedsInputGrad", -1, [&] {
+        return context_ref(ctx_ptr).needs_input_grad(static_cast<size_t>(index)) ? 1 : 0;
+    });
+}
+
+EXPORT void OptimizerStep(void* optimizer_ptr) {
+    guard_void("OptimizerStep", [&] {
+        auto* optimizer = static_cast<torch::optim::Optimizer*>(optimizer_ptr);
+        if (!optimizer) {
+            throw std::invalid_argument("otimizador inválido");
         }
-        at::Tensor* tensor = new at::Tensor(torch::randn({rows, cols}, torch::kFloat32));
-        return static_cast<void*>(tensor);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em CreateTensorRandn: %s\n", e.what());
-        return nullptr;
-    }
+        optimizer->step();
+        optimizer->zero_grad();  // Reset gradients after step
+    });
 }
 
-EXPORT void* CreateTensorZeros(int rows, int cols) {
-    try {
-        if (rows <= 0 || cols <= 0) {
-            fprintf(stderr, "Erro: Dimensões inválidas em CreateTensorZeros\n");
-            return nullptr;
+EXPORT void OptimizerZeroGrad(void* optimizer_ptr) {
+    guard_void("OptimizerZeroGrad", [&] {
+        auto* optimizer = static_cast<torch::optim::Optimizer*>(optimizer_ptr);
+        if (!optimizer) {
+            throw std::invalid_argument("otimizador inválido");
         }
-        at::Tensor* tensor = new at::Tensor(torch::zeros({rows, cols}, torch::kFloat32));
-        return static_cast<void*>(tensor);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em CreateTensorZeros: %s\n", e.what());
-        return nullptr;
+        optimizer->zero_grad();
+    });
+}
+
+EXPORT void FreeOptimizer(void* ptr) {
+    guard_void("FreeOptimizer", [&] {
+        delete static_cast<torch::optim::Optimizer*>(ptr);
+    });
+}
+
+EXPORT void* CreateTensorFromData(const void* values, const int64_t* dims, int ndim, int dtype) {
+    return guard_ptr("CreateTensorFromData", [&]() -> void* {
+        at::IntArrayRef shape = shape_ref(dims, ndim);
+        if (!values) {
+            throw std::invalid_argument("ponteiro de valores nulo");
+        }
+        at::TensorOptions options = at::TensorOptions().dtype(scalar_type(dtype));
+        return wrap_tensor(torch::from_blob(const_cast<void*>(values), shape, options).clone());
+    });
+}
+
+EXPORT void* CreateTensorOnes(const int64_t* dims, int ndim, int dtype) {
+    return guard_ptr("CreateTensorOnes", [&] {
+        return wrap_tensor(torch::ones(shape_ref(dims, ndim), scalar_type(dtype)));
+    });
+}
+
+EXPORT void* CreateTensorRand(const int64_t* dims, int ndim, int dtype) {
+    return guard_ptr("CreateTensorRand", [&] {
+        return wrap_tensor(torch::rand(shape_ref(dims, ndim), scalar_type(dtype)));
+    });
+}
+
+// Semente do gerador padrão do LibTorch (rand, randn, dropout, inicializações)
+EXPORT void ManualSeed(uint64_t seed) {
+    guard_void("ManualSeed", [&] {
+        torch::manual_seed(seed);
+    });
+}
+
+EXPORT void FreeTensor(void* ptr) {
+    guard_void("FreeTensor", [&] {
+        delete static_cast<at::Tensor*>(ptr);
+    });
+}
+
+EXPORT void* TensorData(void* ptr) {
+    return guard_value<void*>("TensorData", nullptr, [&] {
+        return tensor_ref(ptr, "tensor").data_ptr();
+    });
+}
+
+EXPORT int TensorDType(void* ptr) {
+    return guard_value("TensorDType", -1, [&] {
+        return dtype_code(tensor_ref(ptr, "tensor").scalar_type());
+    });
+}
+
+// Sempre devolve uma cópia, mesmo quando o dtype não muda
+EXPORT void* TensorToDType(void* ptr, int dtype) {
+    return guard_ptr("TensorToDType", [&] {
+        return wrap_tensor(tensor_ref(ptr, "tensor").to(scalar_type(dtype), false, true, at::MemoryFormat::Contiguous));
+    });
+}
+
+EXPORT int TensorDim(void* ptr) {
+    return guard_value("TensorDim", -1, [&] {
+        return static_cast<int>(tensor_ref(ptr, "tensor").dim());
+    });
+}
+
+// Copia a forma do tensor para `out`, que deve ter espaço para TensorDim elementos
+EXPORT void TensorSizes(void* ptr, int64_t* out) {
+    guard_void("TensorSizes", [&] {
+        at::IntArrayRef sizes = tensor_ref(ptr, "tensor").sizes();
+        std::copy(sizes.begin(), sizes.end(), out);
+    });
+}
+
+EXPORT int64_t TensorNumel(void* ptr) {
+    return guard_value<int64_t>("TensorNumel", -1, [&] {
+        return tensor_ref(ptr, "tensor").numel();
+    });
+}
+
+// Copia os strides (em elementos) para `out`, que deve ter espaço para TensorDim elementos
+EXPORT void TensorStrides(void* ptr, int64_t* out) {
+    guard_void("TensorStrides", [&] {
+        at::IntArrayRef strides = tensor_ref(ptr, "tensor").strides();
+        std::copy(strides.begin(), strides.end(), out);
+    });
+}
+
+EXPORT int TensorIsContiguous(void* ptr) {
+    return guard_value("TensorIsContiguous", -1, [&] {
+        return tensor_ref(ptr, "tensor").is_contiguous() ? 1 : 0;
+    });
+}
+
+// Views and Indexing
+// narrow, select e slice devolvem views do ATen que compartilham o storage
+EXPORT void* TensorContiguous(void* ptr) {
+    return guard_ptr("TensorContiguous", [&] {
+        return wrap_tensor(tensor_ref(ptr, "tensor").contiguous());
+    });
+}
+
+EXPORT void* TensorNarrow(void* ptr, int64_t dim, int64_t start, int64_t length) {
+    return guard_ptr("TensorNarrow", [&] {
+        return wrap_tensor(tensor_ref(ptr, "tensor").narrow(dim, start, length));
+    });
+}
+
+EXPORT void* TensorSelect(void* ptr, int64_t dim, int64_t index) {
+    return guard_ptr("TensorSelect", [&] {
+        return wrap_tensor(tensor_ref(ptr, "tensor").select(dim, index));
+    });
+}
+
+EXPORT void* TensorSlice(void* ptr, int64_t dim, int64_t start, int64_t end, int64_t step) {
+    return guard_ptr("TensorSlice", [&] {
+        return wrap_tensor(tensor_ref(ptr, "tensor").slice(dim, start, end, step));
+    });
+}
+
+// index_select e masked_select copiam os elementos selecionados
+EXPORT void* TensorIndexSelect(void* ptr, int64_t dim, void* index_ptr) {
+    return guard_ptr("TensorIndexSelect", [&] {
+        return wrap_tensor(tensor_ref(ptr, "tensor").index_select(dim, tensor_ref(index_ptr, "index")));
+    });
+}
+
+EXPORT void* TensorMaskedSelect(void* ptr, void* mask_ptr) {
+    return guard_ptr("TensorMaskedSelect", [&] {
+        return wrap_tensor(tensor_ref(ptr, "tensor").masked_select(tensor_ref(mask_ptr, "mask")));
+    });
+}
+
+// Shape Functions
+EXPORT void* TensorReshape(void* tensor_ptr, const int64_t* dims, int ndim) {
+    return guard_ptr("TensorReshape", [&] {
+        if (ndim < 0 || (ndim > 0 && !dims)) {
+            throw std::invalid_argument("forma inválida");
+        }
+        at::IntArrayRef shape(dims, static_cast<size_t>(ndim));
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").reshape(shape).contiguous());
+    });
+}
+
+EXPORT void* TensorTranspose(void* tensor_ptr, int64_t dim0, int64_t dim1) {
+    return guard_ptr("TensorTranspose", [&] {
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").transpose(dim0, dim1).contiguous());
+    });
+}
+
+// Activation Functions
+EXPORT void* TensorReLU(void* tensor_ptr) {
+    return guard_ptr("TensorReLU", [&] {
+        return wrap_tensor(torch::relu(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorSigmoid(void* tensor_ptr) {
+    return guard_ptr("TensorSigmoid", [&] {
+        return wrap_tensor(torch::sigmoid(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorTanh(void* tensor_ptr) {
+    return guard_ptr("TensorTanh", [&] {
+        return wrap_tensor(torch::tanh(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorSoftmax(void* tensor_ptr, int64_t dim) {
+    return guard_ptr("TensorSoftmax", [&] {
+        return wrap_tensor(torch::softmax(tensor_ref(tensor_ptr, "tensor"), dim));
+    });
+}
+
+EXPORT void* TensorLogSoftmax(void* tensor_ptr, int64_t dim) {
+    return guard_ptr("TensorLogSoftmax", [&] {
+        return wrap_tensor(torch::log_softmax(tensor_ref(tensor_ptr, "tensor"), dim));
+    });
+}
+
+// GELU exata (com erf), o padrão do PyTorch
+EXPORT void* TensorGelu(void* tensor_ptr) {
+    return guard_ptr("TensorGelu", [&] {
+        return wrap_tensor(torch::gelu(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorSilu(void* tensor_ptr) {
+    return guard_ptr("TensorSilu", [&] {
+        return wrap_tensor(torch::silu(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorLeakyRelu(void* tensor_ptr, double negative_slope) {
+    return guard_ptr("TensorLeakyRelu", [&] {
+        return wrap_tensor(torch::leaky_relu(tensor_ref(tensor_ptr, "tensor"), negative_slope));
+    });
+}
+
+EXPORT void* TensorElu(void* tensor_ptr, double alpha) {
+    return guard_ptr("TensorElu", [&] {
+        return wrap_tensor(torch::elu(tensor_ref(tensor_ptr, "tensor"), alpha));
+    });
+}
+
+EXPORT void* TensorSelu(void* tensor_ptr) {
+    return guard_ptr("TensorSelu", [&] {
+        return wrap_tensor(torch::selu(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorSoftplus(void* tensor_ptr) {
+    return guard_ptr("TensorSoftplus", [&] {
+        return wrap_tensor(torch::softplus(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorMish(void* tensor_ptr) {
+    return guard_ptr("TensorMish", [&] {
+        return wrap_tensor(torch::mish(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorHardtanh(void* tensor_ptr, double min_val, double max_val) {
+    return guard_ptr("TensorHardtanh", [&] {
+        return wrap_tensor(torch::hardtanh(tensor_ref(tensor_ptr, "tensor"), min_val, max_val));
+    });
+}
+
+EXPORT void* TensorRelu6(void* tensor_ptr) {
+    return guard_ptr("TensorRelu6", [&] {
+        return wrap_tensor(torch::hardtanh(tensor_ref(tensor_ptr, "tensor"), 0.0, 6.0));
+    });
+}
+
+// Mathematical Functions
+EXPORT void* TensorSin(void* tensor_ptr) {
+    return guard_ptr("TensorSin", [&] {
+        return wrap_tensor(torch::sin(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorCos(void* tensor_ptr) {
+    return guard_ptr("TensorCos", [&] {
+        return wrap_tensor(torch::cos(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorExp(void* tensor_ptr) {
+    return guard_ptr("TensorExp", [&] {
+        return wrap_tensor(torch::exp(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorLog(void* tensor_ptr) {
+    return guard_ptr("TensorLog", [&] {
+        return wrap_tensor(torch::log(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorSqrt(void* tensor_ptr) {
+    return guard_ptr("TensorSqrt", [&] {
+        return wrap_tensor(torch::sqrt(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorAbs(void* tensor_ptr) {
+    return guard_ptr("TensorAbs", [&] {
+        return wrap_tensor(torch::abs(tensor_ref(tensor_ptr, "tensor")));
+    });
+}
+
+EXPORT void* TensorPow(void* tensor_ptr, float exponent) {
+    return guard_ptr("TensorPow", [&] {
+        return wrap_tensor(torch::pow(tensor_ref(tensor_ptr, "tensor"), exponent));
+    });
+}
+
+// Arithmetic Functions (broadcasting do ATen)
+EXPORT void* TensorAdd(void* a_ptr, void* b_ptr) {
+    return guard_ptr("TensorAdd", [&] {
+        return wrap_tensor(torch::add(tensor_ref(a_ptr, "a"), tensor_ref(b_ptr, "b")));
+    });
+}
+
+EXPORT void* TensorSub(void* a_ptr, void* b_ptr) {
+    return guard_ptr("TensorSub", [&] {
+        return wrap_tensor(torch::sub(tensor_ref(a_ptr, "a"), tensor_ref(b_ptr, "b")));
+    });
+}
+
+EXPORT void* TensorMul(void* a_ptr, void* b_ptr) {
+    return guard_ptr("TensorMul", [&] {
+        return wrap_tensor(torch::mul(tensor_ref(a_ptr, "a"), tensor_ref(b_ptr, "b")));
+    });
+}
+
+EXPORT void* TensorDiv(void* a_ptr, void* b_ptr) {
+    return guard_ptr("TensorDiv", [&] {
+        return wrap_tensor(torch::div(tensor_ref(a_ptr, "a"), tensor_ref(b_ptr, "b")));
+    });
+}
+
+EXPORT void* TensorAddScalar(void* tensor_ptr, double value) {
+    return guard_ptr("TensorAddScalar", [&] {
+        return wrap_tensor(torch::add(tensor_ref(tensor_ptr, "tensor"), value));
+    });
+}
+
+EXPORT void* TensorSubScalar(void* tensor_ptr, double value) {
+    return guard_ptr("TensorSubScalar", [&] {
+        return wrap_tensor(torch::sub(tensor_ref(tensor_ptr, "tensor"), value));
+    });
+}
+
+// value - tensor
+EXPORT void* TensorRSubScalar(void* tensor_ptr, double value) {
+    return guard_ptr("TensorRSubScalar", [&] {
+        return wrap_tensor(torch::rsub(tensor_ref(tensor_ptr, "tensor"), value));
+    });
+}
+
+EXPORT void* TensorMulScalar(void* tensor_ptr, double value) {
+    return guard_ptr("TensorMulScalar", [&] {
+        return wrap_tensor(torch::mul(tensor_ref(tensor_ptr, "tensor"), value));
+    });
+}
+
+EXPORT void* TensorDivScalar(void* tensor_ptr, double value) {
+    return guard_ptr("TensorDivScalar", [&] {
+        return wrap_tensor(torch::div(tensor_ref(tensor_ptr, "tensor"), value));
+    });
+}
+
+// value / tensor
+EXPORT void* TensorRDivScalar(void* tensor_ptr, double value) {
+    return guard_ptr("TensorRDivScalar", [&] {
+        at::Tensor& tensor = tensor_ref(tensor_ptr, "tensor");
+        return wrap_tensor(torch::div(torch::scalar_tensor(value, tensor.options()), tensor));
+    });
+}
+
+// Matrix Functions
+EXPORT void* TensorMatmul(void* a_ptr, void* b_ptr) {
+    return guard_ptr("TensorMatmul", [&] {
+        return wrap_tensor(torch::matmul(tensor_ref(a_ptr, "a"), tensor_ref(b_ptr, "b")));
+    });
+}
+
+EXPORT void* TensorBmm(void* a_ptr, void* b_ptr) {
+    return guard_ptr("TensorBmm", [&] {
+        return wrap_tensor(torch::bmm(tensor_ref(a_ptr, "a"), tensor_ref(b_ptr, "b")));
+    });
+}
+
+// Joining
+namespace {
+
+std::vector<at::Tensor> tensor_list(void* const* tensors, int count) {
+    if (count <= 0 || !tensors) {
+        throw std::invalid_argument("lista de tensores vazia");
     }
+    std::vector<at::Tensor> list;
+    list.reserve(static_cast<size_t>(count));
+    for (int i = 0; i < count; ++i) {
+        list.push_back(tensor_ref(tensors[i], "tensors[i]"));
+    }
+    return list;
+}
+
+} // namespace
+
+EXPORT void* TensorCat(void* const* tensors, int count, int64_t dim) {
+    return guard_ptr("TensorCat", [&] {
+        return wrap_tensor(torch::cat(tensor_list(tensors, count), dim));
+    });
+}
+
+EXPORT void* TensorStack(void* const* tensors, int count, int64_t dim) {
+    return guard_ptr("TensorStack", [&] {
+        return wrap_tensor(torch::stack(tensor_list(tensors, count), dim));
+    });
+}
+
+// In-place Arithmetic (o resultado precisa caber na forma e no dtype de `a`)
+EXPORT void TensorAddInPlace(void* a_ptr, void* b_ptr) {
+    guard_void("TensorAddInPlace", [&] {
+        tensor_ref(a_ptr, "a").add_(tensor_ref(b_ptr, "b"));
+    });
+}
+
+EXPORT void TensorSubInPlace(void* a_ptr, void* b_ptr) {
+    guard_void("TensorSubInPlace", [&] {
+        tensor_ref(a_ptr, "a").sub_(tensor_ref(b_ptr, "b"));
+    });
+}
+
+EXPORT void TensorMulInPlace(void* a_ptr, void* b_ptr) {
+    guard_void("TensorMulInPlace", [&] {
+        tensor_ref(a_ptr, "a").mul_(tensor_ref(b_ptr, "b"));
+    });
+}
+
+EXPORT void TensorDivInPlace(void* a_ptr, void* b_ptr) {
+    guard_void("TensorDivInPlace", [&] {
+        tensor_ref(a_ptr, "a").div_(tensor_ref(b_ptr, "b"));
+    });
+}
+
+EXPORT void TensorAddScalarInPlace(void* tensor_ptr, double value) {
+    guard_void("TensorAddScalarInPlace", [&] {
+        tensor_ref(tensor_ptr, "tensor").add_(value);
+    });
+}
+
+EXPORT void TensorSubScalarInPlace(void* tensor_ptr, double value) {
+    guard_void("TensorSubScalarInPlace", [&] {
+        tensor_ref(tensor_ptr, "tensor").sub_(value);
+    });
+}
+
+EXPORT void TensorMulScalarInPlace(void* tensor_ptr, double value) {
+    guard_void("TensorMulScalarInPlace", [&] {
+        tensor_ref(tensor_ptr, "tensor").mul_(value);
+    });
+}
+
+EXPORT void TensorDivScalarInPlace(void* tensor_ptr, double value) {
+    guard_void("TensorDivScalarInPlace", [&] {
+        tensor_ref(tensor_ptr, "tensor").div_(value);
+    });
+}
+
+// Initialization
+// Preenchimentos no lugar usados pelo módulo init do Rust; rodam sem autograd para
+// poderem alterar parâmetros folha que exigem gradiente
+EXPORT void TensorUniformInPlace(void* tensor_ptr, double low, double high) {
+    guard_void("TensorUniformInPlace", [&] {
+        torch::NoGradGuard no_grad;
+        tensor_ref(tensor_ptr, "tensor").uniform_(low, high);
+    });
+}
+
+EXPORT void TensorNormalInPlace(void* tensor_ptr, double mean, double std) {
+    guard_void("TensorNormalInPlace", [&] {
+        torch::NoGradGuard no_grad;
+        tensor_ref(tensor_ptr, "tensor").normal_(mean, std);
+    });
+}
+
+EXPORT void TensorFillInPlace(void* tensor_ptr, double value) {
+    guard_void("TensorFillInPlace", [&] {
+        torch::NoGradGuard no_grad;
+        tensor_ref(tensor_ptr, "tensor").fill_(value);
+    });
+}
+
+// Normal truncada em [a, b] por amostragem inversa da CDF, como o trunc_normal_ do PyTorch
+EXPORT void TensorTruncNormalInPlace(void* tensor_ptr, double mean, double std, double a, double b) {
+    guard_void("TensorTruncNormalInPlace", [&] {
+        if (!(std > 0.0) || !(a < b)) {
+            throw std::invalid_argument("trunc_normal exige std > 0 e a < b");
+        }
+        torch::NoGradGuard no_grad;
+        auto norm_cdf = [](double x) { return (1.0 + std::erf(x / std::sqrt(2.0))) / 2.0; };
+        double low = norm_cdf((a - mean) / std);
+        double high = norm_cdf((b - mean) / std);
+        at::Tensor& tensor = tensor_ref(tensor_ptr, "tensor");
+        tensor.uniform_(2.0 * low - 1.0, 2.0 * high - 1.0);
+        tensor.erfinv_();
+        tensor.mul_(std * std::sqrt(2.0));
+        tensor.add_(mean);
+        tensor.clamp_(a, b);
+    });
+}
+
+EXPORT void TensorOrthogonalInPlace(void* tensor_ptr, double gain) {
+    guard_void("TensorOrthogonalInPlace", [&] {
+        torch::NoGradGuard no_grad;
+        torch::nn::init::orthogonal_(tensor_ref(tensor_ptr, "tensor"), gain);
+    });
+}
+
+// Tensor Creation Functions
+EXPORT void* CreateTensorRandn(const int64_t* dims, int ndim, int dtype) {
+    return guard_ptr("CreateTensorRandn", [&] {
+        return wrap_tensor(torch::randn(shape_ref(dims, ndim), scalar_type(dtype)));
+    });
+}
+
+EXPORT void* CreateTensorZeros(const int64_t* dims, int ndim, int dtype) {
+    return guard_ptr("CreateTensorZeros", [&] {
+        return wrap_tensor(torch::zeros(shape_ref(dims, ndim), scalar_type(dtype)));
+    });
 }
 
 EXPORT void* CreateTensorEye(int size) {
-    try {
+    return guard_ptr("CreateTensorEye", [&]() -> void* {
         if (size <= 0) {
-            fprintf(stderr, "Erro: Tamanho inválido em CreateTensorEye\n");
-            return nullptr;
+            throw std::invalid_argument("tamanho inválido: " + std::to_string(size));
         }
-        at::Tensor* tensor = new at::Tensor(torch::eye(size, torch::kFloat32));
-        return static_cast<void*>(tensor);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em CreateTensorEye: %s\n", e.what());
-        return nullptr;
-    }
+        return wrap_tensor(torch::eye(size, torch::kFloat32));
+    });
 }
 
 EXPORT void* TensorZerosLike(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorZerosLike\n");
-            return nullptr;
-        }
-        at::Tensor* result = new at::Tensor(torch::zeros_like(*tensor));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorZerosLike: %s\n", e.what());
-        return nullptr;
-    }
+    return guard_ptr("TensorZerosLike", [&] {
+        return wrap_tensor(torch::zeros_like(tensor_ref(tensor_ptr, "tensor")));
+    });
 }
 
 EXPORT void* TensorOnesLike(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorOnesLike\n");
-            return nullptr;
-        }
-        at::Tensor* result = new at::Tensor(torch::ones_like(*tensor));
-        return static_cast<void*>(result);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorOnesLike: %s\n", e.what());
-        return nullptr;
-    }
+    return guard_ptr("TensorOnesLike", [&] {
+        return wrap_tensor(torch::ones_like(tensor_ref(tensor_ptr, "tensor")));
+    });
 }
 
 // Statistical Functions
 EXPORT float TensorStd(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorStd\n");
-            return 0.0f;
-        }
-        return tensor->std().item<float>();
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorStd: %s\n", e.what());
-        return 0.0f;
-    }
+    return guard_value("TensorStd", 0.0f, [&] {
+        return tensor_ref(tensor_ptr, "tensor").std().item<float>();
+    });
 }
 
 EXPORT float TensorVar(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorVar\n");
-            return 0.0f;
-        }
-        return tensor->var().item<float>();
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorVar: %s\n", e.what());
-        return 0.0f;
+    return guard_value("TensorVar", 0.0f, [&] {
+        return tensor_ref(tensor_ptr, "tensor").var().item<float>();
+    });
+}
+
+// Full Reductions
+EXPORT float TensorProd(void* tensor_ptr) {
+    return guard_value("TensorProd", 0.0f, [&] {
+        return tensor_ref(tensor_ptr, "tensor").prod().item<float>();
+    });
+}
+
+EXPORT float TensorLogsumexp(void* tensor_ptr) {
+    return guard_value("TensorLogsumexp", 0.0f, [&] {
+        at::Tensor& tensor = tensor_ref(tensor_ptr, "tensor");
+        return torch::logsumexp(tensor.reshape({-1}), 0).item<float>();
+    });
+}
+
+EXPORT float TensorNorm(void* tensor_ptr) {
+    return guard_value("TensorNorm", 0.0f, [&] {
+        return tensor_ref(tensor_ptr, "tensor").norm().item<float>();
+    });
+}
+
+EXPORT int TensorAny(void* tensor_ptr) {
+    return guard_value("TensorAny", 0, [&] {
+        return static_cast<int>(tensor_ref(tensor_ptr, "tensor").any().item<bool>());
+    });
+}
+
+EXPORT int TensorAll(void* tensor_ptr) {
+    return guard_value("TensorAll", 0, [&] {
+        return static_cast<int>(tensor_ref(tensor_ptr, "tensor").all().item<bool>());
+    });
+}
+
+// Reductions along a dimension
+EXPORT void* TensorSumDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorSumDim", [&] {
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").sum(dim, keepdim));
+    });
+}
+
+EXPORT void* TensorMeanDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorMeanDim", [&] {
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").mean(dim, keepdim));
+    });
+}
+
+EXPORT void* TensorMaxDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorMaxDim", [&] {
+        return wrap_tensor(std::get<0>(tensor_ref(tensor_ptr, "tensor").max(dim, keepdim)));
+    });
+}
+
+EXPORT void* TensorMinDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorMinDim", [&] {
+        return wrap_tensor(std::get<0>(tensor_ref(tensor_ptr, "tensor").min(dim, keepdim)));
+    });
+}
+
+EXPORT void* TensorStdDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorStdDim", [&] {
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").std(dim, /*unbiased=*/true, keepdim));
+    });
+}
+
+EXPORT void* TensorVarDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorVarDim", [&] {
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").var(dim, /*unbiased=*/true, keepdim));
+    });
+}
+
+EXPORT void* TensorArgmaxDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorArgmaxDim", [&] {
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").argmax(dim, keepdim));
+    });
+}
+
+EXPORT void* TensorArgminDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorArgminDim", [&] {
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").argmin(dim, keepdim));
+    });
+}
+
+EXPORT void* TensorProdDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorProdDim", [&] {
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").prod(dim, keepdim));
+    });
+}
+
+EXPORT void* TensorLogsumexpDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorLogsumexpDim", [&] {
+        return wrap_tensor(torch::logsumexp(tensor_ref(tensor_ptr, "tensor"), dim, keepdim));
+    });
+}
+
+EXPORT void* TensorNormDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorNormDim", [&] {
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").norm(2, dim, keepdim));
+    });
+}
+
+EXPORT void* TensorAnyDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorAnyDim", [&] {
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").any(dim, keepdim));
+    });
+}
+
+EXPORT void* TensorAllDim(void* tensor_ptr, int64_t dim, bool keepdim) {
+    return guard_ptr("TensorAllDim", [&] {
+        return wrap_tensor(tensor_ref(tensor_ptr, "tensor").all(dim, keepdim));
+    });
+}
+
+// Loss Functions
+// `weight_ptr` (peso por classe ou por elemento) pode ser nullptr; alvos iguais a
+// `ignore_index` não contam na perda nem no denominador da média
+EXPORT void* CrossEntropyLoss(
+    void* prediction_tensor_ptr,
+    void* target_tensor_ptr,
+    void* weight_ptr,
+    int reduction,
+    int64_t ignore_index) {
+    return guard_ptr("CrossEntropyLoss", [&] {
+        at::Tensor& prediction = tensor_ref(prediction_tensor_ptr, "prediction");
+        at::Tensor& target = tensor_ref(target_tensor_ptr, "target");
+        return wrap_tensor(torch::cross_entropy_loss(
+            prediction, target, optional_tensor(weight_ptr, "weight"), reduction_code(reduction), ignore_index));
+    });
+}
+
+EXPORT void* L1Loss(void* prediction_tensor_ptr, void* target_tensor_ptr, int reduction) {
+    return guard_ptr("L1Loss", [&] {
+        at::Tensor& prediction = tensor_ref(prediction_tensor_ptr, "prediction");
+        at::Tensor& target = tensor_ref(target_tensor_ptr, "target");
+        return wrap_tensor(at::l1_loss(prediction, target, reduction_code(reduction)));
+    });
+}
+
+EXPORT void* SmoothL1Loss(void* prediction_tensor_ptr, void* target_tensor_ptr, int reduction, double beta) {
+    return guard_ptr("SmoothL1Loss", [&] {
+        at::Tensor& prediction = tensor_ref(prediction_tensor_ptr, "prediction");
+        at::Tensor& target = tensor_ref(target_tensor_ptr, "target");
+        return wrap_tensor(at::smooth_l1_loss(prediction, target, reduction_code(reduction), beta));
+    });
+}
+
+EXPORT void* HuberLoss(void* prediction_tensor_ptr, void* target_tensor_ptr, int reduction, double delta) {
+    return guard_ptr("HuberLoss", [&] {
+        at::Tensor& prediction = tensor_ref(prediction_tensor_ptr, "prediction");
+        at::Tensor& target = tensor_ref(target_tensor_ptr, "target");
+        return wrap_tensor(at::huber_loss(prediction, target, reduction_code(reduction), delta));
+    });
+}
+
+EXPORT void* BCELoss(void* prediction_tensor_ptr, void* target_tensor_ptr, void* weight_ptr, int reduction) {
+    return guard_ptr("BCELoss", [&] {
+        at::Tensor& prediction = tensor_ref(prediction_tensor_ptr, "prediction");
+        at::Tensor& target = tensor_ref(target_tensor_ptr, "target");
+        return wrap_tensor(at::binary_cross_entropy(
+            prediction, target, optional_tensor(weight_ptr, "weight"), reduction_code(reduction)));
+    });
+}
+
+// `pos_weight` (um peso por classe) multiplica o termo dos exemplos positivos
+EXPORT void* BCEWithLogitsLoss(
+    void* prediction_tensor_ptr,
+    void* target_tensor_ptr,
+    void* weight_ptr,
+    void* pos_weight_ptr,
+    int reduction) {
+    return guard_ptr("BCEWithLogitsLoss", [&] {
+        at::Tensor& prediction = tensor_ref(prediction_tensor_ptr, "prediction");
+        at::Tensor& target = tensor_ref(target_tensor_ptr, "target");
+        return wrap_tensor(at::binary_cross_entropy_with_logits(
+            prediction,
+            target,
+            optional_tensor(weight_ptr, "weight"),
+            optional_tensor(pos_weight_ptr, "pos_weight"),
+            reduction_code(reduction)));
+    });
+}
+
+// Entrada [N, C, *] (log-probabilidades) ou [C]; alvo [N, *] ou escalar
+EXPORT void* NLLLoss(
+    void* prediction_tensor_ptr,
+    void* target_tensor_ptr,
+    void* weight_ptr,
+    int reduction,
+    int64_t ignore_index) {
+    return guard_ptr("NLLLoss", [&] {
+        at::Tensor& prediction = tensor_ref(prediction_tensor_ptr, "prediction");
+        at::Tensor& target = tensor_ref(target_tensor_ptr, "target");
+        return wrap_tensor(at::nll_loss_nd(
+            prediction, target, optional_tensor(weight_ptr, "weight"), reduction_code(reduction), ignore_index));
+    });
+}
+
+// A entrada são log-probabilidades; o alvo também, quando `log_target` é verdadeiro
+EXPORT void* KLDivLoss(void* prediction_tensor_ptr, void* target_tensor_ptr, int reduction, bool log_target) {
+    return guard_ptr("KLDivLoss", [&] {
+        at::Tensor& prediction = tensor_ref(prediction_tensor_ptr, "prediction");
+        at::Tensor& target = tensor_ref(target_tensor_ptr, "target");
+        return wrap_tensor(at::kl_div(prediction, target, reduction_code(reduction), log_target));
+    });
+}
+
+// `target` com 1 (par semelhante) ou -1 (par diferente)
+EXPORT void* CosineEmbeddingLoss(
+    void* input1_ptr,
+    void* input2_ptr,
+    void* target_tensor_ptr,
+    double margin,
+    int reduction) {
+    return guard_ptr("CosineEmbeddingLoss", [&] {
+        return wrap_tensor(at::cosine_embedding_loss(
+            tensor_ref(input1_ptr, "input1"),
+            tensor_ref(input2_ptr, "input2"),
+            tensor_ref(target_tensor_ptr, "target"),
+            margin,
+            reduction_code(reduction)));
+    });
+}
+
+// `target` com 1 (input1 deve ficar acima) ou -1 (input2 deve ficar acima)
+EXPORT void* MarginRankingLoss(
+    void* input1_ptr,
+    void* input2_ptr,
+    void* target_tensor_ptr,
+    double margin,
+    int reduction) {
+    return guard_ptr("MarginRankingLoss", [&] {
+        return wrap_tensor(at::margin_ranking_loss(
+            tensor_ref(input1_ptr, "input1"),
+            tensor_ref(input2_ptr, "input2"),
+            tensor_ref(target_tensor_ptr, "target"),
+            margin,
+            reduction_code(reduction)));
+    });
+}
+
+EXPORT void* TripletMarginLoss(
+    void* anchor_ptr,
+    void* positive_ptr,
+    void* negative_ptr,
+    double margin,
+    double p,
+    double eps,
+    bool swap,
+    int reduction) {
+    return guard_ptr("TripletMarginLoss", [&] {
+        return wrap_tensor(at::triplet_margin_loss(
+            tensor_ref(anchor_ptr, "anchor"),
+            tensor_ref(positive_ptr, "positive"),
+            tensor_ref(negative_ptr, "negative"),
+            margin,
+            p,
+            eps,
+            swap,
+            reduction_code(reduction)));
+    });
+}
+
+// `log_probs` [T, N, C]; `targets` [N, S] (com padding) ou 1-D com os alvos concatenados.
+// Na média, a perda de cada amostra é dividida pelo tamanho do seu alvo.
+EXPORT void* CTCLoss(
+    void* log_probs_ptr,
+    void* targets_ptr,
+    const int64_t* input_lengths,
+    const int64_t* target_lengths,
+    int batch_size,
+    int64_t blank,
+    int reduction) {
+    return guard_ptr("CTCLoss", [&] {
+        const at::Tensor& log_probs = tensor_ref(log_probs_ptr, "log_probs");
+        if (!input_lengths || !target_lengths) {
+            throw std::invalid_argument("comprimentos de entrada ou de alvo nulos");
+        }
+        if (log_probs.dim() != 3 || log_probs.size(1) != batch_size) {
+            throw std::invalid_argument(
+                "log_probs deve ter forma [T, N, C] com N = " + std::to_string(batch_size) +
+                ", recebeu " + shape_string(log_probs));
+        }
+        return wrap_tensor(at::ctc_loss(
+            log_probs,
+            tensor_ref(targets_ptr, "targets"),
+            at::IntArrayRef(input_lengths, batch_size),
+            at::IntArrayRef(target_lengths, batch_size),
+            blank,
+            reduction_code(reduction),
+            false));
+    });
+}
+
+// Optimizers
+// Os parâmetros chegam achatados: o grupo g tem os `group_sizes[g]` tensores seguintes
+// de `params` e taxa de aprendizado `group_lrs[g]`; os demais hiperparâmetros valem
+// para todos os grupos.
+namespace {
+
+torch::optim::Optimizer& optimizer_ref(void* ptr) {
+    if (!ptr) {
+        throw std::invalid_argument("otimizador inválido");
     }
+    return *static_cast<torch::optim::Optimizer*>(ptr);
 }
 
-EXPORT int TensorArgmax(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorArgmax\n");
-            return -1;
-        }
-        return tensor->argmax().item<int>();
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorArgmax: %s\n", e.what());
-        return -1;
+template <typename OptimizerT, typename Options>
+void* create_optimizer(
+    void* const* params,
+    const int* group_sizes,
+    const double* group_lrs,
+    int num_groups,
+    const Options& defaults) {
+    if (num_groups <= 0 || !group_sizes || !group_lrs) {
+        throw std::invalid_argument("nenhum grupo de parâmetros");
     }
+    if (!params) {
+        throw std::invalid_argument("lista de parâmetros nula");
+    }
+    std::vector<torch::optim::OptimizerParamGroup> groups;
+    int offset = 0;
+    for (int g = 0; g < num_groups; ++g) {
+        if (group_sizes[g] < 0) {
+            throw std::invalid_argument(
+                "tamanho negativo no grupo de parâmetros " + std::to_string(g) + ": " + std::to_string(group_sizes[g]));
+        }
+        std::vector<at::Tensor> group_params;
+        for (int i = 0; i < group_sizes[g]; ++i) {
+            group_params.push_back(tensor_ref(params[offset + i], "params[i]"));
+        }
+        offset += group_sizes[g];
+        auto options = std::make_unique<Options>(defaults);
+        options->lr(group_lrs[g]);
+        groups.emplace_back(std::move(group_params), std::move(options));
+    }
+    return static_cast<void*>(new OptimizerT(std::move(groups), defaults));
 }
 
-EXPORT int TensorArgmin(void* tensor_ptr) {
-    try {
-        auto* tensor = static_cast<at::Tensor*>(tensor_ptr);
-        if (!tensor) {
-            fprintf(stderr, "Erro: Tensor inválido em TensorArgmin\n");
-            return -1;
+// O LibTorch C++ não tem Adadelta; esta implementação segue torch.optim.Adadelta
+struct AdadeltaOptions : public torch::optim::OptimizerCloneableOptions<AdadeltaOptions> {
+    AdadeltaOptions(double lr = 1.0) : lr_(lr) {}
+    TORCH_ARG(double, lr);
+    TORCH_ARG(double, rho) = 0.9;
+    TORCH_ARG(double, eps) = 1e-6;
+    TORCH_ARG(double, weight_decay) = 0;
+
+public:
+    double get_lr() const override {
+        return lr();
+    }
+
+    void set_lr(const double lr) override {
+        this->lr(lr);
+    }
+};
+
+struct AdadeltaParamState : public torch::optim::OptimizerCloneableParamState<AdadeltaParamState> {
+    TORCH_ARG(at::Tensor, square_avg);
+    TORCH_ARG(at::Tensor, acc_delta);
+};
+
+class Adadelta : public torch::optim::Optimizer {
+public:
+    Adadelta(std::vector<torch::optim::OptimizerParamGroup> param_groups, AdadeltaOptions defaults)
+        : torch::optim::Optimizer(std::move(param_groups), std::make_unique<AdadeltaOptions>(defaults)) {}
+
+    at::Tensor step(LossClosure closure = nullptr) override {
+        torch::NoGradGuard no_grad;
+        at::Tensor loss;
+        if (closure) {
+            at::AutoGradMode enable_grad(true);
+            loss = closure();
+        }
+        for (auto& group : param_groups_) {
+            auto& options = static_cast<AdadeltaOptions&>(group.options());
+            for (auto& param : group.params()) {
+                if (!param.grad().defined()) {
+                    continue;
+                }
+                auto grad = param.grad();
+                if (options.weight_decay() != 0) {
+                    grad = grad.add(param, options.weight_decay());
+                }
+                auto key = param.unsafeGetTensorImpl();
+                if (state_.find(key) == state_.end()) {
+                    auto state = std::make_unique<AdadeltaParamState>();
+                    state->square_avg(torch::zeros_like(param, at::MemoryFormat::Preserve));
+                    state->acc_delta(torch::zeros_like(param, at::MemoryFormat::Preserve));
+                    state_[key] = std::move(state);
+                }
+                auto& state = static_cast<AdadeltaParamState&>(*state_[key]);
+                auto& square_avg = state.square_avg();
+                auto& acc_delta = state.acc_delta();
+                const double rho = options.rho();
+
+                // E[g²] ← ρ·E[g²] + (1-ρ)·g²; Δ = √(E[Δ²] + ε) / √(E[g²] + ε) · g
+                square_avg.mul_(rho).addcmul_(grad, grad, 1 - rho);
+                auto avg_std = square_avg.add(options.eps()).sqrt_();
+                auto delta = acc_delta.add(options.eps()).sqrt_().div_(avg_std).mul_(grad);
+                acc_delta.mul_(rho).addcmul_(delta, delta, 1 - rho);
+                param.add_(delta, -options.lr());
+            }
         }
-        return tensor->argmin().item<int>();
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em TensorArgmin: %s\n", e.what());
-        return -1;
+        return loss;
     }
+};
+
+} // namespace
+
+EXPORT void* CreateSGD(
+    void* const* params,
+    const int* group_sizes,
+    const double* group_lrs,
+    int num_groups,
+    double lr,
+    double momentum,
+    double dampening,
+    double weight_decay,
+    bool nesterov) {
+    return guard_ptr("CreateSGD", [&] {
+        auto defaults = torch::optim::SGDOptions(lr)
+            .momentum(momentum)
+            .dampening(dampening)
+            .weight_decay(weight_decay)
+            .nesterov(nesterov);
+        return create_optimizer<torch::optim::SGD>(params, group_sizes, group_lrs, num_groups, defaults);
+    });
 }
 
-// Loss Functions
-EXPORT void* CrossEntropyLoss(void* prediction_tensor_ptr, void* target_tensor_ptr) {
-    try {
-        auto* prediction = static_cast<at::Tensor*>(prediction_tensor_ptr);
-        auto* target = static_cast<at::Tensor*>(target_tensor_ptr);
-        if (!prediction || !target) {
-            fprintf(stderr, "Erro: Tensores inválidos em CrossEntropyLoss\n");
-            return nullptr;
-        }
-        at::Tensor* loss = new at::Tensor(torch::cross_entropy_loss(*prediction, *target));
-        return static_cast<void*>(loss);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em CrossEntropyLoss: %s\n", e.what());
-        return nullptr;
-    }
+// `decoupled` escolhe AdamW (weight decay aplicado direto nos pesos)
+EXPORT void* CreateAdam(
+    void* const* params,
+    const int* group_sizes,
+    const double* group_lrs,
+    int num_groups,
+    double lr,
+    double beta1,
+    double beta2,
+    double eps,
+    double weight_decay,
+    bool amsgrad,
+    bool decoupled) {
+    return guard_ptr("CreateAdam", [&] {
+        auto betas = std::make_tuple(beta1, beta2);
+        if (decoupled) {
+            auto defaults = torch::optim::AdamWOptions(lr).betas(betas).eps(eps).weight_decay(weight_decay).amsgrad(amsgrad);
+            return create_optimizer<torch::optim::AdamW>(params, group_sizes, group_lrs, num_groups, defaults);
+        }
+        auto defaults = torch::optim::AdamOptions(lr).betas(betas).eps(eps).weight_decay(weight_decay).amsgrad(amsgrad);
+        return create_optimizer<torch::optim::Adam>(params, group_sizes, group_lrs, num_groups, defaults);
+    });
 }
 
-// Adam Optimizer
-EXPORT void* CreateAdam(void* linear_ptr, float lr) {
-    try {
-        auto* linear = static_cast<torch::nn::LinearImpl*>(linear_ptr);
-        if (!linear) {
-            fprintf(stderr, "Erro: Ponteiro linear inválido em CreateAdam\n");
-            return nullptr;
+EXPORT void* CreateRMSprop(
+    void* const* params,
+    const int* group_sizes,
+    const double* group_lrs,
+    int num_groups,
+    double lr,
+    double alpha,
+    double eps,
+    double weight_decay,
+    double momentum,
+    bool centered) {
+    return guard_ptr("CreateRMSprop", [&] {
+        auto defaults = torch::optim::RMSpropOptions(lr)
+            .alpha(alpha)
+            .eps(eps)
+            .weight_decay(weight_decay)
+            .momentum(momentum)
+            .centered(centered);
+        return create_optimizer<torch::optim::RMSprop>(params, group_sizes, group_lrs, num_groups, defaults);
+    });
+}
+
+EXPORT void* CreateAdagrad(
+    void* const* params,
+    const int* group_sizes,
+    const double* group_lrs,
+    int num_groups,
+    double lr,
+    double lr_decay,
+    double weight_decay,
+    double initial_accumulator_value,
+    double eps) {
+    return guard_ptr("CreateAdagrad", [&] {
+        auto defaults = torch::optim::AdagradOptions(lr)
+            .lr_decay(lr_decay)
+            .weight_decay(weight_decay)
+            .initial_accumulator_value(initial_accumulator_value)
+            .eps(eps);
+        return create_optimizer<torch::optim::Adagrad>(params, group_sizes, group_lrs, num_groups, defaults);
+    });
+}
+
+EXPORT void* CreateAdadelta(
+    void* const* params,
+    const int* group_sizes,
+    const double* group_lrs,
+    int num_groups,
+    double lr,
+    double rho,
+    double eps,
+    double weight_decay) {
+    return guard_ptr("CreateAdadelta", [&] {
+        auto defaults = AdadeltaOptions(lr).rho(rho).eps(eps).weight_decay(weight_decay);
+        return create_optimizer<Adadelta>(params, group_sizes, group_lrs, num_groups, defaults);
+    });
+}
+
+// O LBFGS do LibTorch aceita um único grupo e só avança com OptimizerStepClosure;
+// `max_eval` negativo usa o padrão do PyTorch (max_iter · 5/4)
+EXPORT void* CreateLBFGS(
+    void* const* params,
+    const int* group_sizes,
+    const double* group_lrs,
+    int num_groups,
+    double lr,
+    int64_t max_iter,
+    int64_t max_eval,
+    double tolerance_grad,
+    double tolerance_change,
+    int64_t history_size,
+    bool strong_wolfe) {
+    return guard_ptr("CreateLBFGS", [&] {
+        auto defaults = torch::optim::LBFGSOptions(lr)
+            .max_iter(max_iter)
+            .max_eval(max_eval >= 0 ? max_eval : max_iter * 5 / 4)
+            .tolerance_grad(tolerance_grad)
+            .tolerance_change(tolerance_change)
+            .history_size(history_size);
+        if (strong_wolfe) {
+            defaults.line_search_fn("strong_wolfe");
+        }
+        return create_optimizer<torch::optim::LBFGS>(params, group_sizes, group_lrs, num_groups, defaults);
+    });
+}
+
+// A closure Rust devolve a perda em `loss` (uma lista de tensores). Antes de cada
+// avaliação os gradientes são zerados, e depois o backward da perda é executado, então
+// a closure só precisa calcular a perda. Devolve a perda da primeira avaliação.
+typedef int (*CopperClosureFn)(void* state, void* loss);
+
+EXPORT void* OptimizerStepClosure(void* optimizer_ptr, CopperClosureFn closure, void* state) {
+    return guard_ptr("OptimizerStepClosure", [&] {
+        auto& optimizer = optimizer_ref(optimizer_ptr);
+        auto loss = optimizer.step([&]() -> at::Tensor {
+            at::AutoGradMode enable_grad(true);
+            optimizer.zero_grad();
+            torch::autograd::variable_list outputs;
+            check_callback(closure(state, &outputs));
+            if (outputs.size() != 1 || !outputs[0].defined()) {
+                throw std::invalid_argument("a closure do otimizador deve devolver uma perda");
+            }
+            outputs[0].backward();
+            return outputs[0];
+        });
+        optimizer.zero_grad();
+        return wrap_tensor(loss.detach());
+    });
+}
+
+EXPORT int OptimizerNumGroups(void* optimizer_ptr) {
+    return guard_value("OptimizerNumGroups", -1, [&] {
+        return static_cast<int>(optimizer_ref(optimizer_ptr).param_groups().size());
+    });
+}
+
+EXPORT double OptimizerGetLr(void* optimizer_ptr, int group) {
+    return guard_value("OptimizerGetLr", -1.0, [&] {
+        auto& groups = optimizer_ref(optimizer_ptr).param_groups();
+        if (group < 0 || group >= static_cast<int>(groups.size())) {
+            throw std::out_of_range("grupo de parâmetros inexistente: " + std::to_string(group));
         }
-        auto* optimizer = new torch::optim::Adam(linear->parameters(), lr);
-        return static_cast<void*>(optimizer);
-    } catch (const std::exception& e) {
-        fprintf(stderr, "Erro em CreateAdam: %s\n", e.what());
-        return nullptr;
-    }
+        return groups[group].options().get_lr();
+    });
+}
+
+// `group` negativo altera todos os grupos
+EXPORT void OptimizerSetLr(void* optimizer_ptr, int group, double lr) {
+    guard_void("OptimizerSetLr", [&] {
+        auto& groups = optimizer_ref(optimizer_ptr).param_groups();
+        if (group >= static_cast<int>(groups.size())) {
+            throw std::out_of_range("grupo de parâmetros inexistente: " + std::to_string(group));
+        }
+        for (int g = 0; g < static_cast<int>(groups.size()); ++g) {
+            if (group < 0 || g == group) {
+                groups[g].options().set_lr(lr);
+            }
+        }
+    });
 }
 
-} // Fim do extern "C"
\ No newline at end of file
+} // Fim do extern "C"
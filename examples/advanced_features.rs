@@ -8,11 +8,11 @@ fn main() {
     println!("1. CRIAÇÃO DE TENSORES");
     println!("{}", "-".repeat(50));
     
-    let zeros = Tensor::zeros(2, 3);
+    let zeros = Tensor::zeros(&[2, 3]);
     println!("Zeros (2x3):");
     zeros.print();
     
-    let randn = Tensor::randn(2, 3);
+    let randn = Tensor::randn(&[2, 3]);
     println!("\nRandn - Distribuição Normal (2x3):");
     randn.print();
     
@@ -20,7 +20,7 @@ fn main() {
     println!("\nEye - Matriz Identidade (3x3):");
     eye.print();
     
-    let ones = Tensor::ones(2, 2);
+    let ones = Tensor::ones(&[2, 2]);
     let zeros_like = ones.zeros_like();
     println!("\nZeros Like (mesma forma que ones):");
     zeros_like.print();
@@ -29,7 +29,7 @@ fn main() {
     println!("\n2. FUNÇÕES MATEMÁTICAS");
     println!("{}", "-".repeat(50));
     
-    let x = Tensor::from_values(&[0.0, 1.0, 2.0, 3.0], 2, 2);
+    let x = Tensor::from_values(&[0.0, 1.0, 2.0, 3.0], &[2, 2]);
     println!("Tensor original:");
     x.print();
     
@@ -53,7 +53,7 @@ fn main() {
     println!("\n3. FUNÇÕES DE ATIVAÇÃO");
     println!("{}", "-".repeat(50));
     
-    let activations = Tensor::from_values(&[-2.0, -1.0, 0.0, 1.0, 2.0], 1, 5);
+    let activations = Tensor::from_values(&[-2.0, -1.0, 0.0, 1.0, 2.0], &[1, 5]);
     println!("Valores originais:");
     activations.print();
     
@@ -73,7 +73,7 @@ fn main() {
     println!("\n4. ESTATÍSTICAS AVANÇADAS");
     println!("{}", "-".repeat(50));
     
-    let data = Tensor::from_values(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+    let data = Tensor::from_values(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2, 3]);
     println!("Dataset:");
     data.print();
     
@@ -84,8 +84,8 @@ fn main() {
     println!("  Variância: {:.4}", data.var());
     println!("  Máximo: {:.4}", data.max());
     println!("  Mínimo: {:.4}", data.min());
-    println!("  Argmax (índice): {}", data.argmax());
-    println!("  Argmin (índice): {}", data.argmin());
+    println!("  Argmax (índice): {}", data.argmax_index());
+    println!("  Argmin (índice): {}", data.argmin_index());
     
     // ==================== NEURAL NETWORK COM NOVAS FEATURES ====================
     println!("\n5. REDE NEURAL COM NOVAS FEATURES");
@@ -99,8 +99,8 @@ fn main() {
     let optimizer = Optimizer::adam(&layer1, 0.01);
     
     // Dados de treinamento
-    let x_train = Tensor::from_values(&[0.5, 0.3, 0.8, 0.2, 0.1, 0.9], 3, 2);
-    let y_train = Tensor::from_values(&[1.0, 0.5, 0.2], 3, 1);
+    let x_train = Tensor::from_values(&[0.5, 0.3, 0.8, 0.2, 0.1, 0.9], &[3, 2]);
+    let y_train = Tensor::from_values(&[1.0, 0.5, 0.2], &[3, 1]);
     
     println!("Treinando com Adam optimizer...");
     
@@ -34,9 +34,9 @@ fn exemplo_libtorch() {
     println!("--- Exemplo 1: LibTorch ---");
     
     // Criar tensores
-    let t1 = Tensor::ones(2, 3);
-    let t2 = Tensor::rand(2, 3);
-    let t3 = Tensor::from_values(&[1.0, 2.0, 3.0, 4.0], 2, 2);
+    let t1 = Tensor::ones(&[2, 3]);
+    let t2 = Tensor::rand(&[2, 3]);
+    let t3 = Tensor::from_values(&[1.0, 2.0, 3.0, 4.0], &[2, 2]);
     
     println!("Tensor de uns:");
     t1.print();
@@ -69,8 +69,8 @@ fn exemplo_libtorch() {
     t5.print();
     
     // Multiplicação de matrizes
-    let a = Tensor::from_values(&[1.0, 2.0, 3.0, 4.0], 2, 2);
-    let b = Tensor::from_values(&[5.0, 6.0, 7.0, 8.0], 2, 2);
+    let a = Tensor::from_values(&[1.0, 2.0, 3.0, 4.0], &[2, 2]);
+    let b = Tensor::from_values(&[5.0, 6.0, 7.0, 8.0], &[2, 2]);
     let c = a.matmul(&b);
     println!("\nMultiplicação de matrizes:");
     c.print();
@@ -131,11 +131,11 @@ fn exemplo_unificado() {
     let device = Device::CPU;
     
     println!("Usando backend: {}", backend_torch);
-    let t1 = UnifiedTensor::ones(2, 3, backend_torch, device);
+    let t1 = UnifiedTensor::ones(&[2, 3], backend_torch, device);
     t1.print();
     
     println!("\nUsando backend: {}", backend_tf);
-    let t2 = UnifiedTensor::rand(2, 3, backend_tf, device);
+    let t2 = UnifiedTensor::rand(&[2, 3], backend_tf, device);
     t2.print();
     
     // Conversão entre backends
@@ -145,8 +145,8 @@ fn exemplo_unificado() {
     println!("Backend atual: {}", t3.backend());
     
     // Operações unificadas
-    let t4 = UnifiedTensor::from_values(&[1.0, 2.0, 3.0, 4.0], 2, 2, backend_torch, device);
-    let t5 = UnifiedTensor::from_values(&[5.0, 6.0, 7.0, 8.0], 2, 2, backend_torch, device);
+    let t4 = UnifiedTensor::from_values(&[1.0, 2.0, 3.0, 4.0], &[2, 2], backend_torch, device);
+    let t5 = UnifiedTensor::from_values(&[5.0, 6.0, 7.0, 8.0], &[2, 2], backend_torch, device);
     
     println!("\nSoma unificada:");
     let t6 = t4 + t5;
@@ -171,8 +171,8 @@ fn exemplo_treinamento() {
     println!("--- Exemplo 4: Treinamento com LibTorch ---");
     
     // Dados de treinamento: y = 2*x + 1
-    let x = Tensor::from_values(&[1.0, 2.0, 3.0, 4.0], 4, 1);
-    let y = Tensor::from_values(&[3.0, 5.0, 7.0, 9.0], 4, 1);
+    let x = Tensor::from_values(&[1.0, 2.0, 3.0, 4.0], &[4, 1]);
+    let y = Tensor::from_values(&[3.0, 5.0, 7.0, 9.0], &[4, 1]);
     
     // Criar modelo linear
     let linear = Linear::new(1, 1);
@@ -200,7 +200,7 @@ fn exemplo_treinamento() {
     
     // Teste
     println!("\nTestando o modelo:");
-    let test_x = Tensor::from_values(&[5.0, 6.0], 2, 1);
+    let test_x = Tensor::from_values(&[5.0, 6.0], &[2, 1]);
     let pred = linear.forward(&test_x);
     println!("Predições:");
     pred.print();
@@ -215,16 +215,15 @@ mod tests {
 
     #[test]
     fn test_tensor_creation() {
-        let t = Tensor::ones(2, 3);
-        assert_eq!(t.rows, 2);
-        assert_eq!(t.cols, 3);
+        let t = Tensor::ones(&[2, 3]);
+        assert_eq!(t.shape(), &[2, 3]);
         assert_eq!(t.sum(), 6.0);
     }
 
     #[test]
     fn test_tensor_operations() {
-        let t1 = Tensor::from_values(&[1.0, 2.0, 3.0, 4.0], 2, 2);
-        let t2 = Tensor::from_values(&[1.0, 1.0, 1.0, 1.0], 2, 2);
+        let t1 = Tensor::from_values(&[1.0, 2.0, 3.0, 4.0], &[2, 2]);
+        let t2 = Tensor::from_values(&[1.0, 1.0, 1.0, 1.0], &[2, 2]);
         let t3 = t1 + t2;
         
         assert_eq!(t3.as_slice()[0], 2.0);
@@ -233,17 +232,15 @@ mod tests {
 
     #[test]
     fn test_unified_tensor() {
-        let t1 = UnifiedTensor::ones(2, 2, Backend::LibTorch, Device::CPU);
-        let (rows, cols) = t1.shape();
+        let t1 = UnifiedTensor::ones(&[2, 2], Backend::LibTorch, Device::CPU);
         
-        assert_eq!(rows, 2);
-        assert_eq!(cols, 2);
+        assert_eq!(t1.shape(), &[2, 2]);
         assert_eq!(t1.sum(), 4.0);
     }
 
     #[test]
     fn test_backend_conversion() {
-        let t1 = UnifiedTensor::ones(2, 2, Backend::LibTorch, Device::CPU);
+        let t1 = UnifiedTensor::ones(&[2, 2], Backend::LibTorch, Device::CPU);
         let t2 = t1.to_backend(Backend::TensorFlow);
         
         assert_eq!(t2.backend(), Backend::TensorFlow);
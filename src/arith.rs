@@ -0,0 +1,123 @@
+// Arithmetic Operators
+// Gera as implementações de Add/Sub/Mul/Div (e *Assign) a partir dos métodos try_*
+// de cada tipo de tensor, para operandos próprios, emprestados e escalares f32
+
+/// Operador binário para todas as combinações de `T`/`&T` e escalares `f32`
+macro_rules! impl_binary_op {
+    ($ty:ty, $trait:ident, $method:ident, $op:ident, $scalar_op:ident, $reversed_op:ident,
+     $msg:literal, $scalar_msg:literal) => {
+        impl std::ops::$trait<$ty> for $ty {
+            type Output = $ty;
+
+            fn $method(self, other: $ty) -> $ty {
+                self.$op(&other).expect($msg)
+            }
+        }
+
+        impl std::ops::$trait<&$ty> for $ty {
+            type Output = $ty;
+
+            fn $method(self, other: &$ty) -> $ty {
+                self.$op(other).expect($msg)
+            }
+        }
+
+        impl std::ops::$trait<$ty> for &$ty {
+            type Output = $ty;
+
+            fn $method(self, other: $ty) -> $ty {
+                self.$op(&other).expect($msg)
+            }
+        }
+
+        impl std::ops::$trait<&$ty> for &$ty {
+            type Output = $ty;
+
+            fn $method(self, other: &$ty) -> $ty {
+                self.$op(other).expect($msg)
+            }
+        }
+
+        impl std::ops::$trait<f32> for $ty {
+            type Output = $ty;
+
+            fn $method(self, value: f32) -> $ty {
+                self.$scalar_op(value).expect($scalar_msg)
+            }
+        }
+
+        impl std::ops::$trait<f32> for &$ty {
+            type Output = $ty;
+
+            fn $method(self, value: f32) -> $ty {
+                self.$scalar_op(value).expect($scalar_msg)
+            }
+        }
+
+        impl std::ops::$trait<$ty> for f32 {
+            type Output = $ty;
+
+            fn $method(self, tensor: $ty) -> $ty {
+                tensor.$reversed_op(self).expect($scalar_msg)
+            }
+        }
+
+        impl std::ops::$trait<&$ty> for f32 {
+            type Output = $ty;
+
+            fn $method(self, tensor: &$ty) -> $ty {
+                tensor.$reversed_op(self).expect($scalar_msg)
+            }
+        }
+    };
+}
+
+/// Operador composto (`+=` etc.) que altera o tensor no lugar
+macro_rules! impl_assign_op {
+    ($ty:ty, $trait:ident, $method:ident, $op:ident, $scalar_op:ident, $msg:literal) => {
+        impl std::ops::$trait<$ty> for $ty {
+            fn $method(&mut self, other: $ty) {
+                self.$op(&other).expect($msg)
+            }
+        }
+
+        impl std::ops::$trait<&$ty> for $ty {
+            fn $method(&mut self, other: &$ty) {
+                self.$op(other).expect($msg)
+            }
+        }
+
+        impl std::ops::$trait<f32> for $ty {
+            fn $method(&mut self, value: f32) {
+                self.$scalar_op(value).expect($msg)
+            }
+        }
+    };
+}
+
+/// Implementa todos os operadores aritméticos para um tipo de tensor
+macro_rules! impl_arithmetic_ops {
+    ($ty:ty) => {
+        $crate::arith::impl_binary_op!($ty, Add, add, try_add, try_add_scalar, try_add_scalar,
+            "Cannot add tensors", "Cannot add scalar to tensor");
+        $crate::arith::impl_binary_op!($ty, Sub, sub, try_sub, try_sub_scalar, try_rsub_scalar,
+            "Cannot subtract tensors", "Cannot subtract scalar and tensor");
+        $crate::arith::impl_binary_op!($ty, Mul, mul, try_mul, try_mul_scalar, try_mul_scalar,
+            "Cannot multiply tensors", "Cannot multiply tensor by scalar");
+        $crate::arith::impl_binary_op!($ty, Div, div, try_div, try_div_scalar, try_rdiv_scalar,
+            "Cannot divide tensors", "Cannot divide tensor and scalar");
+
+        $crate::arith::impl_assign_op!($ty, AddAssign, add_assign, try_add_in_place,
+            try_add_scalar_in_place, "Cannot add in place");
+        $crate::arith::impl_assign_op!($ty, SubAssign, sub_assign, try_sub_in_place,
+            try_sub_scalar_in_place, "Cannot subtract in place");
+        $crate::arith::impl_assign_op!($ty, MulAssign, mul_assign, try_mul_in_place,
+            try_mul_scalar_in_place, "Cannot multiply in place");
+        $crate::arith::impl_assign_op!($ty, DivAssign, div_assign, try_div_in_place,
+            try_div_scalar_in_place, "Cannot divide in place");
+    };
+}
+
+pub(crate) use impl_arithmetic_ops;
+pub(crate) use impl_assign_op;
+pub(crate) use impl_binary_op;
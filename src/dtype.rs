@@ -0,0 +1,223 @@
+// Element Types
+// Tipos de elemento suportados pelos dois backends
+
+use crate::error::{CopperError, Result};
+use std::fmt;
+use std::os::raw::c_int;
+
+/// Tipo dos elementos de um tensor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DType {
+    #[default]
+    Float32,
+    Float64,
+    Int64,
+    Int32,
+    UInt8,
+    Bool,
+}
+
+impl DType {
+    /// Código usado na fronteira FFI (mesma ordem do enum CopperDType em cpp/lib.cpp)
+    pub(crate) fn code(self) -> c_int {
+        match self {
+            DType::Float32 => 0,
+            DType::Float64 => 1,
+            DType::Int64 => 2,
+            DType::Int32 => 3,
+            DType::UInt8 => 4,
+            DType::Bool => 5,
+        }
+    }
+
+    /// Converte o código devolvido pelo shim
+    pub(crate) fn from_code(code: c_int) -> Result<DType> {
+        match code {
+            0 => Ok(DType::Float32),
+            1 => Ok(DType::Float64),
+            2 => Ok(DType::Int64),
+            3 => Ok(DType::Int32),
+            4 => Ok(DType::UInt8),
+            5 => Ok(DType::Bool),
+            _ => Err(CopperError::InvalidArgument(format!("código de dtype desconhecido: {}", code))),
+        }
+    }
+
+    /// Tamanho de um elemento em bytes
+    pub fn size(self) -> usize {
+        match self {
+            DType::Float64 | DType::Int64 => 8,
+            DType::Float32 | DType::Int32 => 4,
+            DType::UInt8 | DType::Bool => 1,
+        }
+    }
+
+    /// Indica se o tipo é de ponto flutuante
+    pub fn is_floating_point(self) -> bool {
+        matches!(self, DType::Float32 | DType::Float64)
+    }
+}
+
+impl fmt::Display for DType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DType::Float32 => "f32",
+            DType::Float64 => "f64",
+            DType::Int64 => "i64",
+            DType::Int32 => "i32",
+            DType::UInt8 => "u8",
+            DType::Bool => "bool",
+        };
+        f.write_str(name)
+    }
+}
+
+mod sealed {
+    /// Valor intermediário das conversões entre dtypes: inteiros passam por i64 e floats
+    /// por f64, então nenhum valor perde precisão no caminho
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Scalar {
+        Int(i64),
+        Float(f64),
+    }
+
+    pub trait Sealed {
+        fn to_scalar(self) -> Scalar;
+
+        /// Converte com a semântica de um cast `as` direto para o tipo de destino
+        fn from_scalar(value: Scalar) -> Self;
+    }
+}
+
+/// Tipo Rust que pode ser usado como elemento de um tensor
+pub trait Element: sealed::Sealed + Copy + fmt::Display + 'static {
+    /// DType correspondente
+    const DTYPE: DType;
+
+    /// Converte o valor para f64 (inteiros acima de 2^53 são arredondados)
+    fn to_f64(self) -> f64;
+
+    /// Converte a partir de f64, truncando como um cast `as`
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_element {
+    ($($ty:ty => $dtype:ident, $scalar:ident),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $ty {
+                fn to_scalar(self) -> sealed::Scalar {
+                    sealed::Scalar::$scalar(self as _)
+                }
+
+                fn from_scalar(value: sealed::Scalar) -> Self {
+                    match value {
+                        sealed::Scalar::Int(v) => v as $ty,
+                        sealed::Scalar::Float(v) => v as $ty,
+                    }
+                }
+            }
+
+            impl Element for $ty {
+                const DTYPE: DType = DType::$dtype;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_element! {
+    f32 => Float32, Float,
+    f64 => Float64, Float,
+    i64 => Int64, Int,
+    i32 => Int32, Int,
+    u8 => UInt8, Int,
+}
+
+impl sealed::Sealed for bool {
+    fn to_scalar(self) -> sealed::Scalar {
+        sealed::Scalar::Int(self as i64)
+    }
+
+    fn from_scalar(value: sealed::Scalar) -> Self {
+        match value {
+            sealed::Scalar::Int(v) => v != 0,
+            sealed::Scalar::Float(v) => v != 0.0,
+        }
+    }
+}
+
+impl Element for bool {
+    const DTYPE: DType = DType::Bool;
+
+    fn to_f64(self) -> f64 {
+        if self { 1.0 } else { 0.0 }
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value != 0.0
+    }
+}
+
+/// Executa `$body` com `$T` ligado ao tipo Rust de `$dtype`
+macro_rules! with_element_type {
+    ($dtype:expr, $T:ident => $body:expr) => {
+        match $dtype {
+            $crate::dtype::DType::Float32 => { type $T = f32; $body }
+            $crate::dtype::DType::Float64 => { type $T = f64; $body }
+            $crate::dtype::DType::Int64 => { type $T = i64; $body }
+            $crate::dtype::DType::Int32 => { type $T = i32; $body }
+            $crate::dtype::DType::UInt8 => { type $T = u8; $body }
+            $crate::dtype::DType::Bool => { type $T = bool; $body }
+        }
+    };
+}
+
+pub(crate) use with_element_type;
+
+/// Converte valores entre dois tipos de elemento, como um cast `as` de `S` para `D`
+pub(crate) fn cast_values<S: Element, D: Element>(values: &[S]) -> Vec<D> {
+    values.iter().map(|&v| D::from_scalar(v.to_scalar())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int64_round_trip_is_exact() {
+        let ids = [i64::MAX - 1, i64::MIN, (1 << 53) + 1];
+        assert_eq!(cast_values::<i64, i64>(&ids), ids);
+        assert_eq!(cast_values::<i32, i64>(&[i32::MIN, -1, i32::MAX]), [i32::MIN as i64, -1, i32::MAX as i64]);
+    }
+
+    #[test]
+    fn casts_match_as() {
+        assert_eq!(cast_values::<i64, i32>(&[i64::MAX - 1]), [(i64::MAX - 1) as i32]);
+        assert_eq!(cast_values::<i64, u8>(&[300, -1]), [44, 255]);
+        assert_eq!(cast_values::<i64, f32>(&[i64::MAX - 1]), [(i64::MAX - 1) as f32]);
+        assert_eq!(cast_values::<f32, i32>(&[2.9, -2.9, f32::NAN]), [2, -2, 0]);
+        assert_eq!(cast_values::<f64, i64>(&[1e300]), [i64::MAX]);
+    }
+
+    #[test]
+    fn bool_casts() {
+        assert_eq!(cast_values::<i64, bool>(&[0, 2, -1]), [false, true, true]);
+        assert_eq!(cast_values::<f32, bool>(&[0.0, 0.5]), [false, true]);
+        assert_eq!(cast_values::<bool, u8>(&[true, false]), [1, 0]);
+    }
+
+    #[test]
+    fn codes_round_trip() {
+        for dtype in [DType::Float32, DType::Float64, DType::Int64, DType::Int32, DType::UInt8, DType::Bool] {
+            assert_eq!(DType::from_code(dtype.code()).unwrap(), dtype);
+        }
+        assert!(DType::from_code(42).is_err());
+    }
+}
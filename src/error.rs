@@ -0,0 +1,92 @@
+// Error Handling
+// Tipo de erro comum às APIs LibTorch, TensorFlow e unificada
+
+use crate::dtype::DType;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+
+#[link(name = "ai_copper", kind = "dylib")]
+extern "C" {
+    fn CopperLastError() -> *const c_char;
+    fn CopperClearError();
+    fn CopperSetError(message: *const c_char);
+}
+
+/// Erro retornado pelas variantes `try_*` das operações
+#[derive(Debug, Clone, PartialEq)]
+pub enum CopperError {
+    /// Exceção capturada no shim C++ (LibTorch ou TensorFlow)
+    Backend { op: &'static str, message: String },
+    /// Formas incompatíveis entre os operandos
+    ShapeMismatch { op: &'static str, left: Vec<i64>, right: Vec<i64> },
+    /// Tipo de elemento diferente do esperado pela operação
+    DTypeMismatch { op: &'static str, expected: DType, found: DType },
+    /// Argumento rejeitado antes de chegar ao backend
+    InvalidArgument(String),
+}
+
+/// Alias de `Result` usado em toda a biblioteca
+pub type Result<T> = std::result::Result<T, CopperError>;
+
+impl fmt::Display for CopperError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CopperError::Backend { op, message } => write!(f, "{} falhou: {}", op, message),
+            CopperError::ShapeMismatch { op, left, right } => {
+                write!(f, "{}: formas incompatíveis {:?} e {:?}", op, left, right)
+            }
+            CopperError::DTypeMismatch { op, expected, found } => {
+                write!(f, "{}: esperado dtype {}, encontrado {}", op, expected, found)
+            }
+            CopperError::InvalidArgument(message) => write!(f, "argumento inválido: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for CopperError {}
+
+/// Lê a mensagem do último erro registrado pelo shim na thread atual
+pub(crate) fn last_error() -> Option<String> {
+    unsafe {
+        let message = CopperLastError();
+        if message.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(message).to_string_lossy().into_owned())
+        }
+    }
+}
+
+/// Registra `message` como último erro da thread, para o shim repassar como exceção
+pub(crate) fn set_last_error(message: &str) {
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    unsafe { CopperSetError(message.as_ptr()) };
+}
+
+/// Cria um erro de backend usando a mensagem registrada pelo shim
+pub(crate) fn backend_error(op: &'static str) -> CopperError {
+    CopperError::Backend {
+        op,
+        message: last_error().unwrap_or_else(|| "o shim retornou um ponteiro nulo".to_string()),
+    }
+}
+
+/// Verifica o ponteiro devolvido por uma função do shim
+pub(crate) fn check_ptr(ptr: *mut c_void, op: &'static str) -> Result<*mut c_void> {
+    if ptr.is_null() {
+        Err(backend_error(op))
+    } else {
+        Ok(ptr)
+    }
+}
+
+/// Executa uma chamada ao shim que não sinaliza falha pelo valor de retorno
+pub(crate) fn check_call<T>(op: &'static str, call: impl FnOnce() -> T) -> Result<T> {
+    unsafe { CopperClearError() };
+    let value = call();
+    match last_error() {
+        Some(message) => Err(CopperError::Backend { op, message }),
+        None => Ok(value),
+    }
+}
@@ -1,6 +1,33 @@
 // AI Copper - Unified AI Library
 // Combines LibTorch and TensorFlow capabilities
 
+//==========================================
+// Error Handling
+//==========================================
+pub mod error;
+pub use error::{CopperError, Result};
+
+//==========================================
+// Logging
+//==========================================
+pub mod logging;
+
+//==========================================
+// Element Types
+//==========================================
+pub mod dtype;
+pub use dtype::{DType, Element};
+
+//==========================================
+// Arithmetic Operators
+//==========================================
+mod arith;
+
+//==========================================
+// Shapes
+//==========================================
+pub mod shape;
+
 //==========================================
 // TensorFlow Module
 //==========================================
@@ -14,9 +41,12 @@ pub use tensor_tensorflow::tensors_flow::TensorFlowModel;
 pub mod tensor_libortch;
 pub use tensor_libortch::operators;
 pub use tensor_libortch::tensor::{Tensor, Linear, Optimizer};
+pub use tensor_libortch::nn::{Module, Reduction, Sequential};
+pub use tensor_libortch::optim::{Algorithm, ParamGroup};
+pub use tensor_libortch::autograd::{no_grad, NoGradGuard};
 
 //==========================================
 // Unified API Module
 //==========================================
 pub mod unified;
-pub use unified::{Device, Backend, UnifiedTensor};
\ No newline at end of file
+pub use unified::{Device, Backend, UnifiedTensor, manual_seed};
\ No newline at end of file
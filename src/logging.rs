@@ -0,0 +1,79 @@
+// Logging
+// Encaminha as mensagens do shim C++ para o facade do crate `log`
+
+use log::{Level, LevelFilter};
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+/// Target usado nas mensagens vindas do shim
+pub const SHIM_TARGET: &str = "ai_copper::shim";
+
+type LogCallback = extern "C" fn(level: c_int, message: *const c_char);
+
+#[link(name = "ai_copper", kind = "dylib")]
+extern "C" {
+    fn CopperSetLogCallback(callback: Option<LogCallback>);
+    fn CopperSetLogLevel(level: c_int);
+}
+
+// Níveis na mesma ordem do enum CopperLogLevel em cpp/lib.cpp
+const SHIM_TRACE: c_int = 0;
+const SHIM_DEBUG: c_int = 1;
+const SHIM_INFO: c_int = 2;
+const SHIM_WARN: c_int = 3;
+const SHIM_ERROR: c_int = 4;
+const SHIM_OFF: c_int = 5;
+
+extern "C" fn forward_to_log(level: c_int, message: *const c_char) {
+    if message.is_null() {
+        return;
+    }
+    let level = match level {
+        SHIM_TRACE => Level::Trace,
+        SHIM_DEBUG => Level::Debug,
+        SHIM_INFO => Level::Info,
+        SHIM_WARN => Level::Warn,
+        _ => Level::Error,
+    };
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+    // Um logger que entra em pânico não pode atravessar a fronteira FFI
+    let _ = std::panic::catch_unwind(|| {
+        log::log!(target: SHIM_TARGET, level, "{}", message);
+    });
+}
+
+fn shim_level(filter: LevelFilter) -> c_int {
+    match filter {
+        LevelFilter::Off => SHIM_OFF,
+        LevelFilter::Error => SHIM_ERROR,
+        LevelFilter::Warn => SHIM_WARN,
+        LevelFilter::Info => SHIM_INFO,
+        LevelFilter::Debug => SHIM_DEBUG,
+        LevelFilter::Trace => SHIM_TRACE,
+    }
+}
+
+/// Registra o callback de log do shim usando `log::max_level()` como filtro.
+///
+/// Deve ser chamada depois de inicializar o logger da aplicação. Sem essa
+/// chamada o shim não emite nenhuma mensagem.
+pub fn init_logging() {
+    set_log_level(log::max_level());
+}
+
+/// Altera o nível mínimo das mensagens enviadas pelo shim
+pub fn set_log_level(filter: LevelFilter) {
+    unsafe {
+        CopperSetLogLevel(shim_level(filter));
+        if filter == LevelFilter::Off {
+            CopperSetLogCallback(None);
+        } else {
+            CopperSetLogCallback(Some(forward_to_log));
+        }
+    }
+}
+
+/// Desliga o log do shim
+pub fn disable_logging() {
+    set_log_level(LevelFilter::Off);
+}
@@ -0,0 +1,445 @@
+// Shape Helpers
+// Funções auxiliares para formas N-dimensionais, compartilhadas pelos backends
+
+use crate::dtype::{DType, Element};
+use crate::error::{CopperError, Result};
+
+/// Número de elementos de um tensor com a forma `dims` (1 para escalares 0-D)
+pub fn numel(dims: &[i64]) -> usize {
+    dims.iter().map(|&d| d.max(0) as usize).product()
+}
+
+/// Converte um índice de dimensão (negativos contam a partir do fim) em posição
+pub(crate) fn normalize_dim(dim: i64, dims: &[i64]) -> Result<usize> {
+    let ndim = dims.len() as i64;
+    let index = if dim < 0 { dim + ndim } else { dim };
+    if index < 0 || index >= ndim {
+        return Err(CopperError::InvalidArgument(format!(
+            "dimensão {} fora do intervalo para tensor {:?}",
+            dim, dims
+        )));
+    }
+    Ok(index as usize)
+}
+
+/// Converte o índice de um elemento ao longo de uma dimensão de tamanho `size`
+/// (negativos contam a partir do fim)
+pub(crate) fn normalize_index(index: i64, size: i64) -> Result<i64> {
+    let position = if index < 0 { index + size } else { index };
+    if position < 0 || position >= size {
+        return Err(CopperError::InvalidArgument(format!(
+            "índice {} fora do intervalo para dimensão de tamanho {}",
+            index, size
+        )));
+    }
+    Ok(position)
+}
+
+/// Valida o intervalo `start..start + length` de `narrow` e devolve o início normalizado
+pub(crate) fn narrow_start(size: i64, start: i64, length: i64) -> Result<i64> {
+    let begin = if start < 0 { start + size } else { start };
+    if begin < 0 || length < 0 || begin + length > size {
+        return Err(CopperError::InvalidArgument(format!(
+            "narrow({}, {}) fora do intervalo para dimensão de tamanho {}",
+            start, length, size
+        )));
+    }
+    Ok(begin)
+}
+
+/// Normaliza `start..end` com passo `step` como o `slice` do PyTorch: negativos contam
+/// a partir do fim e os limites são saturados em `[0, size]`. Devolve o início e o
+/// número de elementos selecionados
+pub(crate) fn slice_range(size: i64, start: i64, end: i64, step: i64) -> Result<(i64, i64)> {
+    if step <= 0 {
+        return Err(CopperError::InvalidArgument(format!("passo de slice deve ser positivo, recebeu {}", step)));
+    }
+    let clamp = |i: i64| if i < 0 { i.saturating_add(size) } else { i }.clamp(0, size);
+    let (start, end) = (clamp(start), clamp(end));
+    let len = if end > start { (end - start - 1) / step + 1 } else { 0 };
+    Ok((start, len))
+}
+
+/// Fim exclusivo de um slice de `len` elementos a partir de `start` com passo `step`:
+/// logo após o último elemento, sem estourar `i64` com passos enormes
+pub(crate) fn slice_end(start: i64, len: i64, step: i64) -> i64 {
+    if len > 0 { start + (len - 1) * step + 1 } else { start }
+}
+
+/// Strides, em elementos, de um tensor contíguo (row-major) com a forma `dims`
+pub(crate) fn contiguous_strides(dims: &[i64]) -> Vec<i64> {
+    let mut strides = vec![1i64; dims.len()];
+    for i in (0..dims.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * dims[i + 1].max(1);
+    }
+    strides
+}
+
+/// Índice multidimensional do elemento na posição `offset` de um tensor contíguo com forma `dims`
+pub(crate) fn unravel_index(offset: usize, dims: &[i64]) -> Vec<i64> {
+    let strides = contiguous_strides(dims);
+    let mut rest = offset as i64;
+    strides
+        .iter()
+        .map(|&stride| {
+            let i = rest / stride;
+            rest %= stride;
+            i
+        })
+        .collect()
+}
+
+/// Deslocamento, em elementos, do elemento em `index` de um tensor com forma `dims`
+/// e os `strides` informados
+pub(crate) fn element_offset(index: &[i64], dims: &[i64], strides: &[i64]) -> Result<isize> {
+    if index.len() != dims.len() {
+        return Err(CopperError::InvalidArgument(format!(
+            "índice com {} posições para tensor {:?}",
+            index.len(),
+            dims
+        )));
+    }
+    let mut offset = 0i64;
+    for ((&i, &size), &stride) in index.iter().zip(dims).zip(strides) {
+        offset += normalize_index(i, size)? * stride;
+    }
+    Ok(offset as isize)
+}
+
+/// Verifica se `dims` é uma forma válida para `len` valores
+pub(crate) fn check_values(dims: &[i64], len: usize) -> Result<()> {
+    if dims.iter().any(|&d| d < 0) {
+        return Err(CopperError::InvalidArgument(format!("forma com dimensão negativa: {:?}", dims)));
+    }
+    if numel(dims) != len {
+        return Err(CopperError::InvalidArgument(format!(
+            "{} valores não formam um tensor {:?}",
+            len, dims
+        )));
+    }
+    Ok(())
+}
+
+/// Forma resultante do broadcasting (regras do NumPy) entre `left` e `right`,
+/// ou `None` se as formas forem incompatíveis
+pub fn broadcast_shapes(left: &[i64], right: &[i64]) -> Option<Vec<i64>> {
+    let ndim = left.len().max(right.len());
+    let mut out = vec![0i64; ndim];
+    for i in 0..ndim {
+        // Alinha as dimensões pela direita; as que faltam valem 1
+        let l = if i < ndim - left.len() { 1 } else { left[i - (ndim - left.len())] };
+        let r = if i < ndim - right.len() { 1 } else { right[i - (ndim - right.len())] };
+        out[i] = match (l, r) {
+            (l, r) if l == r => l,
+            (1, r) => r,
+            (l, 1) => l,
+            _ => return None,
+        };
+    }
+    Some(out)
+}
+
+/// Verifica se as formas podem ser combinadas por broadcasting
+pub(crate) fn check_broadcast(op: &'static str, left: &[i64], right: &[i64]) -> Result<Vec<i64>> {
+    broadcast_shapes(left, right).ok_or_else(|| CopperError::ShapeMismatch {
+        op,
+        left: left.to_vec(),
+        right: right.to_vec(),
+    })
+}
+
+/// Forma do resultado de `matmul` (mesmas regras de `torch.matmul`): vetores 1-D
+/// são promovidos a matriz e as dimensões de lote fazem broadcasting
+pub fn matmul_shape(left: &[i64], right: &[i64]) -> Option<Vec<i64>> {
+    if left.is_empty() || right.is_empty() {
+        return None;
+    }
+    let l: Vec<i64> = if left.len() == 1 { vec![1, left[0]] } else { left.to_vec() };
+    let r: Vec<i64> = if right.len() == 1 { vec![right[0], 1] } else { right.to_vec() };
+    let (l_batch, l_mat) = l.split_at(l.len() - 2);
+    let (r_batch, r_mat) = r.split_at(r.len() - 2);
+    if l_mat[1] != r_mat[0] {
+        return None;
+    }
+
+    let mut out = broadcast_shapes(l_batch, r_batch)?;
+    if left.len() > 1 {
+        out.push(l_mat[0]);
+    }
+    if right.len() > 1 {
+        out.push(r_mat[1]);
+    }
+    Some(out)
+}
+
+/// Valida os operandos de `matmul`
+pub(crate) fn check_matmul(left: &[i64], right: &[i64]) -> Result<Vec<i64>> {
+    matmul_shape(left, right).ok_or_else(|| CopperError::ShapeMismatch {
+        op: "matmul",
+        left: left.to_vec(),
+        right: right.to_vec(),
+    })
+}
+
+/// Valida os operandos de `bmm`: dois tensores 3-D `[b, n, k]` e `[b, k, m]`
+pub(crate) fn check_bmm(left: &[i64], right: &[i64]) -> Result<Vec<i64>> {
+    if left.len() != 3 || right.len() != 3 || left[0] != right[0] || left[2] != right[1] {
+        return Err(CopperError::ShapeMismatch {
+            op: "bmm",
+            left: left.to_vec(),
+            right: right.to_vec(),
+        });
+    }
+    Ok(vec![left[0], left[1], right[2]])
+}
+
+/// Forma do resultado de `cat`: todas as formas iguais exceto em `dim`
+pub(crate) fn cat_shape(shapes: &[&[i64]], dim: i64) -> Result<Vec<i64>> {
+    let first = *shapes
+        .first()
+        .ok_or_else(|| CopperError::InvalidArgument("cat exige ao menos um tensor".to_string()))?;
+    let index = normalize_dim(dim, first)?;
+    let mut out = first.to_vec();
+    for &other in &shapes[1..] {
+        let compatible = other.len() == first.len()
+            && other.iter().zip(first).enumerate().all(|(i, (a, b))| i == index || a == b);
+        if !compatible {
+            return Err(CopperError::ShapeMismatch { op: "cat", left: first.to_vec(), right: other.to_vec() });
+        }
+        out[index] += other[index];
+    }
+    Ok(out)
+}
+
+/// Valida os operandos de `stack` (formas idênticas) e devolve a posição da nova dimensão
+pub(crate) fn stack_dim(shapes: &[&[i64]], dim: i64) -> Result<usize> {
+    let first = *shapes
+        .first()
+        .ok_or_else(|| CopperError::InvalidArgument("stack exige ao menos um tensor".to_string()))?;
+    if let Some(&other) = shapes.iter().find(|&&s| s != first) {
+        return Err(CopperError::ShapeMismatch { op: "stack", left: first.to_vec(), right: other.to_vec() });
+    }
+    // A nova dimensão pode ir de 0 até ndim (inclusive)
+    let mut extended = first.to_vec();
+    extended.push(1);
+    normalize_dim(dim, &extended)
+}
+
+/// Tamanhos das partes de `split`: blocos de `split_size`, o último possivelmente menor
+pub(crate) fn split_sizes(size: i64, split_size: i64) -> Result<Vec<i64>> {
+    if split_size <= 0 {
+        return Err(CopperError::InvalidArgument(format!("split_size deve ser positivo, recebeu {}", split_size)));
+    }
+    if size == 0 {
+        return Ok(vec![0]);
+    }
+    Ok((0..size).step_by(split_size as usize).map(|start| split_size.min(size - start)).collect())
+}
+
+/// Tamanhos das partes de `chunk` (mesma regra do PyTorch: blocos de ceil(size / chunks),
+/// o que pode gerar menos de `chunks` partes)
+pub(crate) fn chunk_sizes(size: i64, chunks: i64) -> Result<Vec<i64>> {
+    if chunks <= 0 {
+        return Err(CopperError::InvalidArgument(format!("chunks deve ser positivo, recebeu {}", chunks)));
+    }
+    split_sizes(size, ((size + chunks - 1) / chunks).max(1))
+}
+
+/// Verifica se os tamanhos de `split_with_sizes` somam o tamanho da dimensão
+pub(crate) fn check_split_sizes(size: i64, sizes: &[i64]) -> Result<()> {
+    if sizes.iter().any(|&s| s < 0) || sizes.iter().sum::<i64>() != size {
+        return Err(CopperError::InvalidArgument(format!(
+            "tamanhos {:?} não dividem uma dimensão de tamanho {}",
+            sizes, size
+        )));
+    }
+    Ok(())
+}
+
+/// Verifica se todos os tensores têm o mesmo dtype
+pub(crate) fn check_same_dtype(op: &'static str, dtypes: &[DType]) -> Result<()> {
+    if let Some(&first) = dtypes.first() {
+        if let Some(&other) = dtypes.iter().find(|&&d| d != first) {
+            return Err(CopperError::DTypeMismatch { op, expected: first, found: other });
+        }
+    }
+    Ok(())
+}
+
+/// Formata os valores de um tensor N-D: escalares e vetores em uma linha,
+/// matrizes linha a linha e tensores maiores como uma sequência de matrizes
+/// indexadas pelas dimensões externas, no estilo do PyTorch.
+pub(crate) fn format_values<T: Element>(data: &[T], dims: &[i64]) -> String {
+    let mut out = String::new();
+    match dims.len() {
+        0 => {
+            if let Some(&val) = data.first() {
+                out.push_str(&format_value(val));
+            }
+            out.push('\n');
+        }
+        1 => {
+            for &val in data {
+                out.push_str(&format_value(val));
+                out.push(' ');
+            }
+            out.push('\n');
+        }
+        _ => {
+            let rows = dims[dims.len() - 2].max(0) as usize;
+            let cols = dims[dims.len() - 1].max(0) as usize;
+            let outer = &dims[..dims.len() - 2];
+            let matrix = rows * cols;
+            for (block, chunk) in data.chunks(matrix.max(1)).enumerate().take(numel(outer)) {
+                if !outer.is_empty() {
+                    let mut index = Vec::with_capacity(outer.len());
+                    let mut rest = block;
+                    for &d in outer.iter().rev() {
+                        index.push(rest % d as usize);
+                        rest /= d as usize;
+                    }
+                    let index: Vec<String> = index.iter().rev().map(|i| i.to_string()).collect();
+                    out.push_str(&format!("({},.,.) =\n", index.join(",")));
+                }
+                for row in chunk.chunks(cols.max(1)).take(rows) {
+                    for &val in row {
+                        out.push_str(&format_value(val));
+                        out.push(' ');
+                    }
+                    out.push('\n');
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Floats com 4 casas decimais; inteiros e booleanos como estão
+fn format_value<T: Element>(val: T) -> String {
+    if T::DTYPE.is_floating_point() {
+        format!("{:.4}", val)
+    } else {
+        val.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_libortch::tensor::Tensor;
+
+    #[test]
+    fn broadcast_aligns_from_the_right() {
+        assert_eq!(broadcast_shapes(&[2, 3], &[2, 3]), Some(vec![2, 3]));
+        assert_eq!(broadcast_shapes(&[4, 1, 3], &[5, 1]), Some(vec![4, 5, 3]));
+        assert_eq!(broadcast_shapes(&[], &[2, 3]), Some(vec![2, 3]));
+        assert_eq!(broadcast_shapes(&[0, 1], &[1, 4]), Some(vec![0, 4]));
+        assert_eq!(broadcast_shapes(&[2, 3], &[3, 2]), None);
+    }
+
+    #[test]
+    fn check_broadcast_reports_both_shapes() {
+        match check_broadcast("add", &[2], &[3]) {
+            Err(CopperError::ShapeMismatch { op, left, right }) => {
+                assert_eq!((op, left, right), ("add", vec![2], vec![3]));
+            }
+            other => panic!("esperava ShapeMismatch, recebeu {:?}", other),
+        }
+    }
+
+    #[test]
+    fn matmul_promotes_vectors_and_broadcasts_batches() {
+        assert_eq!(matmul_shape(&[3], &[3]), Some(vec![]));
+        assert_eq!(matmul_shape(&[2, 3], &[3]), Some(vec![2]));
+        assert_eq!(matmul_shape(&[3], &[3, 4]), Some(vec![4]));
+        assert_eq!(matmul_shape(&[2, 3], &[3, 4]), Some(vec![2, 4]));
+        assert_eq!(matmul_shape(&[5, 1, 2, 3], &[4, 3, 6]), Some(vec![5, 4, 2, 6]));
+        assert_eq!(matmul_shape(&[2, 3], &[4, 5]), None);
+        assert_eq!(matmul_shape(&[], &[3]), None);
+    }
+
+    #[test]
+    fn bmm_requires_matching_batches() {
+        assert_eq!(check_bmm(&[2, 3, 4], &[2, 4, 5]).unwrap(), vec![2, 3, 5]);
+        assert!(check_bmm(&[2, 3, 4], &[3, 4, 5]).is_err());
+        assert!(check_bmm(&[3, 4], &[4, 5]).is_err());
+    }
+
+    #[test]
+    fn slice_range_clamps_and_counts() {
+        assert_eq!(slice_range(10, 0, 10, 1).unwrap(), (0, 10));
+        assert_eq!(slice_range(10, 1, 8, 3).unwrap(), (1, 3));
+        assert_eq!(slice_range(10, -3, i64::MAX, 1).unwrap(), (7, 3));
+        assert_eq!(slice_range(10, i64::MIN, 2, 1).unwrap(), (0, 2));
+        assert_eq!(slice_range(10, 8, 2, 1).unwrap(), (8, 0));
+        assert!(slice_range(10, 0, 10, 0).is_err());
+    }
+
+    #[test]
+    fn slice_range_with_huge_step_does_not_overflow() {
+        assert_eq!(slice_range(10, 0, 10, i64::MAX).unwrap(), (0, 1));
+        assert_eq!(slice_range(i64::MAX, 0, i64::MAX, i64::MAX).unwrap(), (0, 1));
+        assert_eq!(slice_range(i64::MAX, 1, i64::MAX, 2).unwrap(), (1, (i64::MAX - 1) / 2));
+        assert_eq!(slice_end(1, 1, i64::MAX), 2);
+        assert_eq!(slice_end(1, 3, 3), 8);
+        assert_eq!(slice_end(8, 0, 1), 8);
+    }
+
+    #[test]
+    fn tensor_slice_with_huge_step_does_not_overflow() {
+        let t = Tensor::from_slice(&[0.0f32, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0], &[10]);
+        let s = t.try_slice(0, 1, 10, i64::MAX).unwrap();
+        assert_eq!(s.shape(), &[1]);
+        assert_eq!(s.try_get::<f32>(&[0]).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn index_helpers_count_from_the_end() {
+        assert_eq!(normalize_index(-1, 4).unwrap(), 3);
+        assert!(normalize_index(4, 4).is_err());
+        assert_eq!(narrow_start(5, -2, 2).unwrap(), 3);
+        assert!(narrow_start(5, 3, 3).is_err());
+        assert_eq!(contiguous_strides(&[2, 3, 4]), vec![12, 4, 1]);
+        assert_eq!(element_offset(&[1, -1], &[2, 3], &[3, 1]).unwrap(), 5);
+        assert!(element_offset(&[0], &[2, 3], &[3, 1]).is_err());
+    }
+
+    #[test]
+    fn cat_shape_sums_the_joined_dim() {
+        assert_eq!(cat_shape(&[&[2, 3], &[4, 3]], 0).unwrap(), vec![6, 3]);
+        assert_eq!(cat_shape(&[&[2, 3], &[2, 1]], -1).unwrap(), vec![2, 4]);
+        assert!(cat_shape(&[&[2, 3], &[4, 2]], 0).is_err());
+        assert!(cat_shape(&[&[2, 3], &[2, 3, 1]], 0).is_err());
+        assert!(cat_shape(&[], 0).is_err());
+    }
+
+    #[test]
+    fn stack_dim_allows_one_past_the_end() {
+        assert_eq!(stack_dim(&[&[2, 3], &[2, 3]], 2).unwrap(), 2);
+        assert_eq!(stack_dim(&[&[2, 3], &[2, 3]], -1).unwrap(), 2);
+        assert!(stack_dim(&[&[2, 3], &[3, 2]], 0).is_err());
+    }
+
+    #[test]
+    fn split_and_chunk_sizes() {
+        assert_eq!(split_sizes(10, 3).unwrap(), vec![3, 3, 3, 1]);
+        assert_eq!(split_sizes(0, 3).unwrap(), vec![0]);
+        assert!(split_sizes(10, 0).is_err());
+        assert_eq!(chunk_sizes(10, 3).unwrap(), vec![4, 4, 2]);
+        // Como no PyTorch, blocos de ceil(6 / 4) = 2 geram só 3 partes
+        assert_eq!(chunk_sizes(6, 4).unwrap(), vec![2, 2, 2]);
+        assert_eq!(chunk_sizes(2, 5).unwrap(), vec![1, 1]);
+        assert!(chunk_sizes(10, 0).is_err());
+        assert!(check_split_sizes(5, &[2, 3]).is_ok());
+        assert!(check_split_sizes(5, &[2, 2]).is_err());
+        assert!(check_split_sizes(5, &[6, -1]).is_err());
+    }
+
+    #[test]
+    fn unravel_index_is_row_major() {
+        assert_eq!(unravel_index(0, &[2, 3]), vec![0, 0]);
+        assert_eq!(unravel_index(5, &[2, 3]), vec![1, 2]);
+        assert_eq!(unravel_index(13, &[2, 3, 4]), vec![1, 0, 1]);
+        assert_eq!(unravel_index(0, &[]), Vec::<i64>::new());
+    }
+}
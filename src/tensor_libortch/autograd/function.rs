@@ -0,0 +1,172 @@
+// Custom Functions
+// Operações diferenciáveis com forward e backward escritos em Rust, registradas no
+// grafo do LibTorch através de um torch::autograd::Function do shim
+
+use super::TensorList;
+use crate::error::{check_call, check_ptr, set_last_error, Result};
+use crate::tensor_libortch::ffi::{
+    CopperFunctionApply,
+    TensorListPush,
+    AutogradContextSaveForBackward,
+    AutogradContextSavedTensors,
+    AutogradContextNeedsInputGrad,
+};
+use crate::tensor_libortch::tensor::Tensor;
+use libc::{c_int, c_void};
+use std::any::Any;
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// Operação diferenciável definida pelo usuário, como `torch.autograd.Function`
+///
+/// O `forward` roda com o autograd desligado; o `backward` recebe um gradiente por
+/// saída e devolve um por entrada (`None` para entradas sem gradiente).
+pub trait Function: Send + 'static {
+    fn forward(&self, ctx: &mut Context, inputs: &[&Tensor]) -> Result<Vec<Tensor>>;
+
+    fn backward(&self, ctx: &mut Context, grad_outputs: &[&Tensor]) -> Result<Vec<Option<Tensor>>>;
+
+    /// Executa a função registrando-a no grafo de autograd
+    fn try_apply(self, inputs: &[&Tensor]) -> Result<Vec<Tensor>>
+    where
+        Self: Sized,
+    {
+        try_apply(self, inputs)
+    }
+
+    /// Executa a função registrando-a no grafo de autograd
+    fn apply(self, inputs: &[&Tensor]) -> Vec<Tensor>
+    where
+        Self: Sized,
+    {
+        self.try_apply(inputs).expect("Error applying custom function")
+    }
+}
+
+/// Contexto compartilhado entre o forward e o backward de uma chamada
+pub struct Context<'a> {
+    ptr: *mut c_void, // Ponteiro para torch::autograd::AutogradContext
+    _call: PhantomData<&'a mut ()>,
+}
+
+impl Context<'_> {
+    /// Guarda tensores para o backward (use no forward)
+    pub fn save_for_backward(&mut self, tensors: &[&Tensor]) -> Result<()> {
+        let ptrs: Vec<*mut c_void> = tensors.iter().map(|t| t.ptr).collect();
+        check_call("AutogradContextSaveForBackward", || unsafe {
+            AutogradContextSaveForBackward(self.ptr, ptrs.as_ptr(), ptrs.len() as c_int)
+        })
+    }
+
+    /// Tensores guardados no forward (use no backward)
+    pub fn saved_tensors(&self) -> Result<Vec<Tensor>> {
+        let list = check_ptr(unsafe { AutogradContextSavedTensors(self.ptr) }, "AutogradContextSavedTensors")?;
+        TensorList(list).into_tensors()
+    }
+
+    /// Indica se a entrada `index` precisa de gradiente
+    pub fn needs_input_grad(&self, index: usize) -> bool {
+        check_call("AutogradContextNeedsInputGrad", || unsafe {
+            AutogradContextNeedsInputGrad(self.ptr, index as c_int)
+        })
+        .map(|flag| flag == 1)
+        .unwrap_or(true)
+    }
+}
+
+/// Executa `function` registrando-a no grafo de autograd
+pub fn try_apply<F: Function>(function: F, inputs: &[&Tensor]) -> Result<Vec<Tensor>> {
+    let state = Box::into_raw(Box::new(function)) as *mut c_void;
+    let ptrs: Vec<*mut c_void> = inputs.iter().map(|t| t.ptr).collect();
+    // A partir daqui o shim é dono do estado e chama drop_trampoline ao liberá-lo
+    let outputs = unsafe {
+        CopperFunctionApply(
+            state,
+            forward_trampoline::<F>,
+            backward_trampoline::<F>,
+            drop_trampoline::<F>,
+            ptrs.as_ptr(),
+            ptrs.len() as c_int,
+        )
+    };
+    TensorList(check_ptr(outputs, "CopperFunctionApply")?).into_tensors()
+}
+
+/// Assume a posse dos tensores criados pelo shim para um callback
+unsafe fn adopt(ptrs: *const *mut c_void, count: c_int) -> Result<Vec<Tensor>> {
+    if count <= 0 {
+        return Ok(Vec::new());
+    }
+    std::slice::from_raw_parts(ptrs, count as usize)
+        .iter()
+        .map(|&ptr| Tensor::from_ptr(ptr, "callback"))
+        .collect()
+}
+
+fn push(list: *mut c_void, tensor: Option<&Tensor>) -> Result<()> {
+    let ptr = tensor.map_or(ptr::null_mut(), |t| t.ptr);
+    check_call("TensorListPush", || unsafe { TensorListPush(list, ptr) })
+}
+
+/// Executa um callback sem deixar erros ou panics atravessarem a fronteira FFI
+pub(crate) fn run_callback(op: &'static str, body: impl FnOnce() -> Result<()>) -> c_int {
+    let message = match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(())) => return 0,
+        Ok(Err(err)) => format!("{}: {}", op, err),
+        Err(payload) => format!("{}: panic: {}", op, panic_message(payload.as_ref())),
+    };
+    set_last_error(&message);
+    -1
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("sem mensagem")
+}
+
+extern "C" fn forward_trampoline<F: Function>(
+    state: *mut c_void,
+    ctx: *mut c_void,
+    inputs: *const *mut c_void,
+    num_inputs: c_int,
+    outputs: *mut c_void,
+) -> c_int {
+    run_callback("Function::forward", || {
+        let function = unsafe { &*(state as *const F) };
+        let inputs = unsafe { adopt(inputs, num_inputs)? };
+        let inputs: Vec<&Tensor> = inputs.iter().collect();
+        let mut ctx = Context { ptr: ctx, _call: PhantomData };
+        for output in function.forward(&mut ctx, &inputs)? {
+            push(outputs, Some(&output))?;
+        }
+        Ok(())
+    })
+}
+
+extern "C" fn backward_trampoline<F: Function>(
+    state: *mut c_void,
+    ctx: *mut c_void,
+    grad_outputs: *const *mut c_void,
+    num_grads: c_int,
+    grad_inputs: *mut c_void,
+) -> c_int {
+    run_callback("Function::backward", || {
+        let function = unsafe { &*(state as *const F) };
+        let grads = unsafe { adopt(grad_outputs, num_grads)? };
+        let grads: Vec<&Tensor> = grads.iter().collect();
+        let mut ctx = Context { ptr: ctx, _call: PhantomData };
+        for grad in function.backward(&mut ctx, &grads)? {
+            push(grad_inputs, grad.as_ref())?;
+        }
+        Ok(())
+    })
+}
+
+extern "C" fn drop_trampoline<F: Function>(state: *mut c_void) {
+    // Um panic no Drop do usuário não pode atravessar o destrutor C++
+    let _ = panic::catch_unwind(|| drop(unsafe { Box::from_raw(state as *mut F) }));
+}
@@ -0,0 +1,281 @@
+// Functional Autograd
+// Derivadas de funções inteiras (vjp, jvp, jacobiana, hessiana), no estilo de
+// torch.autograd.functional, construídas sobre torch::autograd::grad
+
+use super::{enable_grad, TensorList};
+use crate::error::{check_ptr, CopperError, Result};
+use crate::tensor_libortch::ffi::AutogradGrad;
+use crate::tensor_libortch::join;
+use crate::tensor_libortch::tensor::Tensor;
+use libc::{c_int, c_void};
+use std::ptr;
+
+/// Gradientes de `outputs` em relação a `inputs`, como `torch::autograd::grad`
+///
+/// `grad_outputs` dá o vetor de cada saída (pode ser `None` para saídas escalares).
+/// Entradas sem caminho até as saídas recebem zeros. Com `create_graph` os gradientes
+/// ficam no grafo e podem ser derivados de novo.
+pub fn try_grad(
+    outputs: &[&Tensor],
+    inputs: &[&Tensor],
+    grad_outputs: Option<&[&Tensor]>,
+    retain_graph: bool,
+    create_graph: bool,
+) -> Result<Vec<Tensor>> {
+    if let Some(grads) = grad_outputs {
+        if grads.len() != outputs.len() {
+            return Err(CopperError::InvalidArgument(format!(
+                "grad recebeu {} grad_outputs para {} saídas",
+                grads.len(),
+                outputs.len()
+            )));
+        }
+    }
+    // Saídas fora do grafo não dependem de nenhuma entrada e contribuem com zero
+    let connected: Vec<usize> = (0..outputs.len()).filter(|&i| outputs[i].is_requires_grad()).collect();
+    if connected.is_empty() {
+        return inputs.iter().map(|input| input.try_zeros_like()).collect();
+    }
+    let output_ptrs: Vec<*mut c_void> = connected.iter().map(|&i| outputs[i].ptr).collect();
+    let grad_ptrs: Option<Vec<*mut c_void>> = grad_outputs.map(|grads| connected.iter().map(|&i| grads[i].ptr).collect());
+    let input_ptrs: Vec<*mut c_void> = inputs.iter().map(|t| t.ptr).collect();
+    let list = unsafe {
+        AutogradGrad(
+            output_ptrs.as_ptr(),
+            output_ptrs.len() as c_int,
+            input_ptrs.as_ptr(),
+            input_ptrs.len() as c_int,
+            grad_ptrs.as_ref().map_or(ptr::null(), |ptrs| ptrs.as_ptr()),
+            retain_graph,
+            create_graph,
+        )
+    };
+    TensorList(check_ptr(list, "AutogradGrad")?).into_tensors()
+}
+
+/// Gradientes de `outputs` em relação a `inputs`, como `torch::autograd::grad`
+pub fn grad(
+    outputs: &[&Tensor],
+    inputs: &[&Tensor],
+    grad_outputs: Option<&[&Tensor]>,
+    retain_graph: bool,
+    create_graph: bool,
+) -> Vec<Tensor> {
+    try_grad(outputs, inputs, grad_outputs, retain_graph, create_graph).expect("Error computing gradients")
+}
+
+/// Produto vetor-jacobiana `vᵀ·J` de `f` no ponto `inputs`; devolve `(f(inputs), vjp)`
+/// com um gradiente por entrada
+pub fn try_vjp<F>(f: F, inputs: &[&Tensor], v: &Tensor, create_graph: bool) -> Result<(Tensor, Vec<Tensor>)>
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    enable_grad(|| {
+        let xs = track(inputs, create_graph)?;
+        let xs = refs(&xs);
+        let output = f(&xs)?;
+        check_same_shape("vjp", &output, v)?;
+        let grads = try_grad(&[&output], &xs, Some(&[v]), create_graph, create_graph)?;
+        Ok((finish(output, create_graph)?, grads))
+    })
+}
+
+/// Produto vetor-jacobiana `vᵀ·J` de `f` no ponto `inputs`; devolve `(f(inputs), vjp)`
+pub fn vjp<F>(f: F, inputs: &[&Tensor], v: &Tensor, create_graph: bool) -> (Tensor, Vec<Tensor>)
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    try_vjp(f, inputs, v, create_graph).expect("Error computing vjp")
+}
+
+/// Produto jacobiana-vetor `J·v` de `f` no ponto `inputs`, com um vetor por entrada;
+/// devolve `(f(inputs), jvp)`
+pub fn try_jvp<F>(f: F, inputs: &[&Tensor], v: &[&Tensor], create_graph: bool) -> Result<(Tensor, Tensor)>
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    check_vectors("jvp", inputs, v)?;
+    enable_grad(|| {
+        let xs = track(inputs, create_graph)?;
+        let xs = refs(&xs);
+        let output = f(&xs)?;
+        // Truque do duplo vjp: u ↦ Jᵀ·u é linear em u, então seu vjp com v é J·v
+        let u = output.try_zeros_like()?.try_requires_grad(true)?;
+        let grads = try_grad(&[&output], &xs, Some(&[&u]), true, true)?;
+        let jvp = try_grad(&refs(&grads), &[&u], Some(v), create_graph, create_graph)?.remove(0);
+        Ok((finish(output, create_graph)?, finish(jvp, create_graph)?))
+    })
+}
+
+/// Produto jacobiana-vetor `J·v` de `f` no ponto `inputs`; devolve `(f(inputs), jvp)`
+pub fn jvp<F>(f: F, inputs: &[&Tensor], v: &[&Tensor], create_graph: bool) -> (Tensor, Tensor)
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    try_jvp(f, inputs, v, create_graph).expect("Error computing jvp")
+}
+
+/// Jacobiana de `f` em relação a cada entrada, com forma `saída ++ entrada`
+///
+/// Calculada linha a linha, com um vjp por elemento da saída.
+pub fn try_jacobian<F>(f: F, inputs: &[&Tensor], create_graph: bool) -> Result<Vec<Tensor>>
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    enable_grad(|| {
+        let xs = track(inputs, create_graph)?;
+        let xs = refs(&xs);
+        let output = f(&xs)?;
+        jacobian_of(&output, &xs, create_graph)
+    })
+}
+
+/// Jacobiana de `f` em relação a cada entrada, com forma `saída ++ entrada`
+pub fn jacobian<F>(f: F, inputs: &[&Tensor], create_graph: bool) -> Vec<Tensor>
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    try_jacobian(f, inputs, create_graph).expect("Error computing jacobian")
+}
+
+/// Hessiana de uma `f` escalar: o bloco `[i][j]` tem forma `entrada_i ++ entrada_j`
+///
+/// É a jacobiana do gradiente, que é calculado com `create_graph` para ser derivado de novo.
+pub fn try_hessian<F>(f: F, inputs: &[&Tensor], create_graph: bool) -> Result<Vec<Vec<Tensor>>>
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    enable_grad(|| {
+        let xs = track(inputs, create_graph)?;
+        let xs = refs(&xs);
+        let output = f(&xs)?;
+        check_scalar("hessian", &output)?;
+        let grads = try_grad(&[&output], &xs, None, true, true)?;
+        grads.iter().map(|grad| jacobian_of(grad, &xs, create_graph)).collect()
+    })
+}
+
+/// Hessiana de uma `f` escalar: o bloco `[i][j]` tem forma `entrada_i ++ entrada_j`
+pub fn hessian<F>(f: F, inputs: &[&Tensor], create_graph: bool) -> Vec<Vec<Tensor>>
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    try_hessian(f, inputs, create_graph).expect("Error computing hessian")
+}
+
+/// Produto hessiana-vetor `H·v` de uma `f` escalar, sem montar a hessiana;
+/// devolve `(f(inputs), hvp)`
+pub fn try_hvp<F>(f: F, inputs: &[&Tensor], v: &[&Tensor], create_graph: bool) -> Result<(Tensor, Vec<Tensor>)>
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    check_vectors("hvp", inputs, v)?;
+    enable_grad(|| {
+        let xs = track(inputs, create_graph)?;
+        let xs = refs(&xs);
+        let output = f(&xs)?;
+        check_scalar("hvp", &output)?;
+        let grads = try_grad(&[&output], &xs, None, true, true)?;
+        // A hessiana é simétrica, então o vjp do gradiente com v é H·v
+        let hvp = try_grad(&refs(&grads), &xs, Some(v), create_graph, create_graph)?;
+        Ok((finish(output, create_graph)?, hvp))
+    })
+}
+
+/// Produto hessiana-vetor `H·v` de uma `f` escalar; devolve `(f(inputs), hvp)`
+pub fn hvp<F>(f: F, inputs: &[&Tensor], v: &[&Tensor], create_graph: bool) -> (Tensor, Vec<Tensor>)
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    try_hvp(f, inputs, v, create_graph).expect("Error computing hvp")
+}
+
+/// Prepara as entradas como folhas que exigem gradiente; com `create_graph`, entradas
+/// que já estão no grafo continuam ligadas a ele para permitir derivadas de ordem maior
+fn track(inputs: &[&Tensor], create_graph: bool) -> Result<Vec<Tensor>> {
+    inputs
+        .iter()
+        .map(|input| {
+            if create_graph && input.is_requires_grad() {
+                input.try_reshape(input.shape())
+            } else {
+                input.try_detach()?.try_requires_grad(true)
+            }
+        })
+        .collect()
+}
+
+fn refs(tensors: &[Tensor]) -> Vec<&Tensor> {
+    tensors.iter().collect()
+}
+
+fn finish(tensor: Tensor, create_graph: bool) -> Result<Tensor> {
+    if create_graph {
+        Ok(tensor)
+    } else {
+        tensor.try_detach()
+    }
+}
+
+/// Jacobiana de `output` em relação a cada entrada, um vjp por elemento de `output`
+fn jacobian_of(output: &Tensor, inputs: &[&Tensor], create_graph: bool) -> Result<Vec<Tensor>> {
+    let mut rows: Vec<Vec<Tensor>> = inputs.iter().map(|_| Vec::with_capacity(output.numel())).collect();
+    for index in 0..output.numel() {
+        let v = one_hot(output, index)?;
+        let grads = try_grad(&[output], inputs, Some(&[&v]), true, create_graph)?;
+        for (row, grad) in rows.iter_mut().zip(grads) {
+            row.push(grad);
+        }
+    }
+    inputs
+        .iter()
+        .zip(rows)
+        .map(|(input, row)| {
+            let shape: Vec<i64> = output.shape().iter().chain(input.shape()).copied().collect();
+            if row.is_empty() {
+                return Tensor::try_zeros_dtype(&shape, input.dtype());
+            }
+            join::try_stack(&refs(&row), 0)?.try_reshape(&shape)
+        })
+        .collect()
+}
+
+fn one_hot(like: &Tensor, index: usize) -> Result<Tensor> {
+    let mut values = vec![0.0f64; like.numel()];
+    values[index] = 1.0;
+    Tensor::try_from_slice(&values, like.shape())?.try_to_dtype(like.dtype())
+}
+
+fn check_same_shape(op: &'static str, expected: &Tensor, found: &Tensor) -> Result<()> {
+    if expected.shape() != found.shape() {
+        return Err(CopperError::ShapeMismatch {
+            op,
+            left: expected.shape().to_vec(),
+            right: found.shape().to_vec(),
+        });
+    }
+    Ok(())
+}
+
+fn check_vectors(op: &'static str, inputs: &[&Tensor], v: &[&Tensor]) -> Result<()> {
+    if inputs.len() != v.len() {
+        return Err(CopperError::InvalidArgument(format!(
+            "{} recebeu {} vetores para {} entradas",
+            op,
+            v.len(),
+            inputs.len()
+        )));
+    }
+    inputs.iter().zip(v).try_for_each(|(input, v)| check_same_shape(op, input, v))
+}
+
+fn check_scalar(op: &'static str, output: &Tensor) -> Result<()> {
+    if output.numel() != 1 {
+        return Err(CopperError::InvalidArgument(format!(
+            "{} exige uma função escalar, a saída tem forma {:?}",
+            op,
+            output.shape()
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,256 @@
+// Gradient Checking
+// Compara os gradientes do backward com diferenças finitas centrais, para validar
+// camadas novas e backward de `Function`s escritas à mão
+
+use crate::dtype::DType;
+use crate::error::{CopperError, Result};
+use crate::shape;
+use crate::tensor_libortch::autograd::{enable_grad, no_grad};
+use crate::tensor_libortch::tensor::Tensor;
+use std::fmt;
+
+/// Elemento da jacobiana em que o gradiente analítico e o numérico divergem
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradMismatch {
+    /// Posição da entrada em `inputs`
+    pub input: usize,
+    /// Índice do elemento perturbado dentro da entrada
+    pub input_index: Vec<i64>,
+    /// Índice do elemento da saída que foi derivado
+    pub output_index: Vec<i64>,
+    /// Valor obtido pelo backward
+    pub analytic: f64,
+    /// Valor obtido por diferenças finitas
+    pub numeric: f64,
+}
+
+impl GradMismatch {
+    pub fn abs_error(&self) -> f64 {
+        (self.analytic - self.numeric).abs()
+    }
+}
+
+/// Resultado do `gradcheck`: `worst` é o elemento mais longe da tolerância, mesmo
+/// quando todos passam
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradcheckReport {
+    pub passed: bool,
+    pub worst: Option<GradMismatch>,
+}
+
+impl fmt::Display for GradcheckReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.passed { "gradcheck ok" } else { "gradcheck falhou" };
+        match &self.worst {
+            None => write!(f, "{}", status),
+            Some(worst) => write!(
+                f,
+                "{}: pior elemento na entrada {} {:?}, saída {:?}: analítico {}, numérico {} (erro {})",
+                status,
+                worst.input,
+                worst.input_index,
+                worst.output_index,
+                worst.analytic,
+                worst.numeric,
+                worst.abs_error()
+            ),
+        }
+    }
+}
+
+impl Tensor {
+    /// Confere os gradientes de `f` por diferenças finitas centrais com passo `eps`
+    ///
+    /// Cada elemento da jacobiana passa se `|analítico - numérico| <= atol + rtol * |numérico|`.
+    /// As entradas são convertidas para f64 antes da checagem, então `f` deve aceitar esse dtype.
+    pub fn try_gradcheck<F>(f: F, inputs: &[&Tensor], eps: f64, atol: f64, rtol: f64) -> Result<GradcheckReport>
+    where
+        F: Fn(&[&Tensor]) -> Result<Tensor>,
+    {
+        if eps.is_nan() || eps <= 0.0 || atol < 0.0 || rtol < 0.0 {
+            return Err(CopperError::InvalidArgument(format!(
+                "gradcheck exige eps > 0 e tolerâncias não negativas (eps = {}, atol = {}, rtol = {})",
+                eps, atol, rtol
+            )));
+        }
+        let inputs = inputs
+            .iter()
+            .map(|input| input.try_detach()?.try_to_dtype(DType::Float64))
+            .collect::<Result<Vec<Tensor>>>()?;
+        let output_shape = no_grad(|| f(&inputs.iter().collect::<Vec<_>>()))?.shape().to_vec();
+        let analytic = analytic_jacobian(&f, &inputs, &output_shape)?;
+        let numeric = numeric_jacobian(&f, &inputs, eps)?;
+        let input_shapes: Vec<&[i64]> = inputs.iter().map(|x| x.shape()).collect();
+        Ok(compare_jacobians(&analytic, &numeric, &input_shapes, &output_shape, atol, rtol))
+    }
+
+    /// Confere os gradientes de `f` por diferenças finitas centrais com passo `eps`
+    pub fn gradcheck<F>(f: F, inputs: &[&Tensor], eps: f64, atol: f64, rtol: f64) -> GradcheckReport
+    where
+        F: Fn(&[&Tensor]) -> Result<Tensor>,
+    {
+        Tensor::try_gradcheck(f, inputs, eps, atol, rtol).expect("Error in gradcheck")
+    }
+}
+
+/// Compara as jacobianas elemento a elemento. Cada jacobiana tem uma linha por elemento
+/// da saída e uma coluna por elemento da entrada, em ordem row-major.
+fn compare_jacobians(
+    analytic: &[Vec<f64>],
+    numeric: &[Vec<f64>],
+    input_shapes: &[&[i64]],
+    output_shape: &[i64],
+    atol: f64,
+    rtol: f64,
+) -> GradcheckReport {
+    let mut passed = true;
+    let mut worst: Option<(f64, GradMismatch)> = None;
+    for (input, (analytic, numeric)) in analytic.iter().zip(numeric).enumerate() {
+        let input_numel = shape::numel(input_shapes[input]);
+        for (offset, (&a, &n)) in analytic.iter().zip(numeric).enumerate() {
+            let tolerance = atol + rtol * n.abs();
+            passed &= (a - n).abs() <= tolerance;
+            // Erro medido em unidades da tolerância: acima de 1 o elemento falha
+            let excess = (a - n).abs() / tolerance.max(f64::MIN_POSITIVE);
+            if worst.as_ref().is_none_or(|(current, _)| excess > *current) {
+                let mismatch = GradMismatch {
+                    input,
+                    input_index: shape::unravel_index(offset % input_numel, input_shapes[input]),
+                    output_index: shape::unravel_index(offset / input_numel, output_shape),
+                    analytic: a,
+                    numeric: n,
+                };
+                worst = Some((excess, mismatch));
+            }
+        }
+    }
+    GradcheckReport { passed, worst: worst.map(|(_, mismatch)| mismatch) }
+}
+
+/// Valores do tensor como f64, em ordem row-major
+fn values(tensor: &Tensor) -> Result<Vec<f64>> {
+    tensor.try_to_dtype(DType::Float64)?.try_data_as::<f64>()
+}
+
+/// Jacobiana de cada entrada, linha a linha: um backward da saída ponderada por
+/// cada vetor canônico, com folhas novas a cada passada
+fn analytic_jacobian<F>(f: &F, inputs: &[Tensor], output_shape: &[i64]) -> Result<Vec<Vec<f64>>>
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    let output_numel = shape::numel(output_shape);
+    let mut jacobians: Vec<Vec<f64>> = inputs.iter().map(|x| Vec::with_capacity(output_numel * x.numel())).collect();
+    for row in 0..output_numel {
+        let mut weights = vec![0.0f64; output_numel];
+        weights[row] = 1.0;
+        let weights = Tensor::try_from_slice(&weights, output_shape)?;
+        let leaves = inputs
+            .iter()
+            .map(|x| x.try_detach()?.try_requires_grad(true))
+            .collect::<Result<Vec<Tensor>>>()?;
+        enable_grad(|| -> Result<()> {
+            let output = f(&leaves.iter().collect::<Vec<_>>())?;
+            if output.shape() != output_shape {
+                return Err(CopperError::ShapeMismatch {
+                    op: "gradcheck",
+                    left: output_shape.to_vec(),
+                    right: output.shape().to_vec(),
+                });
+            }
+            let selected = output.try_to_dtype(DType::Float64)?.try_mul(&weights)?.try_reshape(&[-1])?.try_sum_dim(0, false)?;
+            // Saídas constantes não têm grafo; o gradiente esperado é zero
+            if selected.is_requires_grad() {
+                selected.try_backward()?;
+            }
+            Ok(())
+        })?;
+        for (jacobian, leaf) in jacobians.iter_mut().zip(&leaves) {
+            match leaf.try_grad()? {
+                Some(grad) => jacobian.extend(values(&grad)?),
+                None => jacobian.extend(std::iter::repeat_n(0.0, leaf.numel())),
+            }
+        }
+    }
+    Ok(jacobians)
+}
+
+/// Jacobiana de cada entrada por diferenças centrais `(f(x + eps) - f(x - eps)) / 2eps`,
+/// no mesmo layout de `analytic_jacobian`
+fn numeric_jacobian<F>(f: &F, inputs: &[Tensor], eps: f64) -> Result<Vec<Vec<f64>>>
+where
+    F: Fn(&[&Tensor]) -> Result<Tensor>,
+{
+    no_grad(|| {
+        inputs
+            .iter()
+            .enumerate()
+            .map(|(position, input)| {
+                let base = values(input)?;
+                let mut columns = Vec::with_capacity(base.len());
+                for element in 0..base.len() {
+                    let evaluate = |delta: f64| -> Result<Vec<f64>> {
+                        let mut perturbed = base.clone();
+                        perturbed[element] += delta;
+                        let perturbed = Tensor::try_from_slice(&perturbed, input.shape())?;
+                        let args: Vec<&Tensor> = inputs
+                            .iter()
+                            .enumerate()
+                            .map(|(i, x)| if i == position { &perturbed } else { x })
+                            .collect();
+                        values(&f(&args)?)
+                    };
+                    let plus = evaluate(eps)?;
+                    let minus = evaluate(-eps)?;
+                    columns.push(plus.iter().zip(&minus).map(|(p, m)| (p - m) / (2.0 * eps)).collect::<Vec<f64>>());
+                }
+                Ok(transpose_columns(&columns))
+            })
+            .collect()
+    })
+}
+
+/// Transpõe de uma coluna por elemento da entrada para linhas por elemento da saída
+fn transpose_columns(columns: &[Vec<f64>]) -> Vec<f64> {
+    let output_numel = columns.first().map_or(0, Vec::len);
+    (0..output_numel)
+        .flat_map(|row| columns.iter().map(move |column| column[row]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_become_row_major_rows() {
+        // Entrada com 3 elementos e saída com 2: coluna j = ∂saída/∂x[j]
+        let columns = vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]];
+        assert_eq!(transpose_columns(&columns), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert!(transpose_columns(&[]).is_empty());
+    }
+
+    #[test]
+    fn worst_mismatch_maps_offset_to_indices() {
+        // Saída [2] e entradas [2, 2] e [3]: offset = linha · numel(entrada) + coluna
+        let numeric = vec![vec![0.0; 8], vec![0.0; 6]];
+        let mut analytic = numeric.clone();
+        analytic[0][6] = 0.5; // linha 1, elemento (1, 0) da entrada 0
+        analytic[1][2] = 2.0; // linha 0, elemento 2 da entrada 1
+        let report = compare_jacobians(&analytic, &numeric, &[&[2, 2], &[3]], &[2], 1e-6, 0.0);
+        assert!(!report.passed);
+        let worst = report.worst.unwrap();
+        assert_eq!(worst.abs_error(), 2.0);
+        assert_eq!((worst.input, worst.input_index, worst.output_index), (1, vec![2], vec![0]));
+
+        let report = compare_jacobians(&numeric, &numeric, &[&[2, 2], &[3]], &[2], 1e-6, 0.0);
+        assert!(report.passed);
+    }
+
+    #[test]
+    fn tolerance_scales_with_numeric_value() {
+        let report = compare_jacobians(&[vec![101.0]], &[vec![100.0]], &[&[]], &[], 0.0, 0.02);
+        assert!(report.passed);
+        let report = compare_jacobians(&[vec![103.0]], &[vec![100.0]], &[&[]], &[], 0.0, 0.02);
+        assert!(!report.passed);
+    }
+}
@@ -0,0 +1,167 @@
+// Autograd
+// Controle de gradientes em tensores arbitrários e do modo de gradiente da thread
+
+use crate::error::{check_call, Result};
+use crate::tensor_libortch::ffi::{
+    TensorSetRequiresGrad,
+    TensorRequiresGrad,
+    TensorIsLeaf,
+    TensorGrad,
+    TensorDetach,
+    TensorRetainGrad,
+    SetGradEnabled,
+    IsGradEnabled,
+    TensorListSize,
+    TensorListGet,
+    FreeTensorList,
+};
+use crate::tensor_libortch::tensor::Tensor;
+use std::marker::PhantomData;
+
+pub mod function;
+pub mod functional;
+pub mod gradcheck;
+pub use function::{Context, Function};
+pub use gradcheck::{GradMismatch, GradcheckReport};
+
+impl Tensor {
+    /// Liga ou desliga o registro de operações para o backward; devolve o próprio tensor
+    /// para permitir `Tensor::randn(&[3]).requires_grad(true)`
+    pub fn try_requires_grad(self, requires_grad: bool) -> Result<Tensor> {
+        check_call("TensorSetRequiresGrad", || unsafe { TensorSetRequiresGrad(self.ptr, requires_grad) })?;
+        Ok(self)
+    }
+
+    /// Liga ou desliga o registro de operações para o backward; devolve o próprio tensor
+    /// para permitir `Tensor::randn(&[3]).requires_grad(true)`
+    pub fn requires_grad(self, requires_grad: bool) -> Tensor {
+        self.try_requires_grad(requires_grad).expect("Error setting requires_grad")
+    }
+
+    /// Indica se o tensor participa do grafo de autograd
+    pub fn is_requires_grad(&self) -> bool {
+        check_call("TensorRequiresGrad", || unsafe { TensorRequiresGrad(self.ptr) })
+            .map(|flag| flag == 1)
+            .unwrap_or(false)
+    }
+
+    /// Indica se o tensor é uma folha do grafo (criado pelo usuário, não por uma op)
+    pub fn is_leaf(&self) -> bool {
+        check_call("TensorIsLeaf", || unsafe { TensorIsLeaf(self.ptr) })
+            .map(|flag| flag == 1)
+            .unwrap_or(false)
+    }
+
+    /// Gradiente acumulado pelo último backward, ou `None` se ainda não existir
+    pub fn try_grad(&self) -> Result<Option<Tensor>> {
+        let ptr = check_call("TensorGrad", || unsafe { TensorGrad(self.ptr) })?;
+        if ptr.is_null() {
+            Ok(None)
+        } else {
+            Tensor::from_ptr(ptr, "TensorGrad").map(Some)
+        }
+    }
+
+    /// Gradiente acumulado pelo último backward, ou `None` se ainda não existir
+    pub fn grad(&self) -> Option<Tensor> {
+        self.try_grad().expect("Error reading gradient")
+    }
+
+    /// Tensor que compartilha os dados mas fica fora do grafo de autograd
+    pub fn try_detach(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorDetach(self.ptr) }, "TensorDetach")
+    }
+
+    /// Tensor que compartilha os dados mas fica fora do grafo de autograd
+    pub fn detach(&self) -> Tensor {
+        self.try_detach().expect("Error detaching tensor")
+    }
+
+    /// Pede que o backward também preencha `grad()` deste tensor intermediário
+    pub fn try_retain_grad(&self) -> Result<()> {
+        check_call("TensorRetainGrad", || unsafe { TensorRetainGrad(self.ptr) })
+    }
+
+    /// Pede que o backward também preencha `grad()` deste tensor intermediário
+    pub fn retain_grad(&self) {
+        self.try_retain_grad().expect("Error in retain_grad")
+    }
+}
+
+/// Indica se a thread atual está registrando operações para o autograd
+pub fn is_grad_enabled() -> bool {
+    check_call("IsGradEnabled", || unsafe { IsGradEnabled() })
+        .map(|flag| flag == 1)
+        .unwrap_or(true)
+}
+
+fn set_grad_enabled(enabled: bool) {
+    check_call("SetGradEnabled", || unsafe { SetGradEnabled(enabled) }).expect("Error setting grad mode");
+}
+
+/// Desliga o autograd na thread atual enquanto estiver vivo, como `torch::NoGradGuard`;
+/// ao sair do escopo o modo anterior é restaurado
+pub struct NoGradGuard {
+    previous: bool,
+    // O modo de gradiente é por thread, então o guard não pode mudar de thread
+    _not_send: PhantomData<*const ()>,
+}
+
+impl NoGradGuard {
+    pub fn new() -> Self {
+        let previous = is_grad_enabled();
+        set_grad_enabled(false);
+        NoGradGuard { previous, _not_send: PhantomData }
+    }
+}
+
+impl Default for NoGradGuard {
+    fn default() -> Self {
+        NoGradGuard::new()
+    }
+}
+
+impl Drop for NoGradGuard {
+    fn drop(&mut self) {
+        set_grad_enabled(self.previous);
+    }
+}
+
+/// Executa `f` com o autograd desligado
+pub fn no_grad<R>(f: impl FnOnce() -> R) -> R {
+    let _guard = NoGradGuard::new();
+    f()
+}
+
+/// Executa `f` com o autograd ligado, mesmo dentro de um `no_grad`
+pub(crate) fn enable_grad<R>(f: impl FnOnce() -> R) -> R {
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            set_grad_enabled(self.0);
+        }
+    }
+
+    let _restore = Restore(is_grad_enabled());
+    set_grad_enabled(true);
+    f()
+}
+
+/// Dono de um std::vector<at::Tensor>* devolvido pelo shim
+pub(crate) struct TensorList(pub(crate) *mut libc::c_void);
+
+impl TensorList {
+    pub(crate) fn into_tensors(self) -> Result<Vec<Tensor>> {
+        let size = check_call("TensorListSize", || unsafe { TensorListSize(self.0) })?;
+        (0..size)
+            .map(|index| Tensor::from_ptr(unsafe { TensorListGet(self.0, index) }, "TensorListGet"))
+            .collect()
+    }
+}
+
+impl Drop for TensorList {
+    fn drop(&mut self) {
+        unsafe { FreeTensorList(self.0) };
+    }
+}
@@ -1,27 +1,75 @@
-use libc::c_void;
+use libc::{c_char, c_int, c_void};
+
+// Callbacks Rust chamados pelo RustFunction do shim (0 = sucesso)
+pub type CopperForwardFn = extern "C" fn(
+    state: *mut c_void,
+    ctx: *mut c_void,
+    inputs: *const *mut c_void,
+    num_inputs: c_int,
+    outputs: *mut c_void,
+) -> c_int;
+pub type CopperBackwardFn = extern "C" fn(
+    state: *mut c_void,
+    ctx: *mut c_void,
+    grad_outputs: *const *mut c_void,
+    num_grads: c_int,
+    grad_inputs: *mut c_void,
+) -> c_int;
+pub type CopperDropFn = extern "C" fn(state: *mut c_void);
+// Closure de OptimizerStepClosure: empurra a perda em `loss` (uma lista de tensores)
+pub type CopperClosureFn = extern "C" fn(state: *mut c_void, loss: *mut c_void) -> c_int;
 
 #[link(name = "ai_copper", kind = "dylib")]
 extern "C" {
     // Tensor Creation
-    pub fn CreateMatrixTensor(values: *const f32, rows: i32, cols: i32) -> *mut c_void;
-    pub fn CreateTensorOnes(rows: i32, cols: i32) -> *mut c_void;
-    pub fn CreateTensorRand(rows: i32, cols: i32) -> *mut c_void;
-    pub fn CreateTensorZeros(rows: i32, cols: i32) -> *mut c_void;
-    pub fn CreateTensorRandn(rows: i32, cols: i32) -> *mut c_void;
+    pub fn CreateTensorFromData(values: *const c_void, dims: *const i64, ndim: c_int, dtype: c_int) -> *mut c_void;
+    pub fn CreateTensorOnes(dims: *const i64, ndim: c_int, dtype: c_int) -> *mut c_void;
+    pub fn CreateTensorRand(dims: *const i64, ndim: c_int, dtype: c_int) -> *mut c_void;
+    pub fn ManualSeed(seed: u64);
+    pub fn CreateTensorZeros(dims: *const i64, ndim: c_int, dtype: c_int) -> *mut c_void;
+    pub fn CreateTensorRandn(dims: *const i64, ndim: c_int, dtype: c_int) -> *mut c_void;
     pub fn CreateTensorEye(size: i32) -> *mut c_void;
     pub fn TensorZerosLike(tensor_ptr: *mut c_void) -> *mut c_void;
     pub fn TensorOnesLike(tensor_ptr: *mut c_void) -> *mut c_void;
     
     // Tensor Operations
     pub fn FreeTensor(ptr: *mut c_void);
-    pub fn TensorData(ptr: *mut c_void) -> *const f32;
-    pub fn TensorRows(ptr: *mut c_void) -> i32;
-    pub fn TensorCols(ptr: *mut c_void) -> i32;
+    pub fn TensorData(ptr: *mut c_void) -> *const c_void;
+    pub fn TensorDType(ptr: *mut c_void) -> c_int;
+    pub fn TensorToDType(ptr: *mut c_void, dtype: c_int) -> *mut c_void;
+    pub fn TensorDim(ptr: *mut c_void) -> c_int;
+    pub fn TensorSizes(ptr: *mut c_void, out: *mut i64);
+    pub fn TensorNumel(ptr: *mut c_void) -> i64;
+    pub fn TensorStrides(ptr: *mut c_void, out: *mut i64);
+    pub fn TensorIsContiguous(ptr: *mut c_void) -> c_int;
+
+    // Views and Indexing
+    pub fn TensorContiguous(ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorNarrow(ptr: *mut c_void, dim: i64, start: i64, length: i64) -> *mut c_void;
+    pub fn TensorSelect(ptr: *mut c_void, dim: i64, index: i64) -> *mut c_void;
+    pub fn TensorSlice(ptr: *mut c_void, dim: i64, start: i64, end: i64, step: i64) -> *mut c_void;
+    pub fn TensorIndexSelect(ptr: *mut c_void, dim: i64, index: *mut c_void) -> *mut c_void;
+    pub fn TensorMaskedSelect(ptr: *mut c_void, mask: *mut c_void) -> *mut c_void;
+
+    // Shape Functions
+    pub fn TensorReshape(tensor_ptr: *mut c_void, dims: *const i64, ndim: c_int) -> *mut c_void;
+    pub fn TensorTranspose(tensor_ptr: *mut c_void, dim0: i64, dim1: i64) -> *mut c_void;
     
     // Activation Functions
     pub fn TensorReLU(tensor_ptr: *mut c_void) -> *mut c_void;
     pub fn TensorSigmoid(tensor_ptr: *mut c_void) -> *mut c_void;
     pub fn TensorTanh(tensor_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorSoftmax(tensor_ptr: *mut c_void, dim: i64) -> *mut c_void;
+    pub fn TensorLogSoftmax(tensor_ptr: *mut c_void, dim: i64) -> *mut c_void;
+    pub fn TensorGelu(tensor_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorSilu(tensor_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorLeakyRelu(tensor_ptr: *mut c_void, negative_slope: f64) -> *mut c_void;
+    pub fn TensorElu(tensor_ptr: *mut c_void, alpha: f64) -> *mut c_void;
+    pub fn TensorSelu(tensor_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorSoftplus(tensor_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorMish(tensor_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorHardtanh(tensor_ptr: *mut c_void, min_val: f64, max_val: f64) -> *mut c_void;
+    pub fn TensorRelu6(tensor_ptr: *mut c_void) -> *mut c_void;
     
     // Mathematical Functions
     pub fn TensorSin(tensor_ptr: *mut c_void) -> *mut c_void;
@@ -32,24 +80,368 @@ extern "C" {
     pub fn TensorAbs(tensor_ptr: *mut c_void) -> *mut c_void;
     pub fn TensorPow(tensor_ptr: *mut c_void, exponent: f32) -> *mut c_void;
     
+    // Arithmetic Functions (broadcasting)
+    pub fn TensorAdd(a_ptr: *mut c_void, b_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorSub(a_ptr: *mut c_void, b_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorMul(a_ptr: *mut c_void, b_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorDiv(a_ptr: *mut c_void, b_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorAddScalar(tensor_ptr: *mut c_void, value: f64) -> *mut c_void;
+    pub fn TensorSubScalar(tensor_ptr: *mut c_void, value: f64) -> *mut c_void;
+    pub fn TensorRSubScalar(tensor_ptr: *mut c_void, value: f64) -> *mut c_void;
+    pub fn TensorMulScalar(tensor_ptr: *mut c_void, value: f64) -> *mut c_void;
+    pub fn TensorDivScalar(tensor_ptr: *mut c_void, value: f64) -> *mut c_void;
+    pub fn TensorRDivScalar(tensor_ptr: *mut c_void, value: f64) -> *mut c_void;
+
+    // Matrix Functions
+    pub fn TensorMatmul(a_ptr: *mut c_void, b_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorBmm(a_ptr: *mut c_void, b_ptr: *mut c_void) -> *mut c_void;
+
+    // Joining
+    pub fn TensorCat(tensors: *const *mut c_void, count: c_int, dim: i64) -> *mut c_void;
+    pub fn TensorStack(tensors: *const *mut c_void, count: c_int, dim: i64) -> *mut c_void;
+
+    // In-place Arithmetic
+    pub fn TensorAddInPlace(a_ptr: *mut c_void, b_ptr: *mut c_void);
+    pub fn TensorSubInPlace(a_ptr: *mut c_void, b_ptr: *mut c_void);
+    pub fn TensorMulInPlace(a_ptr: *mut c_void, b_ptr: *mut c_void);
+    pub fn TensorDivInPlace(a_ptr: *mut c_void, b_ptr: *mut c_void);
+    pub fn TensorAddScalarInPlace(tensor_ptr: *mut c_void, value: f64);
+    pub fn TensorSubScalarInPlace(tensor_ptr: *mut c_void, value: f64);
+    pub fn TensorMulScalarInPlace(tensor_ptr: *mut c_void, value: f64);
+    pub fn TensorDivScalarInPlace(tensor_ptr: *mut c_void, value: f64);
+
+    // Initialization (no lugar, sem autograd)
+    pub fn TensorUniformInPlace(tensor_ptr: *mut c_void, low: f64, high: f64);
+    pub fn TensorNormalInPlace(tensor_ptr: *mut c_void, mean: f64, std: f64);
+    pub fn TensorFillInPlace(tensor_ptr: *mut c_void, value: f64);
+    pub fn TensorTruncNormalInPlace(tensor_ptr: *mut c_void, mean: f64, std: f64, a: f64, b: f64);
+    pub fn TensorOrthogonalInPlace(tensor_ptr: *mut c_void, gain: f64);
+    
     // Statistical Functions
     pub fn TensorStd(tensor_ptr: *mut c_void) -> f32;
     pub fn TensorVar(tensor_ptr: *mut c_void) -> f32;
-    pub fn TensorArgmax(tensor_ptr: *mut c_void) -> i32;
-    pub fn TensorArgmin(tensor_ptr: *mut c_void) -> i32;
+    pub fn TensorProd(tensor_ptr: *mut c_void) -> f32;
+    pub fn TensorLogsumexp(tensor_ptr: *mut c_void) -> f32;
+    pub fn TensorNorm(tensor_ptr: *mut c_void) -> f32;
+    pub fn TensorAny(tensor_ptr: *mut c_void) -> c_int;
+    pub fn TensorAll(tensor_ptr: *mut c_void) -> c_int;
+
+    // Reductions along a dimension
+    pub fn TensorSumDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorMeanDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorMaxDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorMinDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorStdDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorVarDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorArgmaxDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorArgminDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorProdDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorLogsumexpDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorNormDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorAnyDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
+    pub fn TensorAllDim(tensor_ptr: *mut c_void, dim: i64, keepdim: bool) -> *mut c_void;
     
+    // Modules
+    pub fn FreeModule(ptr: *mut c_void);
+    pub fn ModuleParameters(module_ptr: *mut c_void) -> *mut c_void;
+    pub fn ModuleParameterName(module_ptr: *mut c_void, index: c_int) -> *const c_char;
+    pub fn ModuleBuffers(module_ptr: *mut c_void) -> *mut c_void;
+    pub fn ModuleBufferName(module_ptr: *mut c_void, index: c_int) -> *const c_char;
+    pub fn ModuleTrain(module_ptr: *mut c_void, on: bool);
+    pub fn ModuleIsTraining(module_ptr: *mut c_void) -> c_int;
+
     // Neural Network
     pub fn CreateLinear(in_features: i32, out_features: i32) -> *mut c_void;
     pub fn LinearForward(linear_ptr: *mut c_void, input_tensor_ptr: *mut c_void) -> *mut c_void;
+    pub fn CreateConv(
+        ndim: c_int,
+        transposed: bool,
+        in_channels: i64,
+        out_channels: i64,
+        kernel_size: *const i64,
+        stride: *const i64,
+        padding: *const i64,
+        output_padding: *const i64,
+        dilation: *const i64,
+        groups: i64,
+        bias: bool,
+    ) -> *mut c_void;
+    pub fn ConvForward(module_ptr: *mut c_void, input_ptr: *mut c_void) -> *mut c_void;
+    pub fn CreateRNN(
+        mode: c_int,
+        input_size: i64,
+        hidden_size: i64,
+        num_layers: i64,
+        bias: bool,
+        batch_first: bool,
+        dropout: f64,
+        bidirectional: bool,
+    ) -> *mut c_void;
+    pub fn RNNForward(
+        module_ptr: *mut c_void,
+        input_ptr: *mut c_void,
+        h0_ptr: *mut c_void,
+        c0_ptr: *mut c_void,
+    ) -> *mut c_void;
+    pub fn CreateEmbedding(
+        num_embeddings: i64,
+        embedding_dim: i64,
+        padding_idx: *const i64,
+        max_norm: *const f64,
+        weight_ptr: *mut c_void,
+        freeze: bool,
+    ) -> *mut c_void;
+    pub fn EmbeddingForward(module_ptr: *mut c_void, input_ptr: *mut c_void) -> *mut c_void;
+    pub fn CreateEmbeddingBag(
+        num_embeddings: i64,
+        embedding_dim: i64,
+        mode: c_int,
+        padding_idx: *const i64,
+        max_norm: *const f64,
+        weight_ptr: *mut c_void,
+        freeze: bool,
+    ) -> *mut c_void;
+    pub fn EmbeddingBagForward(
+        module_ptr: *mut c_void,
+        input_ptr: *mut c_void,
+        offsets_ptr: *mut c_void,
+        per_sample_weights_ptr: *mut c_void,
+    ) -> *mut c_void;
+    pub fn CreateBatchNorm(
+        ndim: c_int,
+        instance: bool,
+        num_features: i64,
+        eps: f64,
+        momentum: f64,
+        affine: bool,
+        track_running_stats: bool,
+    ) -> *mut c_void;
+    pub fn CreateLayerNorm(normalized_shape: *const i64, ndim: c_int, eps: f64, elementwise_affine: bool) -> *mut c_void;
+    pub fn CreateGroupNorm(num_groups: i64, num_channels: i64, eps: f64, affine: bool) -> *mut c_void;
+    pub fn NormForward(module_ptr: *mut c_void, input_ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorDropout(tensor_ptr: *mut c_void, p: f64, train: bool, kind: c_int) -> *mut c_void;
+    pub fn TensorAttention(
+        query_ptr: *mut c_void,
+        key_ptr: *mut c_void,
+        value_ptr: *mut c_void,
+        attn_mask_ptr: *mut c_void,
+        key_padding_mask_ptr: *mut c_void,
+        dropout_p: f64,
+        train: bool,
+    ) -> *mut c_void;
+
+    // Serialization
+    pub fn SaveTensors(names: *const *const c_char, tensors: *const *mut c_void, count: c_int, path: *const c_char);
+    pub fn LoadTensors(names: *const *const c_char, tensors: *const *mut c_void, count: c_int, path: *const c_char);
+
+    // Convolution and Pooling (functional)
+    pub fn TensorConv(
+        input_ptr: *mut c_void,
+        weight_ptr: *mut c_void,
+        bias_ptr: *mut c_void,
+        stride: *const i64,
+        padding: *const i64,
+        output_padding: *const i64,
+        dilation: *const i64,
+        ndim: c_int,
+        transposed: bool,
+        groups: i64,
+    ) -> *mut c_void;
+    pub fn TensorMaxPool(
+        input_ptr: *mut c_void,
+        kernel_size: *const i64,
+        stride: *const i64,
+        padding: *const i64,
+        dilation: *const i64,
+        ndim: c_int,
+        ceil_mode: bool,
+    ) -> *mut c_void;
+    pub fn TensorAvgPool(
+        input_ptr: *mut c_void,
+        kernel_size: *const i64,
+        stride: *const i64,
+        padding: *const i64,
+        ndim: c_int,
+        ceil_mode: bool,
+        count_include_pad: bool,
+    ) -> *mut c_void;
+    pub fn TensorAdaptiveAvgPool(input_ptr: *mut c_void, output_size: *const i64, ndim: c_int) -> *mut c_void;
     
     // Loss Functions
-    pub fn MSELoss(prediction_ptr: *mut c_void, target_ptr: *mut c_void) -> *mut c_void;
-    pub fn CrossEntropyLoss(prediction_ptr: *mut c_void, target_ptr: *mut c_void) -> *mut c_void;
+    pub fn MSELoss(prediction_ptr: *mut c_void, target_ptr: *mut c_void, reduction: c_int) -> *mut c_void;
+    pub fn CrossEntropyLoss(
+        prediction_ptr: *mut c_void,
+        target_ptr: *mut c_void,
+        weight_ptr: *mut c_void,
+        reduction: c_int,
+        ignore_index: i64,
+    ) -> *mut c_void;
+    pub fn L1Loss(prediction_ptr: *mut c_void, target_ptr: *mut c_void, reduction: c_int) -> *mut c_void;
+    pub fn SmoothL1Loss(prediction_ptr: *mut c_void, target_ptr: *mut c_void, reduction: c_int, beta: f64) -> *mut c_void;
+    pub fn HuberLoss(prediction_ptr: *mut c_void, target_ptr: *mut c_void, reduction: c_int, delta: f64) -> *mut c_void;
+    pub fn BCELoss(prediction_ptr: *mut c_void, target_ptr: *mut c_void, weight_ptr: *mut c_void, reduction: c_int) -> *mut c_void;
+    pub fn BCEWithLogitsLoss(
+        prediction_ptr: *mut c_void,
+        target_ptr: *mut c_void,
+        weight_ptr: *mut c_void,
+        pos_weight_ptr: *mut c_void,
+        reduction: c_int,
+    ) -> *mut c_void;
+    pub fn NLLLoss(
+        prediction_ptr: *mut c_void,
+        target_ptr: *mut c_void,
+        weight_ptr: *mut c_void,
+        reduction: c_int,
+        ignore_index: i64,
+    ) -> *mut c_void;
+    pub fn KLDivLoss(prediction_ptr: *mut c_void, target_ptr: *mut c_void, reduction: c_int, log_target: bool) -> *mut c_void;
+    pub fn CosineEmbeddingLoss(
+        input1_ptr: *mut c_void,
+        input2_ptr: *mut c_void,
+        target_ptr: *mut c_void,
+        margin: f64,
+        reduction: c_int,
+    ) -> *mut c_void;
+    pub fn MarginRankingLoss(
+        input1_ptr: *mut c_void,
+        input2_ptr: *mut c_void,
+        target_ptr: *mut c_void,
+        margin: f64,
+        reduction: c_int,
+    ) -> *mut c_void;
+    pub fn TripletMarginLoss(
+        anchor_ptr: *mut c_void,
+        positive_ptr: *mut c_void,
+        negative_ptr: *mut c_void,
+        margin: f64,
+        p: f64,
+        eps: f64,
+        swap: bool,
+        reduction: c_int,
+    ) -> *mut c_void;
+    pub fn CTCLoss(
+        log_probs_ptr: *mut c_void,
+        targets_ptr: *mut c_void,
+        input_lengths: *const i64,
+        target_lengths: *const i64,
+        batch_size: c_int,
+        blank: i64,
+        reduction: c_int,
+    ) -> *mut c_void;
     
     // Optimizers
-    pub fn CreateSGD(linear_ptr: *mut c_void, lr: f32) -> *mut c_void;
-    pub fn CreateAdam(linear_ptr: *mut c_void, lr: f32) -> *mut c_void;
+    // Os parâmetros vêm achatados; o grupo g tem group_sizes[g] tensores e lr group_lrs[g]
+    pub fn CreateSGD(
+        params: *const *mut c_void,
+        group_sizes: *const c_int,
+        group_lrs: *const f64,
+        num_groups: c_int,
+        lr: f64,
+        momentum: f64,
+        dampening: f64,
+        weight_decay: f64,
+        nesterov: bool,
+    ) -> *mut c_void;
+    pub fn CreateAdam(
+        params: *const *mut c_void,
+        group_sizes: *const c_int,
+        group_lrs: *const f64,
+        num_groups: c_int,
+        lr: f64,
+        beta1: f64,
+        beta2: f64,
+        eps: f64,
+        weight_decay: f64,
+        amsgrad: bool,
+        decoupled: bool,
+    ) -> *mut c_void;
+    pub fn CreateRMSprop(
+        params: *const *mut c_void,
+        group_sizes: *const c_int,
+        group_lrs: *const f64,
+        num_groups: c_int,
+        lr: f64,
+        alpha: f64,
+        eps: f64,
+        weight_decay: f64,
+        momentum: f64,
+        centered: bool,
+    ) -> *mut c_void;
+    pub fn CreateAdagrad(
+        params: *const *mut c_void,
+        group_sizes: *const c_int,
+        group_lrs: *const f64,
+        num_groups: c_int,
+        lr: f64,
+        lr_decay: f64,
+        weight_decay: f64,
+        initial_accumulator_value: f64,
+        eps: f64,
+    ) -> *mut c_void;
+    pub fn CreateAdadelta(
+        params: *const *mut c_void,
+        group_sizes: *const c_int,
+        group_lrs: *const f64,
+        num_groups: c_int,
+        lr: f64,
+        rho: f64,
+        eps: f64,
+        weight_decay: f64,
+    ) -> *mut c_void;
+    pub fn CreateLBFGS(
+        params: *const *mut c_void,
+        group_sizes: *const c_int,
+        group_lrs: *const f64,
+        num_groups: c_int,
+        lr: f64,
+        max_iter: i64,
+        max_eval: i64,
+        tolerance_grad: f64,
+        tolerance_change: f64,
+        history_size: i64,
+        strong_wolfe: bool,
+    ) -> *mut c_void;
+    pub fn OptimizerStepClosure(optimizer_ptr: *mut c_void, closure: CopperClosureFn, state: *mut c_void) -> *mut c_void;
+    pub fn OptimizerNumGroups(optimizer_ptr: *mut c_void) -> c_int;
+    pub fn OptimizerGetLr(optimizer_ptr: *mut c_void, group: c_int) -> f64;
+    pub fn OptimizerSetLr(optimizer_ptr: *mut c_void, group: c_int, lr: f64);
     pub fn Backward(loss_ptr: *mut c_void);
+
+    // Autograd
+    pub fn TensorSetRequiresGrad(ptr: *mut c_void, requires_grad: bool);
+    pub fn TensorRequiresGrad(ptr: *mut c_void) -> c_int;
+    pub fn TensorIsLeaf(ptr: *mut c_void) -> c_int;
+    pub fn TensorGrad(ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorDetach(ptr: *mut c_void) -> *mut c_void;
+    pub fn TensorRetainGrad(ptr: *mut c_void);
+    pub fn SetGradEnabled(enabled: bool);
+    pub fn IsGradEnabled() -> c_int;
+
+    // Custom Functions
+    pub fn CopperFunctionApply(
+        state: *mut c_void,
+        forward: CopperForwardFn,
+        backward: CopperBackwardFn,
+        drop: CopperDropFn,
+        inputs: *const *mut c_void,
+        num_inputs: c_int,
+    ) -> *mut c_void;
+    pub fn TensorListPush(list: *mut c_void, tensor: *mut c_void);
+    pub fn TensorListSize(list: *mut c_void) -> c_int;
+    pub fn TensorListGet(list: *mut c_void, index: c_int) -> *mut c_void;
+    pub fn FreeTensorList(list: *mut c_void);
+    pub fn AutogradContextSaveForBackward(ctx: *mut c_void, tensors: *const *mut c_void, count: c_int);
+    pub fn AutogradContextSavedTensors(ctx: *mut c_void) -> *mut c_void;
+    pub fn AutogradContextNeedsInputGrad(ctx: *mut c_void, index: c_int) -> c_int;
+    pub fn AutogradGrad(
+        outputs: *const *mut c_void,
+        num_outputs: c_int,
+        inputs: *const *mut c_void,
+        num_inputs: c_int,
+        grad_outputs: *const *mut c_void,
+        retain_graph: bool,
+        create_graph: bool,
+    ) -> *mut c_void;
     pub fn OptimizerStep(optimizer_ptr: *mut c_void);
     pub fn OptimizerZeroGrad(optimizer_ptr: *mut c_void);
     pub fn FreeOptimizer(ptr: *mut c_void);
@@ -0,0 +1,164 @@
+use crate::dtype::{DType, Element};
+use crate::error::{check_call, CopperError, Result};
+use crate::shape;
+use crate::tensor_libortch::ffi::{
+    TensorData,
+    TensorStrides,
+    TensorIsContiguous,
+    TensorContiguous,
+    TensorNarrow,
+    TensorSelect,
+    TensorSlice,
+    TensorIndexSelect,
+    TensorMaskedSelect,
+};
+use crate::tensor_libortch::tensor::Tensor;
+
+impl Tensor {
+    /// Indica se os elementos estão contíguos na memória (views podem não estar)
+    pub fn is_contiguous(&self) -> bool {
+        check_call("TensorIsContiguous", || unsafe { TensorIsContiguous(self.ptr) })
+            .map(|flag| flag == 1)
+            .unwrap_or(false)
+    }
+
+    /// Strides de cada dimensão, em elementos
+    pub fn try_strides(&self) -> Result<Vec<i64>> {
+        let mut strides = vec![0i64; self.dim()];
+        check_call("TensorStrides", || unsafe { TensorStrides(self.ptr, strides.as_mut_ptr()) })?;
+        Ok(strides)
+    }
+
+    /// Strides de cada dimensão, em elementos
+    pub fn strides(&self) -> Vec<i64> {
+        self.try_strides().expect("Error reading tensor strides")
+    }
+
+    /// Copia uma view para um tensor contíguo; tensores já contíguos só ganham uma nova referência
+    pub fn try_contiguous(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorContiguous(self.ptr) }, "TensorContiguous")
+    }
+
+    /// Copia uma view para um tensor contíguo; tensores já contíguos só ganham uma nova referência
+    pub fn contiguous(&self) -> Tensor {
+        self.try_contiguous().expect("Error making tensor contiguous")
+    }
+
+    /// Executa `f` sobre uma versão contígua do tensor (o próprio tensor, se já for)
+    pub(crate) fn try_with_contiguous<R>(&self, f: impl FnOnce(&Tensor) -> Result<R>) -> Result<R> {
+        if self.is_contiguous() {
+            f(self)
+        } else {
+            f(&self.try_contiguous()?)
+        }
+    }
+
+    /// View com `length` elementos de `dim` a partir de `start`; compartilha o storage
+    pub fn try_narrow(&self, dim: i64, start: i64, length: i64) -> Result<Tensor> {
+        let index = shape::normalize_dim(dim, self.shape())?;
+        let start = shape::narrow_start(self.shape()[index], start, length)?;
+        Tensor::from_ptr(unsafe { TensorNarrow(self.ptr, index as i64, start, length) }, "TensorNarrow")
+    }
+
+    /// View com `length` elementos de `dim` a partir de `start`; compartilha o storage
+    pub fn narrow(&self, dim: i64, start: i64, length: i64) -> Tensor {
+        self.try_narrow(dim, start, length).expect("Error narrowing tensor")
+    }
+
+    /// View da posição `index` de `dim`, que é removida da forma; compartilha o storage
+    pub fn try_select(&self, dim: i64, index: i64) -> Result<Tensor> {
+        let dim = shape::normalize_dim(dim, self.shape())?;
+        let index = shape::normalize_index(index, self.shape()[dim])?;
+        Tensor::from_ptr(unsafe { TensorSelect(self.ptr, dim as i64, index) }, "TensorSelect")
+    }
+
+    /// View da posição `index` de `dim`, que é removida da forma; compartilha o storage
+    pub fn select(&self, dim: i64, index: i64) -> Tensor {
+        self.try_select(dim, index).expect("Error selecting from tensor")
+    }
+
+    /// View de `start..end` com passo `step` ao longo de `dim`, como `t[start:end:step]`
+    /// no PyTorch; use `i64::MAX` como `end` para ir até o fim
+    pub fn try_slice(&self, dim: i64, start: i64, end: i64, step: i64) -> Result<Tensor> {
+        let index = shape::normalize_dim(dim, self.shape())?;
+        let (start, len) = shape::slice_range(self.shape()[index], start, end, step)?;
+        let end = shape::slice_end(start, len, step);
+        Tensor::from_ptr(unsafe { TensorSlice(self.ptr, index as i64, start, end, step) }, "TensorSlice")
+    }
+
+    /// View de `start..end` com passo `step` ao longo de `dim`, como `t[start:end:step]`
+    /// no PyTorch; use `i64::MAX` como `end` para ir até o fim
+    pub fn slice(&self, dim: i64, start: i64, end: i64, step: i64) -> Tensor {
+        self.try_slice(dim, start, end, step).expect("Error slicing tensor")
+    }
+
+    /// Copia as posições de `dim` listadas em `index` (tensor 1-D de inteiros)
+    pub fn try_index_select(&self, dim: i64, index: &Tensor) -> Result<Tensor> {
+        let dim = shape::normalize_dim(dim, self.shape())?;
+        if index.dim() > 1 {
+            return Err(CopperError::InvalidArgument(format!(
+                "index_select exige índices 1-D, recebeu forma {:?}",
+                index.shape()
+            )));
+        }
+        if !matches!(index.dtype(), DType::Int64 | DType::Int32) {
+            return Err(CopperError::DTypeMismatch { op: "index_select", expected: DType::Int64, found: index.dtype() });
+        }
+        Tensor::from_ptr(unsafe { TensorIndexSelect(self.ptr, dim as i64, index.ptr) }, "TensorIndexSelect")
+    }
+
+    /// Copia as posições de `dim` listadas em `index` (tensor 1-D de inteiros)
+    pub fn index_select(&self, dim: i64, index: &Tensor) -> Tensor {
+        self.try_index_select(dim, index).expect("Error in index_select")
+    }
+
+    /// Copia para um tensor 1-D os elementos em que `mask` (bool, com broadcasting) é verdadeiro
+    pub fn try_masked_select(&self, mask: &Tensor) -> Result<Tensor> {
+        if mask.dtype() != DType::Bool {
+            return Err(CopperError::DTypeMismatch { op: "masked_select", expected: DType::Bool, found: mask.dtype() });
+        }
+        shape::check_broadcast("masked_select", self.shape(), mask.shape())?;
+        Tensor::from_ptr(unsafe { TensorMaskedSelect(self.ptr, mask.ptr) }, "TensorMaskedSelect")
+    }
+
+    /// Copia para um tensor 1-D os elementos em que `mask` (bool, com broadcasting) é verdadeiro
+    pub fn masked_select(&self, mask: &Tensor) -> Tensor {
+        self.try_masked_select(mask).expect("Error in masked_select")
+    }
+
+    /// Lê o elemento em `index` (uma posição por dimensão; negativos contam a partir do fim).
+    /// Segue os strides, então funciona em views; devolve o valor e não uma referência
+    /// porque outra view pode alterar o mesmo storage no lugar
+    pub fn try_get<T: Element>(&self, index: &[i64]) -> Result<T> {
+        if self.dtype() != T::DTYPE {
+            return Err(CopperError::DTypeMismatch { op: "index", expected: T::DTYPE, found: self.dtype() });
+        }
+        let offset = shape::element_offset(index, self.shape(), &self.try_strides()?)?;
+        let data_ptr = check_call("TensorData", || unsafe { TensorData(self.ptr) })?;
+        if data_ptr.is_null() {
+            return Err(crate::error::backend_error("TensorData"));
+        }
+        Ok(unsafe { *(data_ptr as *const T).offset(offset) })
+    }
+
+    /// Lê o elemento em `index` (uma posição por dimensão; negativos contam a partir do fim)
+    pub fn get<T: Element>(&self, index: &[i64]) -> T {
+        self.try_get(index).expect("Invalid tensor index")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_read_before_an_in_place_op_on_a_view_is_a_copy() {
+        let base = Tensor::from_slice(&[0.0f32, 1.0, 2.0, 3.0], &[2, 2]);
+        let before = base.as_slice();
+        let mut row = base.narrow(0, 0, 1);
+        row += 1.0;
+        assert_eq!(before, vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(base.as_slice(), vec![1.0, 2.0, 2.0, 3.0]);
+        assert_eq!(base.get::<f32>(&[0, 1]), 2.0);
+    }
+}
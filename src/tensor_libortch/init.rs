@@ -0,0 +1,195 @@
+// Initialization
+// Esquemas de inicialização de pesos, como torch.nn.init. Todas as funções alteram o
+// tensor no lugar e sem autograd, então valem para os parâmetros de qualquer módulo:
+// `for (name, mut param) in model.named_parameters() { ... }`.
+
+use crate::error::{check_call, CopperError, Result};
+use crate::shape;
+use crate::tensor_libortch::ffi::{
+    TensorFillInPlace,
+    TensorNormalInPlace,
+    TensorOrthogonalInPlace,
+    TensorTruncNormalInPlace,
+    TensorUniformInPlace,
+};
+use crate::tensor_libortch::tensor::Tensor;
+
+/// Qual fan preserva a variância nas inicializações de Kaiming: `FanIn` no forward,
+/// `FanOut` no backward
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FanMode {
+    #[default]
+    FanIn,
+    FanOut,
+}
+
+/// Não linearidade aplicada depois da camada, usada para escolher o ganho
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Nonlinearity {
+    Linear,
+    Sigmoid,
+    Tanh,
+    ReLU,
+    /// Com a inclinação da parte negativa
+    LeakyReLU(f64),
+    Selu,
+}
+
+/// Ganho recomendado para `nonlinearity`, como `torch.nn.init.calculate_gain`
+pub fn calculate_gain(nonlinearity: Nonlinearity) -> f64 {
+    match nonlinearity {
+        Nonlinearity::Linear | Nonlinearity::Sigmoid => 1.0,
+        Nonlinearity::Tanh => 5.0 / 3.0,
+        Nonlinearity::ReLU => 2f64.sqrt(),
+        Nonlinearity::LeakyReLU(slope) => (2.0 / (1.0 + slope * slope)).sqrt(),
+        Nonlinearity::Selu => 0.75,
+    }
+}
+
+/// `(fan_in, fan_out)` de um peso `[out, in, *kernel]`
+fn fans(tensor: &Tensor, op: &str) -> Result<(f64, f64)> {
+    let dims = tensor.shape();
+    if dims.len() < 2 {
+        return Err(CopperError::InvalidArgument(format!(
+            "{} exige um tensor com ao menos 2 dimensões, recebeu {:?}",
+            op, dims
+        )));
+    }
+    let receptive_field: i64 = dims[2..].iter().product();
+    Ok(((dims[1] * receptive_field) as f64, (dims[0] * receptive_field) as f64))
+}
+
+/// Tensores sem elementos não têm o que inicializar (e teriam fan zero)
+fn is_empty(tensor: &Tensor) -> bool {
+    shape::numel(tensor.shape()) == 0
+}
+
+/// Preenche com amostras de `U(low, high)`
+pub fn try_uniform(tensor: &mut Tensor, low: f64, high: f64) -> Result<()> {
+    check_call("TensorUniformInPlace", || unsafe { TensorUniformInPlace(tensor.ptr, low, high) })
+}
+
+/// Preenche com amostras de `U(low, high)`
+pub fn uniform(tensor: &mut Tensor, low: f64, high: f64) {
+    try_uniform(tensor, low, high).expect("Error in uniform initialization")
+}
+
+/// Preenche com amostras de `N(mean, std²)`
+pub fn try_normal(tensor: &mut Tensor, mean: f64, std: f64) -> Result<()> {
+    check_call("TensorNormalInPlace", || unsafe { TensorNormalInPlace(tensor.ptr, mean, std) })
+}
+
+/// Preenche com amostras de `N(mean, std²)`
+pub fn normal(tensor: &mut Tensor, mean: f64, std: f64) {
+    try_normal(tensor, mean, std).expect("Error in normal initialization")
+}
+
+/// Preenche com `value`
+pub fn try_constant(tensor: &mut Tensor, value: f64) -> Result<()> {
+    check_call("TensorFillInPlace", || unsafe { TensorFillInPlace(tensor.ptr, value) })
+}
+
+/// Preenche com `value`
+pub fn constant(tensor: &mut Tensor, value: f64) {
+    try_constant(tensor, value).expect("Error in constant initialization")
+}
+
+/// Preenche com zeros (ex.: bias)
+pub fn try_zeros(tensor: &mut Tensor) -> Result<()> {
+    try_constant(tensor, 0.0)
+}
+
+/// Preenche com zeros (ex.: bias)
+pub fn zeros(tensor: &mut Tensor) {
+    try_zeros(tensor).expect("Error in zeros initialization")
+}
+
+/// Glorot uniforme: `U(-b, b)` com `b = gain · √(6 / (fan_in + fan_out))`
+pub fn try_xavier_uniform(tensor: &mut Tensor, gain: f64) -> Result<()> {
+    let (fan_in, fan_out) = fans(tensor, "xavier_uniform")?;
+    if is_empty(tensor) {
+        return Ok(());
+    }
+    let bound = gain * (6.0 / (fan_in + fan_out)).sqrt();
+    try_uniform(tensor, -bound, bound)
+}
+
+/// Glorot uniforme: `U(-b, b)` com `b = gain · √(6 / (fan_in + fan_out))`
+pub fn xavier_uniform(tensor: &mut Tensor, gain: f64) {
+    try_xavier_uniform(tensor, gain).expect("Error in xavier_uniform initialization")
+}
+
+/// Glorot normal: `N(0, σ²)` com `σ = gain · √(2 / (fan_in + fan_out))`
+pub fn try_xavier_normal(tensor: &mut Tensor, gain: f64) -> Result<()> {
+    let (fan_in, fan_out) = fans(tensor, "xavier_normal")?;
+    if is_empty(tensor) {
+        return Ok(());
+    }
+    try_normal(tensor, 0.0, gain * (2.0 / (fan_in + fan_out)).sqrt())
+}
+
+/// Glorot normal: `N(0, σ²)` com `σ = gain · √(2 / (fan_in + fan_out))`
+pub fn xavier_normal(tensor: &mut Tensor, gain: f64) {
+    try_xavier_normal(tensor, gain).expect("Error in xavier_normal initialization")
+}
+
+/// Desvio padrão de He: `gain / √fan`
+fn kaiming_std(tensor: &Tensor, mode: FanMode, nonlinearity: Nonlinearity, op: &str) -> Result<f64> {
+    let (fan_in, fan_out) = fans(tensor, op)?;
+    let fan = match mode {
+        FanMode::FanIn => fan_in,
+        FanMode::FanOut => fan_out,
+    };
+    Ok(calculate_gain(nonlinearity) / fan.sqrt())
+}
+
+/// He uniforme: `U(-b, b)` com `b = √3 · gain / √fan`; o padrão do PyTorch é
+/// `FanMode::FanIn` com `Nonlinearity::LeakyReLU(0.0)`
+pub fn try_kaiming_uniform(tensor: &mut Tensor, mode: FanMode, nonlinearity: Nonlinearity) -> Result<()> {
+    let std = kaiming_std(tensor, mode, nonlinearity, "kaiming_uniform")?;
+    if is_empty(tensor) {
+        return Ok(());
+    }
+    let bound = 3f64.sqrt() * std;
+    try_uniform(tensor, -bound, bound)
+}
+
+/// He uniforme: `U(-b, b)` com `b = √3 · gain / √fan`
+pub fn kaiming_uniform(tensor: &mut Tensor, mode: FanMode, nonlinearity: Nonlinearity) {
+    try_kaiming_uniform(tensor, mode, nonlinearity).expect("Error in kaiming_uniform initialization")
+}
+
+/// He normal: `N(0, σ²)` com `σ = gain / √fan`
+pub fn try_kaiming_normal(tensor: &mut Tensor, mode: FanMode, nonlinearity: Nonlinearity) -> Result<()> {
+    let std = kaiming_std(tensor, mode, nonlinearity, "kaiming_normal")?;
+    if is_empty(tensor) {
+        return Ok(());
+    }
+    try_normal(tensor, 0.0, std)
+}
+
+/// He normal: `N(0, σ²)` com `σ = gain / √fan`
+pub fn kaiming_normal(tensor: &mut Tensor, mode: FanMode, nonlinearity: Nonlinearity) {
+    try_kaiming_normal(tensor, mode, nonlinearity).expect("Error in kaiming_normal initialization")
+}
+
+/// Matriz (semi-)ortogonal escalada por `gain`; dimensões depois da primeira são achatadas
+pub fn try_orthogonal(tensor: &mut Tensor, gain: f64) -> Result<()> {
+    fans(tensor, "orthogonal")?;
+    check_call("TensorOrthogonalInPlace", || unsafe { TensorOrthogonalInPlace(tensor.ptr, gain) })
+}
+
+/// Matriz (semi-)ortogonal escalada por `gain`
+pub fn orthogonal(tensor: &mut Tensor, gain: f64) {
+    try_orthogonal(tensor, gain).expect("Error in orthogonal initialization")
+}
+
+/// `N(mean, std²)` truncada ao intervalo `[a, b]`
+pub fn try_trunc_normal(tensor: &mut Tensor, mean: f64, std: f64, a: f64, b: f64) -> Result<()> {
+    check_call("TensorTruncNormalInPlace", || unsafe { TensorTruncNormalInPlace(tensor.ptr, mean, std, a, b) })
+}
+
+/// `N(mean, std²)` truncada ao intervalo `[a, b]`
+pub fn trunc_normal(tensor: &mut Tensor, mean: f64, std: f64, a: f64, b: f64) {
+    try_trunc_normal(tensor, mean, std, a, b).expect("Error in trunc_normal initialization")
+}
@@ -0,0 +1,93 @@
+use crate::dtype::DType;
+use crate::error::Result;
+use crate::shape;
+use crate::tensor_libortch::ffi::{TensorCat, TensorStack};
+use crate::tensor_libortch::tensor::Tensor;
+
+fn shapes<'a>(tensors: &[&'a Tensor]) -> Vec<&'a [i64]> {
+    tensors.iter().map(|t| t.shape()).collect()
+}
+
+fn dtypes(tensors: &[&Tensor]) -> Vec<DType> {
+    tensors.iter().map(|t| t.dtype()).collect()
+}
+
+fn pointers(tensors: &[&Tensor]) -> Vec<*mut libc::c_void> {
+    tensors.iter().map(|t| t.ptr).collect()
+}
+
+/// Concatena os tensores ao longo de `dim`; as demais dimensões devem coincidir
+pub fn try_cat(tensors: &[&Tensor], dim: i64) -> Result<Tensor> {
+    shape::cat_shape(&shapes(tensors), dim)?;
+    shape::check_same_dtype("cat", &dtypes(tensors))?;
+    let ptrs = pointers(tensors);
+    Tensor::from_ptr(unsafe { TensorCat(ptrs.as_ptr(), ptrs.len() as i32, dim) }, "TensorCat")
+}
+
+/// Concatena os tensores ao longo de `dim`; as demais dimensões devem coincidir
+pub fn cat(tensors: &[&Tensor], dim: i64) -> Tensor {
+    try_cat(tensors, dim).expect("Error concatenating tensors")
+}
+
+/// Empilha tensores de mesma forma em uma nova dimensão `dim`
+pub fn try_stack(tensors: &[&Tensor], dim: i64) -> Result<Tensor> {
+    let dim = shape::stack_dim(&shapes(tensors), dim)?;
+    shape::check_same_dtype("stack", &dtypes(tensors))?;
+    let ptrs = pointers(tensors);
+    Tensor::from_ptr(unsafe { TensorStack(ptrs.as_ptr(), ptrs.len() as i32, dim as i64) }, "TensorStack")
+}
+
+/// Empilha tensores de mesma forma em uma nova dimensão `dim`
+pub fn stack(tensors: &[&Tensor], dim: i64) -> Tensor {
+    try_stack(tensors, dim).expect("Error stacking tensors")
+}
+
+/// Divide `tensor` ao longo de `dim` em partes com os tamanhos informados (views)
+pub fn try_split_with_sizes(tensor: &Tensor, sizes: &[i64], dim: i64) -> Result<Vec<Tensor>> {
+    let size = tensor.size(dim)?;
+    shape::check_split_sizes(size, sizes)?;
+    let mut start = 0;
+    sizes
+        .iter()
+        .map(|&length| {
+            let part = tensor.try_narrow(dim, start, length);
+            start += length;
+            part
+        })
+        .collect()
+}
+
+/// Divide `tensor` ao longo de `dim` em partes com os tamanhos informados (views)
+pub fn split_with_sizes(tensor: &Tensor, sizes: &[i64], dim: i64) -> Vec<Tensor> {
+    try_split_with_sizes(tensor, sizes, dim).expect("Error splitting tensor")
+}
+
+/// Divide `tensor` ao longo de `dim` em blocos de `split_size` (o último pode ser menor)
+pub fn try_split(tensor: &Tensor, split_size: i64, dim: i64) -> Result<Vec<Tensor>> {
+    try_split_with_sizes(tensor, &shape::split_sizes(tensor.size(dim)?, split_size)?, dim)
+}
+
+/// Divide `tensor` ao longo de `dim` em blocos de `split_size` (o último pode ser menor)
+pub fn split(tensor: &Tensor, split_size: i64, dim: i64) -> Vec<Tensor> {
+    try_split(tensor, split_size, dim).expect("Error splitting tensor")
+}
+
+/// Divide `tensor` em até `chunks` partes ao longo de `dim`
+pub fn try_chunk(tensor: &Tensor, chunks: i64, dim: i64) -> Result<Vec<Tensor>> {
+    try_split_with_sizes(tensor, &shape::chunk_sizes(tensor.size(dim)?, chunks)?, dim)
+}
+
+/// Divide `tensor` em até `chunks` partes ao longo de `dim`
+pub fn chunk(tensor: &Tensor, chunks: i64, dim: i64) -> Vec<Tensor> {
+    try_chunk(tensor, chunks, dim).expect("Error chunking tensor")
+}
+
+/// Separa `tensor` em uma view por posição de `dim`, removendo essa dimensão
+pub fn try_unbind(tensor: &Tensor, dim: i64) -> Result<Vec<Tensor>> {
+    (0..tensor.size(dim)?).map(|index| tensor.try_select(dim, index)).collect()
+}
+
+/// Separa `tensor` em uma view por posição de `dim`, removendo essa dimensão
+pub fn unbind(tensor: &Tensor, dim: i64) -> Vec<Tensor> {
+    try_unbind(tensor, dim).expect("Error unbinding tensor")
+}
@@ -1,4 +1,10 @@
-// Organizes the tensor module into submodules: tensor, operators, and ffi.
+// Organizes the tensor module into submodules: tensor, operators, indexing, join, autograd, nn, init, optim, and ffi.
 pub mod tensor;
 pub mod operators;
+pub mod indexing;
+pub mod join;
+pub mod autograd;
+pub mod nn;
+pub mod init;
+pub mod optim;
 pub mod ffi;
\ No newline at end of file
@@ -0,0 +1,203 @@
+// Convolution
+// Camadas Conv1d/2d/3d e ConvTranspose1d/2d/3d do torch::nn e as formas funcionais.
+// O número de dimensões espaciais `D` vem do tamanho dos arrays de kernel, stride etc.
+
+use super::check_spatial_dims;
+use crate::error::{check_ptr, Result};
+use crate::tensor_libortch::ffi::{ConvForward, CreateConv, TensorConv};
+use crate::tensor_libortch::tensor::Tensor;
+use libc::c_int;
+use std::ptr;
+
+/// Hiperparâmetros de uma convolução com `D` dimensões espaciais
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConvOptions<const D: usize> {
+    pub stride: [i64; D],
+    pub padding: [i64; D],
+    /// Só usado pelas convoluções transpostas
+    pub output_padding: [i64; D],
+    pub dilation: [i64; D],
+    pub groups: i64,
+    pub bias: bool,
+}
+
+impl<const D: usize> Default for ConvOptions<D> {
+    fn default() -> Self {
+        ConvOptions {
+            stride: [1; D],
+            padding: [0; D],
+            output_padding: [0; D],
+            dilation: [1; D],
+            groups: 1,
+            bias: true,
+        }
+    }
+}
+
+impl<const D: usize> ConvOptions<D> {
+    pub fn stride(mut self, stride: [i64; D]) -> Self {
+        self.stride = stride;
+        self
+    }
+
+    pub fn padding(mut self, padding: [i64; D]) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn output_padding(mut self, output_padding: [i64; D]) -> Self {
+        self.output_padding = output_padding;
+        self
+    }
+
+    pub fn dilation(mut self, dilation: [i64; D]) -> Self {
+        self.dilation = dilation;
+        self
+    }
+
+    pub fn groups(mut self, groups: i64) -> Self {
+        self.groups = groups;
+        self
+    }
+
+    pub fn bias(mut self, bias: bool) -> Self {
+        self.bias = bias;
+        self
+    }
+}
+
+fn try_create_conv<const D: usize>(
+    transposed: bool,
+    in_channels: i64,
+    out_channels: i64,
+    kernel_size: [i64; D],
+    options: &ConvOptions<D>,
+) -> Result<*mut libc::c_void> {
+    check_spatial_dims::<D>("conv")?;
+    check_ptr(
+        unsafe {
+            CreateConv(
+                D as c_int,
+                transposed,
+                in_channels,
+                out_channels,
+                kernel_size.as_ptr(),
+                options.stride.as_ptr(),
+                options.padding.as_ptr(),
+                options.output_padding.as_ptr(),
+                options.dilation.as_ptr(),
+                options.groups,
+                options.bias,
+            )
+        },
+        "CreateConv",
+    )
+}
+
+/// Convolução com `D` dimensões espaciais; entrada `[N, C_in, *spatial]` ou sem o batch
+pub struct Conv<const D: usize> {
+    pub ptr: *mut libc::c_void,
+    pub in_channels: i64,
+    pub out_channels: i64,
+    pub kernel_size: [i64; D],
+    pub options: ConvOptions<D>,
+}
+
+pub type Conv1d = Conv<1>;
+pub type Conv2d = Conv<2>;
+pub type Conv3d = Conv<3>;
+
+impl<const D: usize> Conv<D> {
+    pub fn try_new(in_channels: i64, out_channels: i64, kernel_size: [i64; D], options: ConvOptions<D>) -> Result<Self> {
+        let ptr = try_create_conv(false, in_channels, out_channels, kernel_size, &options)?;
+        Ok(Conv { ptr, in_channels, out_channels, kernel_size, options })
+    }
+
+    pub fn new(in_channels: i64, out_channels: i64, kernel_size: [i64; D], options: ConvOptions<D>) -> Self {
+        Conv::try_new(in_channels, out_channels, kernel_size, options).expect("Error creating Conv layer")
+    }
+}
+
+native_module!(impl[const D: usize] Conv<D>, ConvForward);
+
+/// Convolução transposta com `D` dimensões espaciais (usa `options.output_padding`)
+pub struct ConvTranspose<const D: usize> {
+    pub ptr: *mut libc::c_void,
+    pub in_channels: i64,
+    pub out_channels: i64,
+    pub kernel_size: [i64; D],
+    pub options: ConvOptions<D>,
+}
+
+pub type ConvTranspose1d = ConvTranspose<1>;
+pub type ConvTranspose2d = ConvTranspose<2>;
+pub type ConvTranspose3d = ConvTranspose<3>;
+
+impl<const D: usize> ConvTranspose<D> {
+    pub fn try_new(in_channels: i64, out_channels: i64, kernel_size: [i64; D], options: ConvOptions<D>) -> Result<Self> {
+        let ptr = try_create_conv(true, in_channels, out_channels, kernel_size, &options)?;
+        Ok(ConvTranspose { ptr, in_channels, out_channels, kernel_size, options })
+    }
+
+    pub fn new(in_channels: i64, out_channels: i64, kernel_size: [i64; D], options: ConvOptions<D>) -> Self {
+        ConvTranspose::try_new(in_channels, out_channels, kernel_size, options)
+            .expect("Error creating ConvTranspose layer")
+    }
+}
+
+native_module!(impl[const D: usize] ConvTranspose<D>, ConvForward);
+
+impl Tensor {
+    fn try_convolution<const D: usize>(
+        &self,
+        weight: &Tensor,
+        bias: Option<&Tensor>,
+        options: &ConvOptions<D>,
+        transposed: bool,
+    ) -> Result<Tensor> {
+        check_spatial_dims::<D>(if transposed { "conv_transpose" } else { "conv" })?;
+        Tensor::from_ptr(
+            unsafe {
+                TensorConv(
+                    self.ptr,
+                    weight.ptr,
+                    bias.map_or(ptr::null_mut(), |b| b.ptr),
+                    options.stride.as_ptr(),
+                    options.padding.as_ptr(),
+                    options.output_padding.as_ptr(),
+                    options.dilation.as_ptr(),
+                    D as c_int,
+                    transposed,
+                    options.groups,
+                )
+            },
+            "TensorConv",
+        )
+    }
+
+    /// Convolução funcional (conv1d/conv2d/conv3d conforme `D`) com pesos
+    /// `[C_out, C_in / groups, *kernel]`; `options.bias` é ignorado em favor de `bias`
+    pub fn try_conv<const D: usize>(&self, weight: &Tensor, bias: Option<&Tensor>, options: ConvOptions<D>) -> Result<Tensor> {
+        self.try_convolution(weight, bias, &options, false)
+    }
+
+    /// Convolução funcional (conv1d/conv2d/conv3d conforme `D`)
+    pub fn conv<const D: usize>(&self, weight: &Tensor, bias: Option<&Tensor>, options: ConvOptions<D>) -> Tensor {
+        self.try_conv(weight, bias, options).expect("Error in convolution")
+    }
+
+    /// Convolução transposta funcional com pesos `[C_in, C_out / groups, *kernel]`
+    pub fn try_conv_transpose<const D: usize>(
+        &self,
+        weight: &Tensor,
+        bias: Option<&Tensor>,
+        options: ConvOptions<D>,
+    ) -> Result<Tensor> {
+        self.try_convolution(weight, bias, &options, true)
+    }
+
+    /// Convolução transposta funcional com pesos `[C_in, C_out / groups, *kernel]`
+    pub fn conv_transpose<const D: usize>(&self, weight: &Tensor, bias: Option<&Tensor>, options: ConvOptions<D>) -> Tensor {
+        self.try_conv_transpose(weight, bias, options).expect("Error in transposed convolution")
+    }
+}
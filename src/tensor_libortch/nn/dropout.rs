@@ -0,0 +1,133 @@
+// Dropout
+// Dropout por elemento, por canal (Dropout2d) e o AlphaDropout das redes SELU, como
+// formas funcionais em Tensor e como módulos que só sorteiam a máscara em modo de treino
+
+use super::Module;
+use crate::error::{CopperError, Result};
+use crate::tensor_libortch::ffi::TensorDropout;
+use crate::tensor_libortch::tensor::Tensor;
+use libc::c_int;
+
+/// Variantes de dropout; o código é o `kind` do TensorDropout no shim
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DropoutKind {
+    /// Cada elemento é zerado de forma independente
+    Element,
+    /// Canais inteiros (as duas últimas dimensões) são zerados juntos
+    Feature,
+    /// Substitui os elementos pelo valor de saturação da SELU, preservando média e variância
+    Alpha,
+}
+
+impl DropoutKind {
+    fn code(self) -> c_int {
+        match self {
+            DropoutKind::Element => 0,
+            DropoutKind::Feature => 1,
+            DropoutKind::Alpha => 2,
+        }
+    }
+}
+
+/// Garante que a probabilidade de descarte esteja em `[0, 1]`
+pub(crate) fn check_dropout_p(p: f64, op: &str) -> Result<()> {
+    if (0.0..=1.0).contains(&p) {
+        Ok(())
+    } else {
+        Err(CopperError::InvalidArgument(format!(
+            "{}: a probabilidade deve estar em [0, 1], recebeu {}",
+            op, p
+        )))
+    }
+}
+
+impl Tensor {
+    fn try_dropout_kind(&self, p: f64, train: bool, kind: DropoutKind, op: &str) -> Result<Tensor> {
+        check_dropout_p(p, op)?;
+        Tensor::from_ptr(unsafe { TensorDropout(self.ptr, p, train, kind.code()) }, "TensorDropout")
+    }
+
+    /// Zera cada elemento com probabilidade `p` e escala os demais por `1 / (1 - p)`;
+    /// com `train = false` devolve a entrada inalterada
+    pub fn try_dropout(&self, p: f64, train: bool) -> Result<Tensor> {
+        self.try_dropout_kind(p, train, DropoutKind::Element, "dropout")
+    }
+
+    /// Zera cada elemento com probabilidade `p` e escala os demais por `1 / (1 - p)`
+    pub fn dropout(&self, p: f64, train: bool) -> Tensor {
+        self.try_dropout(p, train).expect("Error in dropout")
+    }
+
+    /// Zera canais inteiros de uma entrada `[N, C, H, W]` ou `[C, H, W]` com probabilidade `p`
+    pub fn try_dropout2d(&self, p: f64, train: bool) -> Result<Tensor> {
+        self.try_dropout_kind(p, train, DropoutKind::Feature, "dropout2d")
+    }
+
+    /// Zera canais inteiros de uma entrada `[N, C, H, W]` ou `[C, H, W]` com probabilidade `p`
+    pub fn dropout2d(&self, p: f64, train: bool) -> Tensor {
+        self.try_dropout2d(p, train).expect("Error in dropout2d")
+    }
+
+    /// Dropout para ativações SELU: mantém média zero e variância unitária
+    pub fn try_alpha_dropout(&self, p: f64, train: bool) -> Result<Tensor> {
+        self.try_dropout_kind(p, train, DropoutKind::Alpha, "alpha_dropout")
+    }
+
+    /// Dropout para ativações SELU: mantém média zero e variância unitária
+    pub fn alpha_dropout(&self, p: f64, train: bool) -> Tensor {
+        self.try_alpha_dropout(p, train).expect("Error in alpha dropout")
+    }
+}
+
+/// Implementa `Module` para uma camada de dropout com campos `p` e `training`
+macro_rules! dropout_module {
+    ($ty:ident, $method:ident) => {
+        impl $ty {
+            /// Cria a camada em modo de treino
+            pub fn new(p: f64) -> Self {
+                $ty { p, training: true }
+            }
+        }
+
+        impl Module for $ty {
+            fn try_forward(&self, input: &Tensor) -> Result<Tensor> {
+                input.$method(self.p, self.training)
+            }
+
+            fn train(&mut self, mode: bool) {
+                self.training = mode;
+            }
+
+            fn is_training(&self) -> bool {
+                self.training
+            }
+        }
+    };
+}
+
+/// Camada de dropout por elemento; em avaliação é a identidade
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dropout {
+    pub p: f64,
+    training: bool,
+}
+
+dropout_module!(Dropout, try_dropout);
+
+/// Camada que descarta canais inteiros de mapas de características 2-D
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dropout2d {
+    pub p: f64,
+    training: bool,
+}
+
+dropout_module!(Dropout2d, try_dropout2d);
+
+/// Camada de dropout para redes auto-normalizadas (SELU)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlphaDropout {
+    pub p: f64,
+    training: bool,
+}
+
+dropout_module!(AlphaDropout, try_alpha_dropout);
@@ -0,0 +1,202 @@
+// Embeddings
+// Tabelas de lookup sobre ids int64 (Embedding) e a redução por bolsa de ids
+// (EmbeddingBag), criadas do zero ou a partir de uma matriz de pesos pré-treinada
+
+use crate::error::{check_ptr, Result};
+use crate::tensor_libortch::ffi::{CreateEmbedding, CreateEmbeddingBag, EmbeddingBagForward, EmbeddingForward};
+use crate::tensor_libortch::tensor::Tensor;
+use libc::c_int;
+use std::ptr;
+
+/// Opções comuns a `Embedding` e `EmbeddingBag`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EmbeddingOptions {
+    /// Linha que não recebe gradiente (e começa zerada quando os pesos não são pré-treinados)
+    pub padding_idx: Option<i64>,
+    /// Linhas consultadas com norma acima deste valor são renormalizadas no lugar
+    pub max_norm: Option<f64>,
+}
+
+impl EmbeddingOptions {
+    pub fn padding_idx(mut self, padding_idx: i64) -> Self {
+        self.padding_idx = Some(padding_idx);
+        self
+    }
+
+    pub fn max_norm(mut self, max_norm: f64) -> Self {
+        self.max_norm = Some(max_norm);
+        self
+    }
+}
+
+/// Ponteiros opcionais no formato esperado pelo shim (nullptr = ausente)
+fn option_ptr<T>(value: &Option<T>) -> *const T {
+    value.as_ref().map_or(ptr::null(), |v| v as *const T)
+}
+
+/// Tabela de lookup: cada id da entrada vira uma linha de `[num_embeddings, embedding_dim]`
+pub struct Embedding {
+    pub ptr: *mut libc::c_void,
+    pub num_embeddings: i64,
+    pub embedding_dim: i64,
+    pub options: EmbeddingOptions,
+}
+
+impl Embedding {
+    pub fn try_new(num_embeddings: i64, embedding_dim: i64, options: EmbeddingOptions) -> Result<Self> {
+        let ptr = check_ptr(
+            unsafe {
+                CreateEmbedding(
+                    num_embeddings,
+                    embedding_dim,
+                    option_ptr(&options.padding_idx),
+                    option_ptr(&options.max_norm),
+                    ptr::null_mut(),
+                    false,
+                )
+            },
+            "CreateEmbedding",
+        )?;
+        Ok(Embedding { ptr, num_embeddings, embedding_dim, options })
+    }
+
+    pub fn new(num_embeddings: i64, embedding_dim: i64, options: EmbeddingOptions) -> Self {
+        Embedding::try_new(num_embeddings, embedding_dim, options).expect("Error creating Embedding layer")
+    }
+
+    /// Usa `weight` (2-D, compartilhado) como tabela; com `freeze` ela não é treinada
+    pub fn try_from_pretrained(weight: &Tensor, freeze: bool, options: EmbeddingOptions) -> Result<Self> {
+        let ptr = check_ptr(
+            unsafe {
+                CreateEmbedding(
+                    0,
+                    0,
+                    option_ptr(&options.padding_idx),
+                    option_ptr(&options.max_norm),
+                    weight.ptr,
+                    freeze,
+                )
+            },
+            "CreateEmbedding",
+        )?;
+        let (num_embeddings, embedding_dim) = (weight.shape()[0], weight.shape()[1]);
+        Ok(Embedding { ptr, num_embeddings, embedding_dim, options })
+    }
+
+    /// Usa `weight` (2-D, compartilhado) como tabela; com `freeze` ela não é treinada
+    pub fn from_pretrained(weight: &Tensor, freeze: bool, options: EmbeddingOptions) -> Self {
+        Embedding::try_from_pretrained(weight, freeze, options).expect("Error creating Embedding layer")
+    }
+}
+
+native_module!(impl[] Embedding, EmbeddingForward);
+
+/// Redução aplicada a cada bolsa de ids do `EmbeddingBag`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmbeddingBagMode {
+    Sum,
+    #[default]
+    Mean,
+    Max,
+}
+
+impl EmbeddingBagMode {
+    fn code(self) -> c_int {
+        match self {
+            EmbeddingBagMode::Sum => 0,
+            EmbeddingBagMode::Mean => 1,
+            EmbeddingBagMode::Max => 2,
+        }
+    }
+}
+
+/// Soma, média ou máximo das linhas de cada bolsa de ids, sem materializar os embeddings
+pub struct EmbeddingBag {
+    pub ptr: *mut libc::c_void,
+    pub num_embeddings: i64,
+    pub embedding_dim: i64,
+    pub mode: EmbeddingBagMode,
+    pub options: EmbeddingOptions,
+}
+
+impl EmbeddingBag {
+    fn try_create(
+        num_embeddings: i64,
+        embedding_dim: i64,
+        mode: EmbeddingBagMode,
+        options: &EmbeddingOptions,
+        weight: Option<&Tensor>,
+        freeze: bool,
+    ) -> Result<*mut libc::c_void> {
+        check_ptr(
+            unsafe {
+                CreateEmbeddingBag(
+                    num_embeddings,
+                    embedding_dim,
+                    mode.code(),
+                    option_ptr(&options.padding_idx),
+                    option_ptr(&options.max_norm),
+                    weight.map_or(ptr::null_mut(), |w| w.ptr),
+                    freeze,
+                )
+            },
+            "CreateEmbeddingBag",
+        )
+    }
+
+    pub fn try_new(num_embeddings: i64, embedding_dim: i64, mode: EmbeddingBagMode, options: EmbeddingOptions) -> Result<Self> {
+        let ptr = EmbeddingBag::try_create(num_embeddings, embedding_dim, mode, &options, None, false)?;
+        Ok(EmbeddingBag { ptr, num_embeddings, embedding_dim, mode, options })
+    }
+
+    pub fn new(num_embeddings: i64, embedding_dim: i64, mode: EmbeddingBagMode, options: EmbeddingOptions) -> Self {
+        EmbeddingBag::try_new(num_embeddings, embedding_dim, mode, options).expect("Error creating EmbeddingBag layer")
+    }
+
+    /// Usa `weight` (2-D, compartilhado) como tabela; com `freeze` ela não é treinada
+    pub fn try_from_pretrained(
+        weight: &Tensor,
+        freeze: bool,
+        mode: EmbeddingBagMode,
+        options: EmbeddingOptions,
+    ) -> Result<Self> {
+        let ptr = EmbeddingBag::try_create(0, 0, mode, &options, Some(weight), freeze)?;
+        let (num_embeddings, embedding_dim) = (weight.shape()[0], weight.shape()[1]);
+        Ok(EmbeddingBag { ptr, num_embeddings, embedding_dim, mode, options })
+    }
+
+    /// Usa `weight` (2-D, compartilhado) como tabela; com `freeze` ela não é treinada
+    pub fn from_pretrained(weight: &Tensor, freeze: bool, mode: EmbeddingBagMode, options: EmbeddingOptions) -> Self {
+        EmbeddingBag::try_from_pretrained(weight, freeze, mode, options).expect("Error creating EmbeddingBag layer")
+    }
+
+    /// Com entrada 1-D, `offsets` marca o início de cada bolsa; com entrada 2-D cada linha
+    /// é uma bolsa. `per_sample_weights` (só no modo `Sum`) pondera cada id.
+    pub fn try_forward_with_offsets(
+        &self,
+        input: &Tensor,
+        offsets: Option<&Tensor>,
+        per_sample_weights: Option<&Tensor>,
+    ) -> Result<Tensor> {
+        Tensor::from_ptr(
+            unsafe {
+                EmbeddingBagForward(
+                    self.ptr,
+                    input.ptr,
+                    offsets.map_or(ptr::null_mut(), |t| t.ptr),
+                    per_sample_weights.map_or(ptr::null_mut(), |t| t.ptr),
+                )
+            },
+            "EmbeddingBagForward",
+        )
+    }
+
+    /// Com entrada 1-D, `offsets` marca o início de cada bolsa; com entrada 2-D cada linha
+    /// é uma bolsa
+    pub fn forward_with_offsets(&self, input: &Tensor, offsets: Option<&Tensor>, per_sample_weights: Option<&Tensor>) -> Tensor {
+        self.try_forward_with_offsets(input, offsets, per_sample_weights)
+            .expect("Error in EmbeddingBag forward")
+    }
+}
+
+native_module!(impl[] EmbeddingBag, |bag, input| bag.try_forward_with_offsets(input, None, None));
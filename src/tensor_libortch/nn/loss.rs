@@ -0,0 +1,359 @@
+// Loss Functions
+// Funções de perda do torch::nn::functional como métodos de Tensor, onde `self` é a
+// predição. Todas recebem uma `Reduction`; as de classificação aceitam pesos por classe
+// e um índice de alvo a ignorar.
+
+use crate::error::{CopperError, Result};
+use crate::tensor_libortch::ffi::{
+    BCELoss,
+    BCEWithLogitsLoss,
+    CTCLoss,
+    CosineEmbeddingLoss,
+    CrossEntropyLoss,
+    HuberLoss,
+    KLDivLoss,
+    L1Loss,
+    MSELoss,
+    MarginRankingLoss,
+    NLLLoss,
+    SmoothL1Loss,
+    TripletMarginLoss,
+};
+use crate::tensor_libortch::tensor::Tensor;
+use libc::{c_int, c_void};
+use std::ptr;
+
+/// Como as perdas de cada elemento são combinadas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Reduction {
+    /// Devolve a perda de cada elemento (ou amostra), sem reduzir
+    None,
+    /// Média; com pesos de classe, ponderada pelos pesos dos alvos
+    #[default]
+    Mean,
+    /// Soma
+    Sum,
+}
+
+impl Reduction {
+    /// Código do `reduction` no shim (os mesmos de at::Reduction)
+    pub(crate) fn code(self) -> c_int {
+        match self {
+            Reduction::None => 0,
+            Reduction::Mean => 1,
+            Reduction::Sum => 2,
+        }
+    }
+}
+
+/// `ignore_index` padrão do PyTorch, que nunca é uma classe válida
+const NO_IGNORE_INDEX: i64 = -100;
+
+fn optional_ptr(tensor: Option<&Tensor>) -> *mut c_void {
+    tensor.map_or(ptr::null_mut(), |t| t.ptr)
+}
+
+impl Tensor {
+    /// Erro quadrático médio (ou somado, ou por elemento) entre `self` e `target`
+    pub fn try_mse_loss_with(&self, target: &Tensor, reduction: Reduction) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { MSELoss(self.ptr, target.ptr, reduction.code()) }, "MSELoss")
+    }
+
+    /// Erro quadrático entre `self` e `target`, com a redução escolhida
+    pub fn mse_loss_with(&self, target: &Tensor, reduction: Reduction) -> Tensor {
+        self.try_mse_loss_with(target, reduction).expect("Error calculating MSELoss")
+    }
+
+    /// Cross entropy sobre logits `[N, C, *]` e alvos inteiros `[N, *]`. `weight` tem um
+    /// peso por classe; alvos iguais a `ignore_index` não contam
+    pub fn try_cross_entropy_loss_with(
+        &self,
+        target: &Tensor,
+        weight: Option<&Tensor>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Result<Tensor> {
+        Tensor::from_ptr(
+            unsafe {
+                CrossEntropyLoss(
+                    self.ptr,
+                    target.ptr,
+                    optional_ptr(weight),
+                    reduction.code(),
+                    ignore_index.unwrap_or(NO_IGNORE_INDEX),
+                )
+            },
+            "CrossEntropyLoss",
+        )
+    }
+
+    /// Cross entropy com pesos por classe, `ignore_index` e a redução escolhida
+    pub fn cross_entropy_loss_with(
+        &self,
+        target: &Tensor,
+        weight: Option<&Tensor>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Tensor {
+        self.try_cross_entropy_loss_with(target, weight, ignore_index, reduction)
+            .expect("Error calculating CrossEntropyLoss")
+    }
+
+    /// Erro absoluto |self - target|
+    pub fn try_l1_loss(&self, target: &Tensor, reduction: Reduction) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { L1Loss(self.ptr, target.ptr, reduction.code()) }, "L1Loss")
+    }
+
+    /// Erro absoluto |self - target|
+    pub fn l1_loss(&self, target: &Tensor, reduction: Reduction) -> Tensor {
+        self.try_l1_loss(target, reduction).expect("Error calculating L1Loss")
+    }
+
+    /// Quadrática (dividida por `beta`) para erros menores que `beta`, linear acima
+    pub fn try_smooth_l1_loss(&self, target: &Tensor, beta: f64, reduction: Reduction) -> Result<Tensor> {
+        if beta < 0.0 {
+            return Err(CopperError::InvalidArgument(format!(
+                "smooth_l1_loss: beta não pode ser negativo, recebeu {}",
+                beta
+            )));
+        }
+        Tensor::from_ptr(unsafe { SmoothL1Loss(self.ptr, target.ptr, reduction.code(), beta) }, "SmoothL1Loss")
+    }
+
+    /// Quadrática (dividida por `beta`) para erros menores que `beta`, linear acima
+    pub fn smooth_l1_loss(&self, target: &Tensor, beta: f64, reduction: Reduction) -> Tensor {
+        self.try_smooth_l1_loss(target, beta, reduction).expect("Error calculating SmoothL1Loss")
+    }
+
+    /// Huber: quadrática para erros menores que `delta`, linear com inclinação `delta` acima
+    /// (igual a `smooth_l1_loss` multiplicada por `beta = delta`)
+    pub fn try_huber_loss(&self, target: &Tensor, delta: f64, reduction: Reduction) -> Result<Tensor> {
+        if delta <= 0.0 {
+            return Err(CopperError::InvalidArgument(format!(
+                "huber_loss: delta deve ser positivo, recebeu {}",
+                delta
+            )));
+        }
+        Tensor::from_ptr(unsafe { HuberLoss(self.ptr, target.ptr, reduction.code(), delta) }, "HuberLoss")
+    }
+
+    /// Huber: quadrática para erros menores que `delta`, linear com inclinação `delta` acima
+    pub fn huber_loss(&self, target: &Tensor, delta: f64, reduction: Reduction) -> Tensor {
+        self.try_huber_loss(target, delta, reduction).expect("Error calculating HuberLoss")
+    }
+
+    /// Binary cross entropy sobre probabilidades em `[0, 1]`; `weight` pondera cada elemento
+    pub fn try_bce_loss(&self, target: &Tensor, weight: Option<&Tensor>, reduction: Reduction) -> Result<Tensor> {
+        Tensor::from_ptr(
+            unsafe { BCELoss(self.ptr, target.ptr, optional_ptr(weight), reduction.code()) },
+            "BCELoss",
+        )
+    }
+
+    /// Binary cross entropy sobre probabilidades em `[0, 1]`
+    pub fn bce_loss(&self, target: &Tensor, weight: Option<&Tensor>, reduction: Reduction) -> Tensor {
+        self.try_bce_loss(target, weight, reduction).expect("Error calculating BCELoss")
+    }
+
+    /// Sigmoid seguida de binary cross entropy, numericamente estável. `pos_weight` (um
+    /// valor por classe) pesa os exemplos positivos, útil com classes desbalanceadas
+    pub fn try_bce_with_logits_loss(
+        &self,
+        target: &Tensor,
+        weight: Option<&Tensor>,
+        pos_weight: Option<&Tensor>,
+        reduction: Reduction,
+    ) -> Result<Tensor> {
+        Tensor::from_ptr(
+            unsafe {
+                BCEWithLogitsLoss(self.ptr, target.ptr, optional_ptr(weight), optional_ptr(pos_weight), reduction.code())
+            },
+            "BCEWithLogitsLoss",
+        )
+    }
+
+    /// Sigmoid seguida de binary cross entropy, numericamente estável
+    pub fn bce_with_logits_loss(
+        &self,
+        target: &Tensor,
+        weight: Option<&Tensor>,
+        pos_weight: Option<&Tensor>,
+        reduction: Reduction,
+    ) -> Tensor {
+        self.try_bce_with_logits_loss(target, weight, pos_weight, reduction)
+            .expect("Error calculating BCEWithLogitsLoss")
+    }
+
+    /// Negative log likelihood sobre log-probabilidades `[N, C, *]` e alvos inteiros `[N, *]`
+    /// (normalmente depois de `log_softmax(1)`)
+    pub fn try_nll_loss(
+        &self,
+        target: &Tensor,
+        weight: Option<&Tensor>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Result<Tensor> {
+        Tensor::from_ptr(
+            unsafe {
+                NLLLoss(
+                    self.ptr,
+                    target.ptr,
+                    optional_ptr(weight),
+                    reduction.code(),
+                    ignore_index.unwrap_or(NO_IGNORE_INDEX),
+                )
+            },
+            "NLLLoss",
+        )
+    }
+
+    /// Negative log likelihood sobre log-probabilidades `[N, C, *]` e alvos inteiros `[N, *]`
+    pub fn nll_loss(
+        &self,
+        target: &Tensor,
+        weight: Option<&Tensor>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Tensor {
+        self.try_nll_loss(target, weight, ignore_index, reduction).expect("Error calculating NLLLoss")
+    }
+
+    /// Divergência KL de `target` para a distribuição de `self`, que deve estar em
+    /// log-probabilidades (`target` também, com `log_target`). `Reduction::Mean` divide
+    /// por todos os elementos; o `batchmean` do PyTorch é `Sum` dividido pelo batch
+    pub fn try_kl_div_loss(&self, target: &Tensor, log_target: bool, reduction: Reduction) -> Result<Tensor> {
+        Tensor::from_ptr(
+            unsafe { KLDivLoss(self.ptr, target.ptr, reduction.code(), log_target) },
+            "KLDivLoss",
+        )
+    }
+
+    /// Divergência KL de `target` para a distribuição de `self` (em log-probabilidades)
+    pub fn kl_div_loss(&self, target: &Tensor, log_target: bool, reduction: Reduction) -> Tensor {
+        self.try_kl_div_loss(target, log_target, reduction).expect("Error calculating KLDivLoss")
+    }
+
+    /// `1 - cos(self, other)` para pares com `target = 1` e `max(0, cos - margin)` para
+    /// pares com `target = -1`; a similaridade é calculada na última dimensão
+    pub fn try_cosine_embedding_loss(
+        &self,
+        other: &Tensor,
+        target: &Tensor,
+        margin: f64,
+        reduction: Reduction,
+    ) -> Result<Tensor> {
+        Tensor::from_ptr(
+            unsafe { CosineEmbeddingLoss(self.ptr, other.ptr, target.ptr, margin, reduction.code()) },
+            "CosineEmbeddingLoss",
+        )
+    }
+
+    /// Perda de similaridade de cosseno entre pares rotulados com 1 ou -1
+    pub fn cosine_embedding_loss(&self, other: &Tensor, target: &Tensor, margin: f64, reduction: Reduction) -> Tensor {
+        self.try_cosine_embedding_loss(other, target, margin, reduction)
+            .expect("Error calculating CosineEmbeddingLoss")
+    }
+
+    /// `max(0, -target · (self - other) + margin)`: com `target = 1` `self` deve ficar
+    /// acima de `other`, com `target = -1` abaixo
+    pub fn try_margin_ranking_loss(
+        &self,
+        other: &Tensor,
+        target: &Tensor,
+        margin: f64,
+        reduction: Reduction,
+    ) -> Result<Tensor> {
+        Tensor::from_ptr(
+            unsafe { MarginRankingLoss(self.ptr, other.ptr, target.ptr, margin, reduction.code()) },
+            "MarginRankingLoss",
+        )
+    }
+
+    /// `max(0, -target · (self - other) + margin)`
+    pub fn margin_ranking_loss(&self, other: &Tensor, target: &Tensor, margin: f64, reduction: Reduction) -> Tensor {
+        self.try_margin_ranking_loss(other, target, margin, reduction)
+            .expect("Error calculating MarginRankingLoss")
+    }
+
+    /// `max(0, d(self, positive) - d(self, negative) + margin)`, com `self` como âncora e
+    /// `d` a distância de norma `p` na última dimensão
+    pub fn try_triplet_margin_loss(
+        &self,
+        positive: &Tensor,
+        negative: &Tensor,
+        margin: f64,
+        p: f64,
+        reduction: Reduction,
+    ) -> Result<Tensor> {
+        if p <= 0.0 {
+            return Err(CopperError::InvalidArgument(format!(
+                "triplet_margin_loss: p deve ser positivo, recebeu {}",
+                p
+            )));
+        }
+        Tensor::from_ptr(
+            unsafe { TripletMarginLoss(self.ptr, positive.ptr, negative.ptr, margin, p, 1e-6, false, reduction.code()) },
+            "TripletMarginLoss",
+        )
+    }
+
+    /// `max(0, d(self, positive) - d(self, negative) + margin)`, com `self` como âncora
+    pub fn triplet_margin_loss(&self, positive: &Tensor, negative: &Tensor, margin: f64, p: f64, reduction: Reduction) -> Tensor {
+        self.try_triplet_margin_loss(positive, negative, margin, p, reduction)
+            .expect("Error calculating TripletMarginLoss")
+    }
+
+    /// Connectionist Temporal Classification sobre log-probabilidades `[T, N, C]`.
+    /// `targets` é `[N, S]` (com padding) ou 1-D com os alvos concatenados; os tamanhos
+    /// têm um valor por amostra. Na média, cada perda é dividida pelo tamanho do seu alvo
+    pub fn try_ctc_loss(
+        &self,
+        targets: &Tensor,
+        input_lengths: &[i64],
+        target_lengths: &[i64],
+        blank: i64,
+        reduction: Reduction,
+    ) -> Result<Tensor> {
+        check_ctc_lengths(input_lengths, target_lengths)?;
+        Tensor::from_ptr(
+            unsafe {
+                CTCLoss(
+                    self.ptr,
+                    targets.ptr,
+                    input_lengths.as_ptr(),
+                    target_lengths.as_ptr(),
+                    input_lengths.len() as c_int,
+                    blank,
+                    reduction.code(),
+                )
+            },
+            "CTCLoss",
+        )
+    }
+
+    /// Connectionist Temporal Classification sobre log-probabilidades `[T, N, C]`
+    pub fn ctc_loss(
+        &self,
+        targets: &Tensor,
+        input_lengths: &[i64],
+        target_lengths: &[i64],
+        blank: i64,
+        reduction: Reduction,
+    ) -> Tensor {
+        self.try_ctc_loss(targets, input_lengths, target_lengths, blank, reduction)
+            .expect("Error calculating CTCLoss")
+    }
+}
+
+/// `input_lengths` e `target_lengths` precisam de um valor por amostra do batch
+pub(crate) fn check_ctc_lengths(input_lengths: &[i64], target_lengths: &[i64]) -> Result<()> {
+    if input_lengths.len() == target_lengths.len() {
+        Ok(())
+    } else {
+        Err(CopperError::InvalidArgument(format!(
+            "ctc_loss: {} tamanhos de entrada para {} tamanhos de alvo",
+            input_lengths.len(),
+            target_lengths.len()
+        )))
+    }
+}
@@ -0,0 +1,448 @@
+// Neural Network Modules
+// Trait Module, o container Sequential e as camadas do torch::nn expostas ao Rust
+
+use crate::error::{backend_error, check_call, check_ptr, CopperError, Result};
+use crate::tensor_libortch::autograd::TensorList;
+use crate::tensor_libortch::ffi::{
+    ModuleParameters,
+    ModuleParameterName,
+    ModuleBuffers,
+    ModuleBufferName,
+    SaveTensors,
+    LoadTensors,
+    ModuleTrain,
+    ModuleIsTraining,
+    CreateLinear,
+    LinearForward,
+};
+use crate::tensor_libortch::tensor::Tensor;
+use libc::{c_char, c_int, c_void};
+use std::ffi::{CStr, CString};
+
+/// Bloco de uma rede neural, como `torch::nn::Module`
+///
+/// Só `try_forward` é obrigatório. Containers declaram seus submódulos em `children` e
+/// `children_mut`; camadas com pesos próprios os devolvem em `try_local_parameters` e
+/// o estado que não é treinado (ex.: médias móveis da BatchNorm) em `try_local_buffers`.
+pub trait Module {
+    /// Aplica o módulo a `input`
+    fn try_forward(&self, input: &Tensor) -> Result<Tensor>;
+
+    /// Aplica o módulo a `input`
+    fn forward(&self, input: &Tensor) -> Tensor {
+        self.try_forward(input).expect("Error in module forward")
+    }
+
+    /// Submódulos diretos, com o nome usado como prefixo em `named_parameters`
+    fn children(&self) -> Vec<(String, &dyn Module)> {
+        Vec::new()
+    }
+
+    /// Submódulos diretos, na mesma ordem de `children`, para propagar `train`
+    fn children_mut(&mut self) -> Vec<&mut dyn Module> {
+        Vec::new()
+    }
+
+    /// Parâmetros registrados no próprio módulo, sem os dos submódulos
+    fn try_local_parameters(&self) -> Result<Vec<(String, Tensor)>> {
+        Ok(Vec::new())
+    }
+
+    /// Todos os parâmetros, nomeados pelo caminho até eles (ex.: `"0.weight"`)
+    fn try_named_parameters(&self) -> Result<Vec<(String, Tensor)>> {
+        let mut params = self.try_local_parameters()?;
+        for (prefix, child) in self.children() {
+            for (name, param) in child.try_named_parameters()? {
+                params.push((format!("{}.{}", prefix, name), param));
+            }
+        }
+        Ok(params)
+    }
+
+    /// Todos os parâmetros, nomeados pelo caminho até eles (ex.: `"0.weight"`)
+    fn named_parameters(&self) -> Vec<(String, Tensor)> {
+        self.try_named_parameters().expect("Error reading module parameters")
+    }
+
+    /// Todos os parâmetros; compartilham o storage com o módulo, então um otimizador
+    /// criado sobre eles atualiza a rede
+    fn try_parameters(&self) -> Result<Vec<Tensor>> {
+        Ok(self.try_named_parameters()?.into_iter().map(|(_, param)| param).collect())
+    }
+
+    /// Todos os parâmetros; compartilham o storage com o módulo
+    fn parameters(&self) -> Vec<Tensor> {
+        self.try_parameters().expect("Error reading module parameters")
+    }
+
+    /// Buffers registrados no próprio módulo, sem os dos submódulos
+    fn try_local_buffers(&self) -> Result<Vec<(String, Tensor)>> {
+        Ok(Vec::new())
+    }
+
+    /// Todos os buffers, nomeados pelo caminho até eles (ex.: `"1.running_mean"`)
+    fn try_named_buffers(&self) -> Result<Vec<(String, Tensor)>> {
+        let mut buffers = self.try_local_buffers()?;
+        for (prefix, child) in self.children() {
+            for (name, buffer) in child.try_named_buffers()? {
+                buffers.push((format!("{}.{}", prefix, name), buffer));
+            }
+        }
+        Ok(buffers)
+    }
+
+    /// Todos os buffers, nomeados pelo caminho até eles (ex.: `"1.running_mean"`)
+    fn named_buffers(&self) -> Vec<(String, Tensor)> {
+        self.try_named_buffers().expect("Error reading module buffers")
+    }
+
+    /// Parâmetros seguidos dos buffers, como o `state_dict` do PyTorch
+    fn try_state_dict(&self) -> Result<Vec<(String, Tensor)>> {
+        let mut state = self.try_named_parameters()?;
+        state.extend(self.try_named_buffers()?);
+        Ok(state)
+    }
+
+    /// Parâmetros seguidos dos buffers, como o `state_dict` do PyTorch
+    fn state_dict(&self) -> Vec<(String, Tensor)> {
+        self.try_state_dict().expect("Error reading module state")
+    }
+
+    /// Grava parâmetros e buffers em `path` (formato do torch::save)
+    fn try_save(&self, path: &str) -> Result<()> {
+        with_named_tensors(&self.try_state_dict()?, path, "SaveTensors", |names, tensors, count, path| unsafe {
+            SaveTensors(names, tensors, count, path)
+        })
+    }
+
+    /// Grava parâmetros e buffers em `path` (formato do torch::save)
+    fn save(&self, path: &str) {
+        self.try_save(path).expect("Error saving module")
+    }
+
+    /// Restaura parâmetros e buffers gravados por `save`, copiando os valores no lugar;
+    /// todas as entradas do `state_dict` devem existir no arquivo com a mesma forma
+    fn try_load(&mut self, path: &str) -> Result<()> {
+        with_named_tensors(&self.try_state_dict()?, path, "LoadTensors", |names, tensors, count, path| unsafe {
+            LoadTensors(names, tensors, count, path)
+        })
+    }
+
+    /// Restaura parâmetros e buffers gravados por `save`, copiando os valores no lugar
+    fn load(&mut self, path: &str) {
+        self.try_load(path).expect("Error loading module")
+    }
+
+    /// Liga o modo de treino (`false` liga o de avaliação) neste módulo e nos submódulos
+    fn train(&mut self, mode: bool) {
+        for child in self.children_mut() {
+            child.train(mode);
+        }
+    }
+
+    /// Atalho para `train(false)`
+    fn eval(&mut self) {
+        self.train(false)
+    }
+
+    /// Indica se o módulo está em modo de treino; por padrão segue o primeiro submódulo
+    fn is_training(&self) -> bool {
+        self.children().first().is_none_or(|(_, child)| child.is_training())
+    }
+}
+
+// Módulos implementados no shim (torch::nn::Module*)
+
+/// Implementa `Module` e `Drop` para uma camada do shim guardada no campo `ptr`.
+/// O forward é a função `$forward` do ffi ou, na segunda forma, a expressão `$body`
+/// com o módulo e a entrada ligados a `$module` e `$input`
+macro_rules! native_module {
+    (impl[$($generics:tt)*] $ty:ty, $forward:ident) => {
+        native_module!(impl[$($generics)*] $ty, |module, input| {
+            $crate::tensor_libortch::tensor::Tensor::from_ptr(
+                unsafe { $forward(module.ptr, input.ptr) },
+                stringify!($forward),
+            )
+        });
+    };
+    (impl[$($generics:tt)*] $ty:ty, |$module:ident, $input:ident| $body:expr) => {
+        impl<$($generics)*> $crate::tensor_libortch::nn::Module for $ty {
+            fn try_forward(
+                &self,
+                input: &$crate::tensor_libortch::tensor::Tensor,
+            ) -> $crate::error::Result<$crate::tensor_libortch::tensor::Tensor> {
+                let $module = self;
+                let $input = input;
+                $body
+            }
+
+            fn try_local_parameters(
+                &self,
+            ) -> $crate::error::Result<Vec<(String, $crate::tensor_libortch::tensor::Tensor)>> {
+                $crate::tensor_libortch::nn::native_parameters(self.ptr)
+            }
+
+            fn try_local_buffers(
+                &self,
+            ) -> $crate::error::Result<Vec<(String, $crate::tensor_libortch::tensor::Tensor)>> {
+                $crate::tensor_libortch::nn::native_buffers(self.ptr)
+            }
+
+            fn train(&mut self, mode: bool) {
+                $crate::tensor_libortch::nn::native_train(self.ptr, mode)
+            }
+
+            fn is_training(&self) -> bool {
+                $crate::tensor_libortch::nn::native_is_training(self.ptr)
+            }
+        }
+
+        impl<$($generics)*> Drop for $ty {
+            fn drop(&mut self) {
+                unsafe { $crate::tensor_libortch::ffi::FreeModule(self.ptr) };
+            }
+        }
+    };
+}
+
+mod conv;
+mod dropout;
+mod embedding;
+mod loss;
+mod normalization;
+mod pooling;
+mod rnn;
+mod transformer;
+pub use conv::{Conv, Conv1d, Conv2d, Conv3d, ConvOptions, ConvTranspose, ConvTranspose1d, ConvTranspose2d, ConvTranspose3d};
+pub use pooling::{
+    AdaptiveAvgPool,
+    AdaptiveAvgPool1d,
+    AdaptiveAvgPool2d,
+    AdaptiveAvgPool3d,
+    AvgPool,
+    AvgPool1d,
+    AvgPool2d,
+    AvgPool3d,
+    MaxPool,
+    MaxPool1d,
+    MaxPool2d,
+    MaxPool3d,
+};
+pub use dropout::{AlphaDropout, Dropout, Dropout2d};
+pub(crate) use dropout::{check_dropout_p, DropoutKind};
+pub use embedding::{Embedding, EmbeddingBag, EmbeddingBagMode, EmbeddingOptions};
+pub use loss::Reduction;
+pub(crate) use loss::check_ctc_lengths;
+pub use normalization::{
+    BatchNorm,
+    BatchNorm1d,
+    BatchNorm2d,
+    BatchNorm3d,
+    GroupNorm,
+    InstanceNorm,
+    InstanceNorm1d,
+    InstanceNorm2d,
+    InstanceNorm3d,
+    LayerNorm,
+    NormOptions,
+};
+pub use rnn::{Gru, Lstm, Nonlinearity, Rnn, RnnOptions};
+pub use transformer::{
+    AttentionMasks,
+    MultiheadAttention,
+    TransformerDecoderLayer,
+    TransformerEncoderLayer,
+    TransformerOptions,
+};
+
+/// Garante que `D` seja 1, 2 ou 3 dimensões espaciais
+pub(crate) fn check_spatial_dims<const D: usize>(op: &str) -> Result<()> {
+    if (1..=3).contains(&D) {
+        Ok(())
+    } else {
+        Err(CopperError::InvalidArgument(format!(
+            "{} aceita 1, 2 ou 3 dimensões espaciais, recebeu {}",
+            op, D
+        )))
+    }
+}
+
+/// Lê uma lista de tensores do shim e os nomes correspondentes, um por índice
+fn native_named(
+    ptr: *mut c_void,
+    list: unsafe extern "C" fn(*mut c_void) -> *mut c_void,
+    name: unsafe extern "C" fn(*mut c_void, c_int) -> *const c_char,
+    list_op: &'static str,
+    name_op: &'static str,
+) -> Result<Vec<(String, Tensor)>> {
+    let tensors = TensorList(check_ptr(unsafe { list(ptr) }, list_op)?).into_tensors()?;
+    tensors
+        .into_iter()
+        .enumerate()
+        .map(|(index, tensor)| {
+            let name = unsafe { name(ptr, index as c_int) };
+            if name.is_null() {
+                return Err(backend_error(name_op));
+            }
+            let name = unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned();
+            Ok((name, tensor))
+        })
+        .collect()
+}
+
+/// Parâmetros de um módulo do shim, com os nomes dados pelo torch::nn
+pub(crate) fn native_parameters(ptr: *mut c_void) -> Result<Vec<(String, Tensor)>> {
+    native_named(ptr, ModuleParameters, ModuleParameterName, "ModuleParameters", "ModuleParameterName")
+}
+
+/// Buffers de um módulo do shim, com os nomes dados pelo torch::nn
+pub(crate) fn native_buffers(ptr: *mut c_void) -> Result<Vec<(String, Tensor)>> {
+    native_named(ptr, ModuleBuffers, ModuleBufferName, "ModuleBuffers", "ModuleBufferName")
+}
+
+/// Converte nomes e caminho para C e chama `call` (SaveTensors/LoadTensors) com os arrays
+fn with_named_tensors(
+    named: &[(String, Tensor)],
+    path: &str,
+    op: &'static str,
+    call: impl FnOnce(*const *const c_char, *const *mut c_void, c_int, *const c_char),
+) -> Result<()> {
+    let names = named
+        .iter()
+        .map(|(name, _)| CString::new(name.as_str()))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|_| CopperError::InvalidArgument("nome de tensor com byte nulo".to_string()))?;
+    let path = CString::new(path)
+        .map_err(|_| CopperError::InvalidArgument(format!("caminho inválido: {}", path)))?;
+    let name_ptrs: Vec<*const c_char> = names.iter().map(|name| name.as_ptr()).collect();
+    let tensor_ptrs: Vec<*mut c_void> = named.iter().map(|(_, tensor)| tensor.ptr).collect();
+    check_call(op, || call(name_ptrs.as_ptr(), tensor_ptrs.as_ptr(), named.len() as c_int, path.as_ptr()))
+}
+
+pub(crate) fn native_train(ptr: *mut c_void, mode: bool) {
+    check_call("ModuleTrain", || unsafe { ModuleTrain(ptr, mode) }).expect("Error setting training mode");
+}
+
+pub(crate) fn native_is_training(ptr: *mut c_void) -> bool {
+    check_call("ModuleIsTraining", || unsafe { ModuleIsTraining(ptr) })
+        .map(|flag| flag == 1)
+        .unwrap_or(true)
+}
+
+/// Camada totalmente conectada `y = x·Wᵀ + b`
+pub struct Linear {
+    pub ptr: *mut libc::c_void,
+    pub in_features: i32,
+    pub out_features: i32,
+}
+
+impl Linear {
+    pub fn try_new(in_features: i32, out_features: i32) -> Result<Self> {
+        let ptr = check_ptr(unsafe { CreateLinear(in_features, out_features) }, "CreateLinear")?;
+        Ok(Linear { ptr, in_features, out_features })
+    }
+
+    pub fn new(in_features: i32, out_features: i32) -> Self {
+        Linear::try_new(in_features, out_features).expect("Error creating Linear layer")
+    }
+
+    pub fn try_forward(&self, input: &Tensor) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { LinearForward(self.ptr, input.ptr) }, "LinearForward")
+    }
+
+    pub fn forward(&self, input: &Tensor) -> Tensor {
+        self.try_forward(input).expect("Error in Linear forward")
+    }
+}
+
+native_module!(impl[] Linear, LinearForward);
+
+/// Função sem parâmetros usada como camada (ex.: `Tensor::try_relu`)
+struct Lambda<F>(F);
+
+impl<F: Fn(&Tensor) -> Result<Tensor>> Module for Lambda<F> {
+    fn try_forward(&self, input: &Tensor) -> Result<Tensor> {
+        (self.0)(input)
+    }
+}
+
+/// Encadeia módulos: a saída de cada um é a entrada do próximo
+///
+/// Os parâmetros ficam nomeados pela posição da camada (`"0.weight"`, `"2.bias"`).
+pub struct Sequential {
+    layers: Vec<Box<dyn Module>>,
+    training: bool,
+}
+
+impl Sequential {
+    pub fn new() -> Self {
+        Sequential { layers: Vec::new(), training: true }
+    }
+
+    /// Acrescenta uma camada ao fim: `Sequential::new().add(Linear::new(2, 3))`
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<M: Module + 'static>(mut self, layer: M) -> Self {
+        self.push(layer);
+        self
+    }
+
+    /// Acrescenta uma função sem parâmetros ao fim: `.add_fn(Tensor::try_relu)`
+    pub fn add_fn<F: Fn(&Tensor) -> Result<Tensor> + 'static>(self, f: F) -> Self {
+        self.add(Lambda(f))
+    }
+
+    /// Acrescenta uma camada ao fim
+    pub fn push<M: Module + 'static>(&mut self, layer: M) {
+        self.layers.push(Box::new(layer));
+    }
+
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
+
+impl Default for Sequential {
+    fn default() -> Self {
+        Sequential::new()
+    }
+}
+
+impl Module for Sequential {
+    fn try_forward(&self, input: &Tensor) -> Result<Tensor> {
+        let Some((first, rest)) = self.layers.split_first() else {
+            return input.try_clone();
+        };
+        let mut output = first.try_forward(input)?;
+        for layer in rest {
+            output = layer.try_forward(&output)?;
+        }
+        Ok(output)
+    }
+
+    fn children(&self) -> Vec<(String, &dyn Module)> {
+        self.layers
+            .iter()
+            .enumerate()
+            .map(|(index, layer)| (index.to_string(), layer.as_ref()))
+            .collect()
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut dyn Module> {
+        self.layers.iter_mut().map(|layer| layer.as_mut() as &mut dyn Module).collect()
+    }
+
+    fn train(&mut self, mode: bool) {
+        self.training = mode;
+        for child in self.children_mut() {
+            child.train(mode);
+        }
+    }
+
+    fn is_training(&self) -> bool {
+        self.training
+    }
+}
@@ -0,0 +1,177 @@
+// Normalization
+// BatchNorm, InstanceNorm, LayerNorm e GroupNorm do torch::nn. A BatchNorm (e a
+// InstanceNorm com `track_running_stats`) guarda médias e variâncias móveis como
+// buffers: em treino normaliza com as estatísticas do batch e atualiza os buffers, em
+// avaliação (`eval()`) usa os buffers.
+
+use super::check_spatial_dims;
+use crate::error::{check_ptr, Result};
+use crate::tensor_libortch::ffi::{CreateBatchNorm, CreateGroupNorm, CreateLayerNorm, NormForward};
+use libc::c_int;
+
+/// Hiperparâmetros das camadas de normalização
+///
+/// `momentum` e `track_running_stats` só valem para BatchNorm e InstanceNorm; LayerNorm
+/// e GroupNorm usam `eps` e `affine` (pesos e bias por elemento/canal).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NormOptions {
+    pub eps: f64,
+    /// Peso da estatística do batch na atualização das médias móveis
+    pub momentum: f64,
+    pub affine: bool,
+    pub track_running_stats: bool,
+}
+
+impl Default for NormOptions {
+    fn default() -> Self {
+        NormOptions { eps: 1e-5, momentum: 0.1, affine: true, track_running_stats: true }
+    }
+}
+
+impl NormOptions {
+    /// Padrões do PyTorch para InstanceNorm: sem pesos e sem estatísticas móveis
+    pub fn instance_norm() -> Self {
+        NormOptions { affine: false, track_running_stats: false, ..NormOptions::default() }
+    }
+
+    pub fn eps(mut self, eps: f64) -> Self {
+        self.eps = eps;
+        self
+    }
+
+    pub fn momentum(mut self, momentum: f64) -> Self {
+        self.momentum = momentum;
+        self
+    }
+
+    pub fn affine(mut self, affine: bool) -> Self {
+        self.affine = affine;
+        self
+    }
+
+    pub fn track_running_stats(mut self, track_running_stats: bool) -> Self {
+        self.track_running_stats = track_running_stats;
+        self
+    }
+}
+
+fn try_create_batch_norm<const D: usize>(
+    instance: bool,
+    num_features: i64,
+    options: &NormOptions,
+) -> Result<*mut libc::c_void> {
+    check_spatial_dims::<D>(if instance { "instance_norm" } else { "batch_norm" })?;
+    check_ptr(
+        unsafe {
+            CreateBatchNorm(
+                D as c_int,
+                instance,
+                num_features,
+                options.eps,
+                options.momentum,
+                options.affine,
+                options.track_running_stats,
+            )
+        },
+        "CreateBatchNorm",
+    )
+}
+
+/// Normaliza cada canal sobre o batch e as dimensões espaciais; entrada `[N, C, *spatial]`
+/// (com `D = 1` também `[N, C]`)
+pub struct BatchNorm<const D: usize> {
+    pub ptr: *mut libc::c_void,
+    pub num_features: i64,
+    pub options: NormOptions,
+}
+
+pub type BatchNorm1d = BatchNorm<1>;
+pub type BatchNorm2d = BatchNorm<2>;
+pub type BatchNorm3d = BatchNorm<3>;
+
+impl<const D: usize> BatchNorm<D> {
+    pub fn try_new(num_features: i64, options: NormOptions) -> Result<Self> {
+        let ptr = try_create_batch_norm::<D>(false, num_features, &options)?;
+        Ok(BatchNorm { ptr, num_features, options })
+    }
+
+    pub fn new(num_features: i64, options: NormOptions) -> Self {
+        BatchNorm::try_new(num_features, options).expect("Error creating BatchNorm layer")
+    }
+}
+
+native_module!(impl[const D: usize] BatchNorm<D>, NormForward);
+
+/// Normaliza cada canal de cada amostra sobre as dimensões espaciais; normalmente criada
+/// com `NormOptions::instance_norm()`
+pub struct InstanceNorm<const D: usize> {
+    pub ptr: *mut libc::c_void,
+    pub num_features: i64,
+    pub options: NormOptions,
+}
+
+pub type InstanceNorm1d = InstanceNorm<1>;
+pub type InstanceNorm2d = InstanceNorm<2>;
+pub type InstanceNorm3d = InstanceNorm<3>;
+
+impl<const D: usize> InstanceNorm<D> {
+    pub fn try_new(num_features: i64, options: NormOptions) -> Result<Self> {
+        let ptr = try_create_batch_norm::<D>(true, num_features, &options)?;
+        Ok(InstanceNorm { ptr, num_features, options })
+    }
+
+    pub fn new(num_features: i64, options: NormOptions) -> Self {
+        InstanceNorm::try_new(num_features, options).expect("Error creating InstanceNorm layer")
+    }
+}
+
+native_module!(impl[const D: usize] InstanceNorm<D>, NormForward);
+
+/// Normaliza cada amostra sobre as últimas dimensões, que devem ser `normalized_shape`
+pub struct LayerNorm {
+    pub ptr: *mut libc::c_void,
+    pub normalized_shape: Vec<i64>,
+    pub options: NormOptions,
+}
+
+impl LayerNorm {
+    pub fn try_new(normalized_shape: &[i64], options: NormOptions) -> Result<Self> {
+        let ptr = check_ptr(
+            unsafe {
+                CreateLayerNorm(normalized_shape.as_ptr(), normalized_shape.len() as c_int, options.eps, options.affine)
+            },
+            "CreateLayerNorm",
+        )?;
+        Ok(LayerNorm { ptr, normalized_shape: normalized_shape.to_vec(), options })
+    }
+
+    pub fn new(normalized_shape: &[i64], options: NormOptions) -> Self {
+        LayerNorm::try_new(normalized_shape, options).expect("Error creating LayerNorm layer")
+    }
+}
+
+native_module!(impl[] LayerNorm, NormForward);
+
+/// Divide os canais em `num_groups` grupos e normaliza cada grupo de cada amostra
+pub struct GroupNorm {
+    pub ptr: *mut libc::c_void,
+    pub num_groups: i64,
+    pub num_channels: i64,
+    pub options: NormOptions,
+}
+
+impl GroupNorm {
+    pub fn try_new(num_groups: i64, num_channels: i64, options: NormOptions) -> Result<Self> {
+        let ptr = check_ptr(
+            unsafe { CreateGroupNorm(num_groups, num_channels, options.eps, options.affine) },
+            "CreateGroupNorm",
+        )?;
+        Ok(GroupNorm { ptr, num_groups, num_channels, options })
+    }
+
+    pub fn new(num_groups: i64, num_channels: i64, options: NormOptions) -> Self {
+        GroupNorm::try_new(num_groups, num_channels, options).expect("Error creating GroupNorm layer")
+    }
+}
+
+native_module!(impl[] GroupNorm, NormForward);
@@ -0,0 +1,218 @@
+// Pooling
+// MaxPool, AvgPool e AdaptiveAvgPool com `D` dimensões espaciais, como módulos sem
+// parâmetros e como formas funcionais em Tensor
+
+use super::{check_spatial_dims, Module};
+use crate::error::Result;
+use crate::tensor_libortch::ffi::{TensorAdaptiveAvgPool, TensorAvgPool, TensorMaxPool};
+use crate::tensor_libortch::tensor::Tensor;
+use libc::c_int;
+
+impl Tensor {
+    /// Max pooling (max_pool1d/2d/3d conforme `D`) sobre entrada `[N, C, *spatial]` ou sem o batch
+    pub fn try_max_pool<const D: usize>(
+        &self,
+        kernel_size: [i64; D],
+        stride: [i64; D],
+        padding: [i64; D],
+        dilation: [i64; D],
+        ceil_mode: bool,
+    ) -> Result<Tensor> {
+        check_spatial_dims::<D>("max_pool")?;
+        Tensor::from_ptr(
+            unsafe {
+                TensorMaxPool(
+                    self.ptr,
+                    kernel_size.as_ptr(),
+                    stride.as_ptr(),
+                    padding.as_ptr(),
+                    dilation.as_ptr(),
+                    D as c_int,
+                    ceil_mode,
+                )
+            },
+            "TensorMaxPool",
+        )
+    }
+
+    /// Max pooling (max_pool1d/2d/3d conforme `D`)
+    pub fn max_pool<const D: usize>(
+        &self,
+        kernel_size: [i64; D],
+        stride: [i64; D],
+        padding: [i64; D],
+        dilation: [i64; D],
+        ceil_mode: bool,
+    ) -> Tensor {
+        self.try_max_pool(kernel_size, stride, padding, dilation, ceil_mode)
+            .expect("Error in max pooling")
+    }
+
+    /// Average pooling (avg_pool1d/2d/3d conforme `D`); com `count_include_pad` o
+    /// preenchimento entra no divisor
+    pub fn try_avg_pool<const D: usize>(
+        &self,
+        kernel_size: [i64; D],
+        stride: [i64; D],
+        padding: [i64; D],
+        ceil_mode: bool,
+        count_include_pad: bool,
+    ) -> Result<Tensor> {
+        check_spatial_dims::<D>("avg_pool")?;
+        Tensor::from_ptr(
+            unsafe {
+                TensorAvgPool(
+                    self.ptr,
+                    kernel_size.as_ptr(),
+                    stride.as_ptr(),
+                    padding.as_ptr(),
+                    D as c_int,
+                    ceil_mode,
+                    count_include_pad,
+                )
+            },
+            "TensorAvgPool",
+        )
+    }
+
+    /// Average pooling (avg_pool1d/2d/3d conforme `D`)
+    pub fn avg_pool<const D: usize>(
+        &self,
+        kernel_size: [i64; D],
+        stride: [i64; D],
+        padding: [i64; D],
+        ceil_mode: bool,
+        count_include_pad: bool,
+    ) -> Tensor {
+        self.try_avg_pool(kernel_size, stride, padding, ceil_mode, count_include_pad)
+            .expect("Error in average pooling")
+    }
+
+    /// Average pooling que escolhe as janelas para produzir exatamente `output_size`
+    pub fn try_adaptive_avg_pool<const D: usize>(&self, output_size: [i64; D]) -> Result<Tensor> {
+        check_spatial_dims::<D>("adaptive_avg_pool")?;
+        Tensor::from_ptr(
+            unsafe { TensorAdaptiveAvgPool(self.ptr, output_size.as_ptr(), D as c_int) },
+            "TensorAdaptiveAvgPool",
+        )
+    }
+
+    /// Average pooling que escolhe as janelas para produzir exatamente `output_size`
+    pub fn adaptive_avg_pool<const D: usize>(&self, output_size: [i64; D]) -> Tensor {
+        self.try_adaptive_avg_pool(output_size).expect("Error in adaptive average pooling")
+    }
+}
+
+/// Camada de max pooling; por padrão o stride é igual ao kernel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaxPool<const D: usize> {
+    pub kernel_size: [i64; D],
+    pub stride: [i64; D],
+    pub padding: [i64; D],
+    pub dilation: [i64; D],
+    pub ceil_mode: bool,
+}
+
+pub type MaxPool1d = MaxPool<1>;
+pub type MaxPool2d = MaxPool<2>;
+pub type MaxPool3d = MaxPool<3>;
+
+impl<const D: usize> MaxPool<D> {
+    pub fn new(kernel_size: [i64; D]) -> Self {
+        MaxPool { kernel_size, stride: kernel_size, padding: [0; D], dilation: [1; D], ceil_mode: false }
+    }
+
+    pub fn stride(mut self, stride: [i64; D]) -> Self {
+        self.stride = stride;
+        self
+    }
+
+    pub fn padding(mut self, padding: [i64; D]) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn dilation(mut self, dilation: [i64; D]) -> Self {
+        self.dilation = dilation;
+        self
+    }
+
+    pub fn ceil_mode(mut self, ceil_mode: bool) -> Self {
+        self.ceil_mode = ceil_mode;
+        self
+    }
+}
+
+impl<const D: usize> Module for MaxPool<D> {
+    fn try_forward(&self, input: &Tensor) -> Result<Tensor> {
+        input.try_max_pool(self.kernel_size, self.stride, self.padding, self.dilation, self.ceil_mode)
+    }
+}
+
+/// Camada de average pooling; por padrão o stride é igual ao kernel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AvgPool<const D: usize> {
+    pub kernel_size: [i64; D],
+    pub stride: [i64; D],
+    pub padding: [i64; D],
+    pub ceil_mode: bool,
+    pub count_include_pad: bool,
+}
+
+pub type AvgPool1d = AvgPool<1>;
+pub type AvgPool2d = AvgPool<2>;
+pub type AvgPool3d = AvgPool<3>;
+
+impl<const D: usize> AvgPool<D> {
+    pub fn new(kernel_size: [i64; D]) -> Self {
+        AvgPool { kernel_size, stride: kernel_size, padding: [0; D], ceil_mode: false, count_include_pad: true }
+    }
+
+    pub fn stride(mut self, stride: [i64; D]) -> Self {
+        self.stride = stride;
+        self
+    }
+
+    pub fn padding(mut self, padding: [i64; D]) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn ceil_mode(mut self, ceil_mode: bool) -> Self {
+        self.ceil_mode = ceil_mode;
+        self
+    }
+
+    pub fn count_include_pad(mut self, count_include_pad: bool) -> Self {
+        self.count_include_pad = count_include_pad;
+        self
+    }
+}
+
+impl<const D: usize> Module for AvgPool<D> {
+    fn try_forward(&self, input: &Tensor) -> Result<Tensor> {
+        input.try_avg_pool(self.kernel_size, self.stride, self.padding, self.ceil_mode, self.count_include_pad)
+    }
+}
+
+/// Camada de average pooling adaptativo com saída de tamanho fixo
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveAvgPool<const D: usize> {
+    pub output_size: [i64; D],
+}
+
+pub type AdaptiveAvgPool1d = AdaptiveAvgPool<1>;
+pub type AdaptiveAvgPool2d = AdaptiveAvgPool<2>;
+pub type AdaptiveAvgPool3d = AdaptiveAvgPool<3>;
+
+impl<const D: usize> AdaptiveAvgPool<D> {
+    pub fn new(output_size: [i64; D]) -> Self {
+        AdaptiveAvgPool { output_size }
+    }
+}
+
+impl<const D: usize> Module for AdaptiveAvgPool<D> {
+    fn try_forward(&self, input: &Tensor) -> Result<Tensor> {
+        input.try_adaptive_avg_pool(self.output_size)
+    }
+}
@@ -0,0 +1,235 @@
+// Recurrent Layers
+// RNN, LSTM e GRU do torch::nn com várias camadas, bidirecionais e batch_first.
+// Como `Module`, o forward devolve só a sequência de saída; os estados finais vêm de
+// `forward_with_state`.
+
+use crate::error::{check_ptr, Result};
+use crate::tensor_libortch::autograd::TensorList;
+use crate::tensor_libortch::ffi::{CreateRNN, RNNForward};
+use crate::tensor_libortch::tensor::Tensor;
+use libc::c_int;
+use std::ptr;
+
+/// Não linearidade aplicada pelo `Rnn` simples
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Nonlinearity {
+    Tanh,
+    ReLU,
+}
+
+/// Hiperparâmetros comuns às camadas recorrentes
+///
+/// Os estados têm forma `[num_layers * direções, N, hidden_size]`; com `batch_first` a
+/// entrada e a saída são `[N, L, *]` em vez de `[L, N, *]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RnnOptions {
+    pub num_layers: i64,
+    pub bias: bool,
+    pub batch_first: bool,
+    /// Dropout entre as camadas empilhadas (exceto a última), só em modo de treino
+    pub dropout: f64,
+    pub bidirectional: bool,
+    /// Só usado pelo `Rnn`
+    pub nonlinearity: Nonlinearity,
+}
+
+impl Default for RnnOptions {
+    fn default() -> Self {
+        RnnOptions {
+            num_layers: 1,
+            bias: true,
+            batch_first: false,
+            dropout: 0.0,
+            bidirectional: false,
+            nonlinearity: Nonlinearity::Tanh,
+        }
+    }
+}
+
+impl RnnOptions {
+    pub fn num_layers(mut self, num_layers: i64) -> Self {
+        self.num_layers = num_layers;
+        self
+    }
+
+    pub fn bias(mut self, bias: bool) -> Self {
+        self.bias = bias;
+        self
+    }
+
+    pub fn batch_first(mut self, batch_first: bool) -> Self {
+        self.batch_first = batch_first;
+        self
+    }
+
+    pub fn dropout(mut self, dropout: f64) -> Self {
+        self.dropout = dropout;
+        self
+    }
+
+    pub fn bidirectional(mut self, bidirectional: bool) -> Self {
+        self.bidirectional = bidirectional;
+        self
+    }
+
+    pub fn nonlinearity(mut self, nonlinearity: Nonlinearity) -> Self {
+        self.nonlinearity = nonlinearity;
+        self
+    }
+}
+
+// Códigos de `mode` do CreateRNN no shim
+const MODE_RNN_TANH: c_int = 0;
+const MODE_RNN_RELU: c_int = 1;
+const MODE_LSTM: c_int = 2;
+const MODE_GRU: c_int = 3;
+
+fn try_create_rnn(mode: c_int, input_size: i64, hidden_size: i64, options: &RnnOptions) -> Result<*mut libc::c_void> {
+    check_ptr(
+        unsafe {
+            CreateRNN(
+                mode,
+                input_size,
+                hidden_size,
+                options.num_layers,
+                options.bias,
+                options.batch_first,
+                options.dropout,
+                options.bidirectional,
+            )
+        },
+        "CreateRNN",
+    )
+}
+
+/// Executa o forward e devolve `[output, h_n]` ou `[output, h_n, c_n]`
+fn try_rnn_forward(
+    module: *mut libc::c_void,
+    input: &Tensor,
+    h0: Option<&Tensor>,
+    c0: Option<&Tensor>,
+) -> Result<Vec<Tensor>> {
+    let list = unsafe {
+        RNNForward(
+            module,
+            input.ptr,
+            h0.map_or(ptr::null_mut(), |t| t.ptr),
+            c0.map_or(ptr::null_mut(), |t| t.ptr),
+        )
+    };
+    TensorList(check_ptr(list, "RNNForward")?).into_tensors()
+}
+
+/// RNN de Elman `h_t = σ(W_ih·x_t + b_ih + W_hh·h_(t-1) + b_hh)`
+pub struct Rnn {
+    pub ptr: *mut libc::c_void,
+    pub input_size: i64,
+    pub hidden_size: i64,
+    pub options: RnnOptions,
+}
+
+impl Rnn {
+    pub fn try_new(input_size: i64, hidden_size: i64, options: RnnOptions) -> Result<Self> {
+        let mode = match options.nonlinearity {
+            Nonlinearity::Tanh => MODE_RNN_TANH,
+            Nonlinearity::ReLU => MODE_RNN_RELU,
+        };
+        let ptr = try_create_rnn(mode, input_size, hidden_size, &options)?;
+        Ok(Rnn { ptr, input_size, hidden_size, options })
+    }
+
+    pub fn new(input_size: i64, hidden_size: i64, options: RnnOptions) -> Self {
+        Rnn::try_new(input_size, hidden_size, options).expect("Error creating RNN layer")
+    }
+
+    /// Devolve `(output, h_n)`; sem `h0` o estado inicial é zero
+    pub fn try_forward_with_state(&self, input: &Tensor, h0: Option<&Tensor>) -> Result<(Tensor, Tensor)> {
+        let mut outputs = try_rnn_forward(self.ptr, input, h0, None)?.into_iter();
+        let (Some(output), Some(h_n)) = (outputs.next(), outputs.next()) else {
+            unreachable!("RNNForward devolve output e h_n");
+        };
+        Ok((output, h_n))
+    }
+
+    /// Devolve `(output, h_n)`; sem `h0` o estado inicial é zero
+    pub fn forward_with_state(&self, input: &Tensor, h0: Option<&Tensor>) -> (Tensor, Tensor) {
+        self.try_forward_with_state(input, h0).expect("Error in RNN forward")
+    }
+}
+
+native_module!(impl[] Rnn, |rnn, input| Ok(rnn.try_forward_with_state(input, None)?.0));
+
+/// LSTM com estado oculto `h` e estado de célula `c`
+pub struct Lstm {
+    pub ptr: *mut libc::c_void,
+    pub input_size: i64,
+    pub hidden_size: i64,
+    pub options: RnnOptions,
+}
+
+impl Lstm {
+    pub fn try_new(input_size: i64, hidden_size: i64, options: RnnOptions) -> Result<Self> {
+        let ptr = try_create_rnn(MODE_LSTM, input_size, hidden_size, &options)?;
+        Ok(Lstm { ptr, input_size, hidden_size, options })
+    }
+
+    pub fn new(input_size: i64, hidden_size: i64, options: RnnOptions) -> Self {
+        Lstm::try_new(input_size, hidden_size, options).expect("Error creating LSTM layer")
+    }
+
+    /// Devolve `(output, (h_n, c_n))`; sem `state` os estados iniciais são zero
+    pub fn try_forward_with_state(
+        &self,
+        input: &Tensor,
+        state: Option<(&Tensor, &Tensor)>,
+    ) -> Result<(Tensor, (Tensor, Tensor))> {
+        let (h0, c0) = state.map_or((None, None), |(h0, c0)| (Some(h0), Some(c0)));
+        let mut outputs = try_rnn_forward(self.ptr, input, h0, c0)?.into_iter();
+        let (Some(output), Some(h_n), Some(c_n)) = (outputs.next(), outputs.next(), outputs.next()) else {
+            unreachable!("RNNForward devolve output, h_n e c_n para o LSTM");
+        };
+        Ok((output, (h_n, c_n)))
+    }
+
+    /// Devolve `(output, (h_n, c_n))`; sem `state` os estados iniciais são zero
+    pub fn forward_with_state(&self, input: &Tensor, state: Option<(&Tensor, &Tensor)>) -> (Tensor, (Tensor, Tensor)) {
+        self.try_forward_with_state(input, state).expect("Error in LSTM forward")
+    }
+}
+
+native_module!(impl[] Lstm, |lstm, input| Ok(lstm.try_forward_with_state(input, None)?.0));
+
+/// GRU com portas de reset e de atualização
+pub struct Gru {
+    pub ptr: *mut libc::c_void,
+    pub input_size: i64,
+    pub hidden_size: i64,
+    pub options: RnnOptions,
+}
+
+impl Gru {
+    pub fn try_new(input_size: i64, hidden_size: i64, options: RnnOptions) -> Result<Self> {
+        let ptr = try_create_rnn(MODE_GRU, input_size, hidden_size, &options)?;
+        Ok(Gru { ptr, input_size, hidden_size, options })
+    }
+
+    pub fn new(input_size: i64, hidden_size: i64, options: RnnOptions) -> Self {
+        Gru::try_new(input_size, hidden_size, options).expect("Error creating GRU layer")
+    }
+
+    /// Devolve `(output, h_n)`; sem `h0` o estado inicial é zero
+    pub fn try_forward_with_state(&self, input: &Tensor, h0: Option<&Tensor>) -> Result<(Tensor, Tensor)> {
+        let mut outputs = try_rnn_forward(self.ptr, input, h0, None)?.into_iter();
+        let (Some(output), Some(h_n)) = (outputs.next(), outputs.next()) else {
+            unreachable!("RNNForward devolve output e h_n");
+        };
+        Ok((output, h_n))
+    }
+
+    /// Devolve `(output, h_n)`; sem `h0` o estado inicial é zero
+    pub fn forward_with_state(&self, input: &Tensor, h0: Option<&Tensor>) -> (Tensor, Tensor) {
+        self.try_forward_with_state(input, h0).expect("Error in GRU forward")
+    }
+}
+
+native_module!(impl[] Gru, |gru, input| Ok(gru.try_forward_with_state(input, None)?.0));
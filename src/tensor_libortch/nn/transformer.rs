@@ -0,0 +1,479 @@
+// Transformer
+// MultiheadAttention e as camadas TransformerEncoderLayer/TransformerDecoderLayer,
+// compostas em Rust a partir de Linear, LayerNorm e Dropout. O núcleo da atenção
+// (scores, máscaras e softmax) é o TensorAttention do shim. As entradas são
+// batch-first: `[N, L, E]`.
+
+use super::{check_dropout_p, Dropout, LayerNorm, Linear, Module, NormOptions};
+use crate::error::{check_ptr, CopperError, Result};
+use crate::tensor_libortch::autograd::TensorList;
+use crate::tensor_libortch::ffi::TensorAttention;
+use crate::tensor_libortch::tensor::Tensor;
+use std::ptr;
+
+/// Máscaras opcionais de uma atenção
+///
+/// `attn_mask` tem forma `[L, S]` ou `[N * num_heads, L, S]` e `key_padding_mask` tem
+/// forma `[N, S]`. Máscaras bool marcam as posições ignoradas (`true`); as de ponto
+/// flutuante são somadas aos scores antes do softmax.
+#[derive(Clone, Copy, Default)]
+pub struct AttentionMasks<'a> {
+    pub attn_mask: Option<&'a Tensor>,
+    pub key_padding_mask: Option<&'a Tensor>,
+}
+
+impl<'a> AttentionMasks<'a> {
+    pub fn attn_mask(mut self, attn_mask: &'a Tensor) -> Self {
+        self.attn_mask = Some(attn_mask);
+        self
+    }
+
+    pub fn key_padding_mask(mut self, key_padding_mask: &'a Tensor) -> Self {
+        self.key_padding_mask = Some(key_padding_mask);
+        self
+    }
+}
+
+/// Chama o TensorAttention e devolve `(output, pesos)`
+fn try_attention(
+    query: &Tensor,
+    key: &Tensor,
+    value: &Tensor,
+    attn_mask: Option<&Tensor>,
+    key_padding_mask: Option<&Tensor>,
+    dropout_p: f64,
+    train: bool,
+) -> Result<(Tensor, Tensor)> {
+    check_dropout_p(dropout_p, "attention")?;
+    let list = unsafe {
+        TensorAttention(
+            query.ptr,
+            key.ptr,
+            value.ptr,
+            attn_mask.map_or(ptr::null_mut(), |mask| mask.ptr),
+            key_padding_mask.map_or(ptr::null_mut(), |mask| mask.ptr),
+            dropout_p,
+            train,
+        )
+    };
+    let mut outputs = TensorList(check_ptr(list, "TensorAttention")?).into_tensors()?.into_iter();
+    let (Some(output), Some(weights)) = (outputs.next(), outputs.next()) else {
+        unreachable!("TensorAttention devolve output e pesos");
+    };
+    Ok((output, weights))
+}
+
+impl Tensor {
+    /// Atenção `softmax(Q·Kᵀ / √E + attn_mask)·V` sobre as duas últimas dimensões, com
+    /// `self` como Q; devolve `(output, pesos)`. Com `train` aplica dropout aos pesos.
+    pub fn try_scaled_dot_product_attention(
+        &self,
+        key: &Tensor,
+        value: &Tensor,
+        attn_mask: Option<&Tensor>,
+        dropout_p: f64,
+        train: bool,
+    ) -> Result<(Tensor, Tensor)> {
+        try_attention(self, key, value, attn_mask, None, dropout_p, train)
+    }
+
+    /// Atenção `softmax(Q·Kᵀ / √E + attn_mask)·V` com `self` como Q; devolve `(output, pesos)`
+    pub fn scaled_dot_product_attention(
+        &self,
+        key: &Tensor,
+        value: &Tensor,
+        attn_mask: Option<&Tensor>,
+        dropout_p: f64,
+        train: bool,
+    ) -> (Tensor, Tensor) {
+        self.try_scaled_dot_product_attention(key, value, attn_mask, dropout_p, train)
+            .expect("Error in scaled dot product attention")
+    }
+
+    /// Máscara bool `[size, size]` que esconde as posições futuras (`true` acima da diagonal)
+    pub fn try_causal_mask(size: i64) -> Result<Tensor> {
+        if size < 0 {
+            return Err(CopperError::InvalidArgument(format!("tamanho de máscara negativo: {}", size)));
+        }
+        let n = size as usize;
+        let values: Vec<bool> = (0..n * n).map(|i| i % n > i / n).collect();
+        Tensor::try_from_slice(&values, &[size, size])
+    }
+
+    /// Máscara bool `[size, size]` que esconde as posições futuras (`true` acima da diagonal)
+    pub fn causal_mask(size: i64) -> Tensor {
+        Tensor::try_causal_mask(size).expect("Error creating causal mask")
+    }
+}
+
+fn try_linear(in_features: i64, out_features: i64) -> Result<Linear> {
+    let features = |n: i64| {
+        i32::try_from(n).map_err(|_| CopperError::InvalidArgument(format!("dimensão fora do intervalo de i32: {}", n)))
+    };
+    Linear::try_new(features(in_features)?, features(out_features)?)
+}
+
+/// Implementa `Module` para um bloco composto cujos submódulos são os campos listados
+/// e que guarda o próprio modo em `training`
+macro_rules! composite_module {
+    ($ty:ident, [$($field:ident),*], |$layer:ident, $input:ident| $body:expr) => {
+        impl Module for $ty {
+            fn try_forward(&self, $input: &Tensor) -> Result<Tensor> {
+                let $layer = self;
+                $body
+            }
+
+            fn children(&self) -> Vec<(String, &dyn Module)> {
+                vec![$((stringify!($field).to_string(), &self.$field as &dyn Module)),*]
+            }
+
+            fn children_mut(&mut self) -> Vec<&mut dyn Module> {
+                vec![$(&mut self.$field as &mut dyn Module),*]
+            }
+
+            fn train(&mut self, mode: bool) {
+                self.training = mode;
+                for child in self.children_mut() {
+                    child.train(mode);
+                }
+            }
+
+            fn is_training(&self) -> bool {
+                self.training
+            }
+        }
+    };
+}
+
+/// Atenção com `num_heads` cabeças de dimensão `embed_dim / num_heads`
+///
+/// Os parâmetros são `q_proj`, `k_proj`, `v_proj` e `out_proj` (cada um uma `Linear`).
+pub struct MultiheadAttention {
+    pub embed_dim: i64,
+    pub num_heads: i64,
+    /// Dropout aplicado aos pesos de atenção em modo de treino
+    pub dropout: f64,
+    q_proj: Linear,
+    k_proj: Linear,
+    v_proj: Linear,
+    out_proj: Linear,
+    training: bool,
+}
+
+impl MultiheadAttention {
+    pub fn try_new(embed_dim: i64, num_heads: i64, dropout: f64) -> Result<Self> {
+        if embed_dim <= 0 || num_heads <= 0 || embed_dim % num_heads != 0 {
+            return Err(CopperError::InvalidArgument(format!(
+                "embed_dim ({}) deve ser um múltiplo positivo de num_heads ({})",
+                embed_dim, num_heads
+            )));
+        }
+        check_dropout_p(dropout, "MultiheadAttention")?;
+        Ok(MultiheadAttention {
+            embed_dim,
+            num_heads,
+            dropout,
+            q_proj: try_linear(embed_dim, embed_dim)?,
+            k_proj: try_linear(embed_dim, embed_dim)?,
+            v_proj: try_linear(embed_dim, embed_dim)?,
+            out_proj: try_linear(embed_dim, embed_dim)?,
+            training: true,
+        })
+    }
+
+    pub fn new(embed_dim: i64, num_heads: i64, dropout: f64) -> Self {
+        MultiheadAttention::try_new(embed_dim, num_heads, dropout).expect("Error creating MultiheadAttention layer")
+    }
+
+    /// Projeta e separa as cabeças: `[N, L, E]` -> `[N, H, L, E / H]`
+    fn try_project_heads(&self, projection: &Linear, input: &Tensor) -> Result<Tensor> {
+        let shape = input.shape();
+        if shape.len() != 3 || shape[2] != self.embed_dim {
+            return Err(CopperError::InvalidArgument(format!(
+                "MultiheadAttention espera entradas [N, L, {}], recebeu {:?}",
+                self.embed_dim, shape
+            )));
+        }
+        projection
+            .try_forward(input)?
+            .try_reshape(&[shape[0], shape[1], self.num_heads, self.embed_dim / self.num_heads])?
+            .try_transpose_dims(1, 2)
+    }
+
+    /// Atenção de `query` `[N, L, E]` sobre `key`/`value` `[N, S, E]`; devolve a saída
+    /// `[N, L, E]` e os pesos `[N, L, S]` (média das cabeças). Em treino os pesos já
+    /// incluem o dropout, como no PyTorch.
+    pub fn try_forward_with_masks(
+        &self,
+        query: &Tensor,
+        key: &Tensor,
+        value: &Tensor,
+        masks: AttentionMasks,
+    ) -> Result<(Tensor, Tensor)> {
+        let q = self.try_project_heads(&self.q_proj, query)?;
+        let k = self.try_project_heads(&self.k_proj, key)?;
+        let v = self.try_project_heads(&self.v_proj, value)?;
+        let (n, l) = (query.shape()[0], query.shape()[1]);
+        // `[N * H, L, S]` vira `[N, H, L, S]`; `[L, S]` já é broadcast sobre batch e cabeças
+        let per_head_mask = match masks.attn_mask {
+            Some(mask) if mask.shape().len() == 3 => Some(mask.try_reshape(&[n, self.num_heads, l, -1])?),
+            _ => None,
+        };
+        let padding_mask = masks.key_padding_mask.map(|mask| mask.try_reshape(&[n, 1, 1, -1])).transpose()?;
+        let (output, weights) = try_attention(
+            &q,
+            &k,
+            &v,
+            per_head_mask.as_ref().or(masks.attn_mask),
+            padding_mask.as_ref(),
+            self.dropout,
+            self.training,
+        )?;
+        let output = output.try_transpose_dims(1, 2)?.try_reshape(&[n, l, self.embed_dim])?;
+        Ok((self.out_proj.try_forward(&output)?, weights.try_mean_dim(1, false)?))
+    }
+
+    /// Atenção de `query` `[N, L, E]` sobre `key`/`value` `[N, S, E]`; devolve a saída
+    /// `[N, L, E]` e os pesos `[N, L, S]` (média das cabeças). Em treino os pesos já
+    /// incluem o dropout, como no PyTorch.
+    pub fn forward_with_masks(&self, query: &Tensor, key: &Tensor, value: &Tensor, masks: AttentionMasks) -> (Tensor, Tensor) {
+        self.try_forward_with_masks(query, key, value, masks)
+            .expect("Error in MultiheadAttention forward")
+    }
+}
+
+// Como `Module`: auto-atenção sem máscaras
+composite_module!(MultiheadAttention, [q_proj, k_proj, v_proj, out_proj], |attn, input| {
+    Ok(attn.try_forward_with_masks(input, input, input, AttentionMasks::default())?.0)
+});
+
+/// Hiperparâmetros das camadas Transformer
+#[derive(Debug, Clone, Copy)]
+pub struct TransformerOptions {
+    /// Largura da camada oculta do feedforward
+    pub dim_feedforward: i64,
+    pub dropout: f64,
+    /// Ativação entre as duas camadas do feedforward
+    pub activation: fn(&Tensor) -> Result<Tensor>,
+    pub layer_norm_eps: f64,
+    /// Aplica a LayerNorm antes de cada subcamada (pre-norm) em vez de depois da soma residual
+    pub norm_first: bool,
+}
+
+impl Default for TransformerOptions {
+    fn default() -> Self {
+        TransformerOptions {
+            dim_feedforward: 2048,
+            dropout: 0.1,
+            activation: Tensor::try_relu,
+            layer_norm_eps: 1e-5,
+            norm_first: false,
+        }
+    }
+}
+
+impl TransformerOptions {
+    pub fn dim_feedforward(mut self, dim_feedforward: i64) -> Self {
+        self.dim_feedforward = dim_feedforward;
+        self
+    }
+
+    pub fn dropout(mut self, dropout: f64) -> Self {
+        self.dropout = dropout;
+        self
+    }
+
+    pub fn activation(mut self, activation: fn(&Tensor) -> Result<Tensor>) -> Self {
+        self.activation = activation;
+        self
+    }
+
+    pub fn layer_norm_eps(mut self, layer_norm_eps: f64) -> Self {
+        self.layer_norm_eps = layer_norm_eps;
+        self
+    }
+
+    pub fn norm_first(mut self, norm_first: bool) -> Self {
+        self.norm_first = norm_first;
+        self
+    }
+}
+
+fn try_layer_norm(d_model: i64, options: &TransformerOptions) -> Result<LayerNorm> {
+    LayerNorm::try_new(&[d_model], NormOptions::default().eps(options.layer_norm_eps))
+}
+
+/// Camada do encoder: auto-atenção seguida de feedforward, cada uma com soma residual
+/// e LayerNorm
+pub struct TransformerEncoderLayer {
+    pub d_model: i64,
+    pub nhead: i64,
+    pub options: TransformerOptions,
+    self_attn: MultiheadAttention,
+    linear1: Linear,
+    dropout: Dropout,
+    linear2: Linear,
+    norm1: LayerNorm,
+    norm2: LayerNorm,
+    dropout1: Dropout,
+    dropout2: Dropout,
+    training: bool,
+}
+
+impl TransformerEncoderLayer {
+    pub fn try_new(d_model: i64, nhead: i64, options: TransformerOptions) -> Result<Self> {
+        Ok(TransformerEncoderLayer {
+            d_model,
+            nhead,
+            options,
+            self_attn: MultiheadAttention::try_new(d_model, nhead, options.dropout)?,
+            linear1: try_linear(d_model, options.dim_feedforward)?,
+            dropout: Dropout::new(options.dropout),
+            linear2: try_linear(options.dim_feedforward, d_model)?,
+            norm1: try_layer_norm(d_model, &options)?,
+            norm2: try_layer_norm(d_model, &options)?,
+            dropout1: Dropout::new(options.dropout),
+            dropout2: Dropout::new(options.dropout),
+            training: true,
+        })
+    }
+
+    pub fn new(d_model: i64, nhead: i64, options: TransformerOptions) -> Self {
+        TransformerEncoderLayer::try_new(d_model, nhead, options).expect("Error creating TransformerEncoderLayer")
+    }
+
+    fn try_self_attention(&self, x: &Tensor, masks: AttentionMasks) -> Result<Tensor> {
+        let (output, _) = self.self_attn.try_forward_with_masks(x, x, x, masks)?;
+        self.dropout1.try_forward(&output)
+    }
+
+    fn try_feed_forward(&self, x: &Tensor) -> Result<Tensor> {
+        let hidden = self.dropout.try_forward(&(self.options.activation)(&self.linear1.try_forward(x)?)?)?;
+        self.dropout2.try_forward(&self.linear2.try_forward(&hidden)?)
+    }
+
+    /// Aplica a camada a `src` `[N, L, d_model]` com máscaras de auto-atenção
+    pub fn try_forward_with_masks(&self, src: &Tensor, masks: AttentionMasks) -> Result<Tensor> {
+        if self.options.norm_first {
+            let x = src.try_add(&self.try_self_attention(&self.norm1.try_forward(src)?, masks)?)?;
+            x.try_add(&self.try_feed_forward(&self.norm2.try_forward(&x)?)?)
+        } else {
+            let x = self.norm1.try_forward(&src.try_add(&self.try_self_attention(src, masks)?)?)?;
+            self.norm2.try_forward(&x.try_add(&self.try_feed_forward(&x)?)?)
+        }
+    }
+
+    /// Aplica a camada a `src` `[N, L, d_model]` com máscaras de auto-atenção
+    pub fn forward_with_masks(&self, src: &Tensor, masks: AttentionMasks) -> Tensor {
+        self.try_forward_with_masks(src, masks).expect("Error in TransformerEncoderLayer forward")
+    }
+}
+
+composite_module!(
+    TransformerEncoderLayer,
+    [self_attn, linear1, dropout, linear2, norm1, norm2, dropout1, dropout2],
+    |layer, input| layer.try_forward_with_masks(input, AttentionMasks::default())
+);
+
+/// Camada do decoder: auto-atenção, atenção sobre a memória do encoder e feedforward,
+/// cada uma com soma residual e LayerNorm
+pub struct TransformerDecoderLayer {
+    pub d_model: i64,
+    pub nhead: i64,
+    pub options: TransformerOptions,
+    self_attn: MultiheadAttention,
+    multihead_attn: MultiheadAttention,
+    linear1: Linear,
+    dropout: Dropout,
+    linear2: Linear,
+    norm1: LayerNorm,
+    norm2: LayerNorm,
+    norm3: LayerNorm,
+    dropout1: Dropout,
+    dropout2: Dropout,
+    dropout3: Dropout,
+    training: bool,
+}
+
+impl TransformerDecoderLayer {
+    pub fn try_new(d_model: i64, nhead: i64, options: TransformerOptions) -> Result<Self> {
+        Ok(TransformerDecoderLayer {
+            d_model,
+            nhead,
+            options,
+            self_attn: MultiheadAttention::try_new(d_model, nhead, options.dropout)?,
+            multihead_attn: MultiheadAttention::try_new(d_model, nhead, options.dropout)?,
+            linear1: try_linear(d_model, options.dim_feedforward)?,
+            dropout: Dropout::new(options.dropout),
+            linear2: try_linear(options.dim_feedforward, d_model)?,
+            norm1: try_layer_norm(d_model, &options)?,
+            norm2: try_layer_norm(d_model, &options)?,
+            norm3: try_layer_norm(d_model, &options)?,
+            dropout1: Dropout::new(options.dropout),
+            dropout2: Dropout::new(options.dropout),
+            dropout3: Dropout::new(options.dropout),
+            training: true,
+        })
+    }
+
+    pub fn new(d_model: i64, nhead: i64, options: TransformerOptions) -> Self {
+        TransformerDecoderLayer::try_new(d_model, nhead, options).expect("Error creating TransformerDecoderLayer")
+    }
+
+    fn try_self_attention(&self, x: &Tensor, masks: AttentionMasks) -> Result<Tensor> {
+        let (output, _) = self.self_attn.try_forward_with_masks(x, x, x, masks)?;
+        self.dropout1.try_forward(&output)
+    }
+
+    fn try_cross_attention(&self, x: &Tensor, memory: &Tensor, masks: AttentionMasks) -> Result<Tensor> {
+        let (output, _) = self.multihead_attn.try_forward_with_masks(x, memory, memory, masks)?;
+        self.dropout2.try_forward(&output)
+    }
+
+    fn try_feed_forward(&self, x: &Tensor) -> Result<Tensor> {
+        let hidden = self.dropout.try_forward(&(self.options.activation)(&self.linear1.try_forward(x)?)?)?;
+        self.dropout3.try_forward(&self.linear2.try_forward(&hidden)?)
+    }
+
+    /// Aplica a camada a `tgt` `[N, T, d_model]` atendendo à saída do encoder `memory`
+    /// `[N, S, d_model]`; `tgt_masks` vale para a auto-atenção (ex.: `Tensor::causal_mask`)
+    /// e `memory_masks` para a atenção sobre a memória
+    pub fn try_forward_with_memory(
+        &self,
+        tgt: &Tensor,
+        memory: &Tensor,
+        tgt_masks: AttentionMasks,
+        memory_masks: AttentionMasks,
+    ) -> Result<Tensor> {
+        if self.options.norm_first {
+            let x = tgt.try_add(&self.try_self_attention(&self.norm1.try_forward(tgt)?, tgt_masks)?)?;
+            let x = x.try_add(&self.try_cross_attention(&self.norm2.try_forward(&x)?, memory, memory_masks)?)?;
+            x.try_add(&self.try_feed_forward(&self.norm3.try_forward(&x)?)?)
+        } else {
+            let x = self.norm1.try_forward(&tgt.try_add(&self.try_self_attention(tgt, tgt_masks)?)?)?;
+            let x = self.norm2.try_forward(&x.try_add(&self.try_cross_attention(&x, memory, memory_masks)?)?)?;
+            self.norm3.try_forward(&x.try_add(&self.try_feed_forward(&x)?)?)
+        }
+    }
+
+    /// Aplica a camada a `tgt` `[N, T, d_model]` atendendo à saída do encoder `memory`
+    pub fn forward_with_memory(
+        &self,
+        tgt: &Tensor,
+        memory: &Tensor,
+        tgt_masks: AttentionMasks,
+        memory_masks: AttentionMasks,
+    ) -> Tensor {
+        self.try_forward_with_memory(tgt, memory, tgt_masks, memory_masks)
+            .expect("Error in TransformerDecoderLayer forward")
+    }
+}
+
+// Como `Module` só expõe parâmetros e modo: o forward precisa da memória do encoder
+composite_module!(
+    TransformerDecoderLayer,
+    [self_attn, multihead_attn, linear1, dropout, linear2, norm1, norm2, norm3, dropout1, dropout2, dropout3],
+    |_layer, _input| Err(CopperError::InvalidArgument(
+        "TransformerDecoderLayer precisa da memória do encoder; use forward_with_memory".to_string()
+    ))
+);
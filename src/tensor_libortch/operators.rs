@@ -1,83 +1,140 @@
+use crate::arith::impl_arithmetic_ops;
+use crate::error::{check_call, CopperError, Result};
+use crate::shape;
+use crate::tensor_libortch::ffi::{
+    TensorAdd,
+    TensorSub,
+    TensorMul,
+    TensorDiv,
+    TensorAddScalar,
+    TensorSubScalar,
+    TensorRSubScalar,
+    TensorMulScalar,
+    TensorDivScalar,
+    TensorRDivScalar,
+    TensorAddInPlace,
+    TensorSubInPlace,
+    TensorMulInPlace,
+    TensorDivInPlace,
+    TensorAddScalarInPlace,
+    TensorSubScalarInPlace,
+    TensorMulScalarInPlace,
+    TensorDivScalarInPlace,
+};
 use crate::tensor_libortch::tensor::Tensor;
-use std::ops::{Add, Sub, Mul, Div};
 
-impl Add for Tensor {
-    type Output = Tensor;
+impl Tensor {
+    /// Soma elemento a elemento, com broadcasting
+    pub fn try_add(&self, other: &Tensor) -> Result<Tensor> {
+        shape::check_broadcast("add", self.shape(), other.shape())?;
+        Tensor::from_ptr(unsafe { TensorAdd(self.ptr, other.ptr) }, "TensorAdd")
+    }
 
-    fn add(self, other: Tensor) -> Tensor {
-        if self.rows != other.rows || self.cols != other.cols {
-            panic!("Cannot add tensors with different dimensions!");
-        }
+    /// Subtração elemento a elemento, com broadcasting
+    pub fn try_sub(&self, other: &Tensor) -> Result<Tensor> {
+        shape::check_broadcast("sub", self.shape(), other.shape())?;
+        Tensor::from_ptr(unsafe { TensorSub(self.ptr, other.ptr) }, "TensorSub")
+    }
 
-        let result_values: Vec<f32> = self
-            .as_slice()
-            .iter()
-            .zip(other.as_slice().iter())
-            .map(|(a, b)| a + b)
-            .collect();
+    /// Multiplicação elemento a elemento, com broadcasting
+    pub fn try_mul(&self, other: &Tensor) -> Result<Tensor> {
+        shape::check_broadcast("mul", self.shape(), other.shape())?;
+        Tensor::from_ptr(unsafe { TensorMul(self.ptr, other.ptr) }, "TensorMul")
+    }
 
-        Tensor::from_values(&result_values, self.rows, self.cols)
+    /// Divisão elemento a elemento, com broadcasting (divisão por zero segue o IEEE 754)
+    pub fn try_div(&self, other: &Tensor) -> Result<Tensor> {
+        shape::check_broadcast("div", self.shape(), other.shape())?;
+        Tensor::from_ptr(unsafe { TensorDiv(self.ptr, other.ptr) }, "TensorDiv")
     }
-}
 
-impl Sub for Tensor {
-    type Output = Tensor;
+    /// Soma um escalar a cada elemento
+    pub fn try_add_scalar(&self, value: f32) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorAddScalar(self.ptr, value as f64) }, "TensorAddScalar")
+    }
 
-    fn sub(self, other: Tensor) -> Tensor {
-        if self.rows != other.rows || self.cols != other.cols {
-            panic!("Cannot subtract tensors with different dimensions!");
-        }
+    /// Subtrai um escalar de cada elemento
+    pub fn try_sub_scalar(&self, value: f32) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorSubScalar(self.ptr, value as f64) }, "TensorSubScalar")
+    }
 
-        let result_values: Vec<f32> = self
-            .as_slice()
-            .iter()
-            .zip(other.as_slice().iter())
-            .map(|(a, b)| a - b)
-            .collect();
+    /// Calcula `value - x` para cada elemento
+    pub fn try_rsub_scalar(&self, value: f32) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorRSubScalar(self.ptr, value as f64) }, "TensorRSubScalar")
+    }
 
-        Tensor::from_values(&result_values, self.rows, self.cols)
+    /// Multiplica cada elemento por um escalar
+    pub fn try_mul_scalar(&self, value: f32) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorMulScalar(self.ptr, value as f64) }, "TensorMulScalar")
     }
-}
 
-impl Mul for Tensor {
-    type Output = Tensor;
+    /// Divide cada elemento por um escalar
+    pub fn try_div_scalar(&self, value: f32) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorDivScalar(self.ptr, value as f64) }, "TensorDivScalar")
+    }
 
-    fn mul(self, other: Tensor) -> Tensor {
-        if self.rows != other.rows || self.cols != other.cols {
-            panic!("Cannot multiply tensors with different dimensions!");
+    /// Calcula `value / x` para cada elemento
+    pub fn try_rdiv_scalar(&self, value: f32) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorRDivScalar(self.ptr, value as f64) }, "TensorRDivScalar")
+    }
+
+    // ==================== IN-PLACE ====================
+
+    /// O resultado de uma operação no lugar precisa manter a forma de `self`
+    fn check_in_place(&self, op: &'static str, other: &Tensor) -> Result<()> {
+        if shape::check_broadcast(op, self.shape(), other.shape())? != self.shape() {
+            return Err(CopperError::ShapeMismatch {
+                op,
+                left: self.shape().to_vec(),
+                right: other.shape().to_vec(),
+            });
         }
+        Ok(())
+    }
 
-        let result_values: Vec<f32> = self
-            .as_slice()
-            .iter()
-            .zip(other.as_slice().iter())
-            .map(|(a, b)| a * b)
-            .collect();
+    /// Soma `other` a este tensor sem realocar (`+=`)
+    pub fn try_add_in_place(&mut self, other: &Tensor) -> Result<()> {
+        self.check_in_place("add_in_place", other)?;
+        check_call("TensorAddInPlace", || unsafe { TensorAddInPlace(self.ptr, other.ptr) })
+    }
 
-        Tensor::from_values(&result_values, self.rows, self.cols)
+    /// Subtrai `other` deste tensor sem realocar (`-=`)
+    pub fn try_sub_in_place(&mut self, other: &Tensor) -> Result<()> {
+        self.check_in_place("sub_in_place", other)?;
+        check_call("TensorSubInPlace", || unsafe { TensorSubInPlace(self.ptr, other.ptr) })
     }
-}
 
-impl Div for Tensor {
-    type Output = Tensor;
+    /// Multiplica este tensor por `other` sem realocar (`*=`)
+    pub fn try_mul_in_place(&mut self, other: &Tensor) -> Result<()> {
+        self.check_in_place("mul_in_place", other)?;
+        check_call("TensorMulInPlace", || unsafe { TensorMulInPlace(self.ptr, other.ptr) })
+    }
 
-    fn div(self, other: Tensor) -> Tensor {
-        if self.rows != other.rows || self.cols != other.cols {
-            panic!("Cannot divide tensors with different dimensions!");
-        }
+    /// Divide este tensor por `other` sem realocar (`/=`)
+    pub fn try_div_in_place(&mut self, other: &Tensor) -> Result<()> {
+        self.check_in_place("div_in_place", other)?;
+        check_call("TensorDivInPlace", || unsafe { TensorDivInPlace(self.ptr, other.ptr) })
+    }
+
+    /// Soma um escalar a cada elemento, no lugar
+    pub fn try_add_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        check_call("TensorAddScalarInPlace", || unsafe { TensorAddScalarInPlace(self.ptr, value as f64) })
+    }
+
+    /// Subtrai um escalar de cada elemento, no lugar
+    pub fn try_sub_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        check_call("TensorSubScalarInPlace", || unsafe { TensorSubScalarInPlace(self.ptr, value as f64) })
+    }
+
+    /// Multiplica cada elemento por um escalar, no lugar
+    pub fn try_mul_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        check_call("TensorMulScalarInPlace", || unsafe { TensorMulScalarInPlace(self.ptr, value as f64) })
+    }
+
+    /// Divide cada elemento por um escalar, no lugar
+    pub fn try_div_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        check_call("TensorDivScalarInPlace", || unsafe { TensorDivScalarInPlace(self.ptr, value as f64) })
+    }
+}
 
-        let result_values: Vec<f32> = self
-            .as_slice()
-            .iter()
-            .zip(other.as_slice().iter())
-            .map(|(a, b)| {
-                if *b == 0.0 {
-                    panic!("Division by zero in tensor!");
-                }
-                a / b
-            })
-            .collect();
-
-        Tensor::from_values(&result_values, self.rows, self.cols)
-    }
-}
\ No newline at end of file
+impl_arithmetic_ops!(Tensor);
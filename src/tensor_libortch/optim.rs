@@ -0,0 +1,535 @@
+// Optimizers
+// Otimizadores do LibTorch sobre listas arbitrárias de parâmetros, organizadas em grupos
+// com taxas de aprendizado próprias, como os `param_groups` de torch.optim.
+
+use crate::error::{check_call, check_ptr, CopperError, Result};
+use crate::tensor_libortch::autograd::function::run_callback;
+use crate::tensor_libortch::ffi::{
+    CreateSGD,
+    CreateAdam,
+    CreateRMSprop,
+    CreateAdagrad,
+    CreateAdadelta,
+    CreateLBFGS,
+    OptimizerStep,
+    OptimizerStepClosure,
+    OptimizerZeroGrad,
+    OptimizerNumGroups,
+    OptimizerGetLr,
+    OptimizerSetLr,
+    FreeOptimizer,
+    TensorListPush,
+};
+use crate::tensor_libortch::nn::Module;
+use crate::tensor_libortch::tensor::Tensor;
+use libc::{c_int, c_void};
+
+/// Conjunto de parâmetros otimizados juntos; sem `lr` próprio usa o do otimizador.
+/// Não implementa `Clone`: `Tensor::clone` copia os dados, e um grupo clonado treinaria
+/// cópias desligadas do módulo
+pub struct ParamGroup {
+    pub params: Vec<Tensor>,
+    pub lr: Option<f64>,
+}
+
+impl ParamGroup {
+    pub fn new(params: Vec<Tensor>) -> Self {
+        ParamGroup { params, lr: None }
+    }
+
+    /// Todos os parâmetros de `module` (uma camada ou uma rede inteira)
+    pub fn try_from_module<M: Module + ?Sized>(module: &M) -> Result<Self> {
+        Ok(ParamGroup::new(module.try_parameters()?))
+    }
+
+    /// Todos os parâmetros de `module` (uma camada ou uma rede inteira)
+    pub fn from_module<M: Module + ?Sized>(module: &M) -> Self {
+        ParamGroup::try_from_module(module).expect("Error reading module parameters")
+    }
+
+    pub fn lr(mut self, lr: f64) -> Self {
+        self.lr = Some(lr);
+        self
+    }
+}
+
+/// Hiperparâmetros do SGD
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SgdOptions {
+    pub momentum: f64,
+    pub dampening: f64,
+    /// Penalidade L2 somada ao gradiente
+    pub weight_decay: f64,
+    /// Momento de Nesterov (exige `momentum > 0` e `dampening = 0`)
+    pub nesterov: bool,
+}
+
+impl SgdOptions {
+    pub fn momentum(mut self, momentum: f64) -> Self {
+        self.momentum = momentum;
+        self
+    }
+
+    pub fn dampening(mut self, dampening: f64) -> Self {
+        self.dampening = dampening;
+        self
+    }
+
+    pub fn weight_decay(mut self, weight_decay: f64) -> Self {
+        self.weight_decay = weight_decay;
+        self
+    }
+
+    pub fn nesterov(mut self, nesterov: bool) -> Self {
+        self.nesterov = nesterov;
+        self
+    }
+}
+
+/// Hiperparâmetros do Adam e do AdamW
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdamOptions {
+    /// Decaimento das médias móveis do gradiente e do seu quadrado
+    pub betas: (f64, f64),
+    pub eps: f64,
+    /// No Adam é uma penalidade L2 no gradiente; no AdamW é aplicado direto nos pesos
+    pub weight_decay: f64,
+    pub amsgrad: bool,
+}
+
+impl Default for AdamOptions {
+    fn default() -> Self {
+        AdamOptions { betas: (0.9, 0.999), eps: 1e-8, weight_decay: 0.0, amsgrad: false }
+    }
+}
+
+impl AdamOptions {
+    /// Padrões do PyTorch para AdamW: `weight_decay = 1e-2`
+    pub fn adamw() -> Self {
+        AdamOptions { weight_decay: 1e-2, ..AdamOptions::default() }
+    }
+
+    pub fn betas(mut self, beta1: f64, beta2: f64) -> Self {
+        self.betas = (beta1, beta2);
+        self
+    }
+
+    pub fn eps(mut self, eps: f64) -> Self {
+        self.eps = eps;
+        self
+    }
+
+    pub fn weight_decay(mut self, weight_decay: f64) -> Self {
+        self.weight_decay = weight_decay;
+        self
+    }
+
+    pub fn amsgrad(mut self, amsgrad: bool) -> Self {
+        self.amsgrad = amsgrad;
+        self
+    }
+}
+
+/// Hiperparâmetros do RMSprop
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RmsPropOptions {
+    /// Decaimento da média móvel do quadrado do gradiente
+    pub alpha: f64,
+    pub eps: f64,
+    pub weight_decay: f64,
+    pub momentum: f64,
+    /// Normaliza pela variância estimada em vez do segundo momento
+    pub centered: bool,
+}
+
+impl Default for RmsPropOptions {
+    fn default() -> Self {
+        RmsPropOptions { alpha: 0.99, eps: 1e-8, weight_decay: 0.0, momentum: 0.0, centered: false }
+    }
+}
+
+impl RmsPropOptions {
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn eps(mut self, eps: f64) -> Self {
+        self.eps = eps;
+        self
+    }
+
+    pub fn weight_decay(mut self, weight_decay: f64) -> Self {
+        self.weight_decay = weight_decay;
+        self
+    }
+
+    pub fn momentum(mut self, momentum: f64) -> Self {
+        self.momentum = momentum;
+        self
+    }
+
+    pub fn centered(mut self, centered: bool) -> Self {
+        self.centered = centered;
+        self
+    }
+}
+
+/// Hiperparâmetros do Adagrad
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdagradOptions {
+    pub lr_decay: f64,
+    pub weight_decay: f64,
+    pub initial_accumulator_value: f64,
+    pub eps: f64,
+}
+
+impl Default for AdagradOptions {
+    fn default() -> Self {
+        AdagradOptions { lr_decay: 0.0, weight_decay: 0.0, initial_accumulator_value: 0.0, eps: 1e-10 }
+    }
+}
+
+impl AdagradOptions {
+    pub fn lr_decay(mut self, lr_decay: f64) -> Self {
+        self.lr_decay = lr_decay;
+        self
+    }
+
+    pub fn weight_decay(mut self, weight_decay: f64) -> Self {
+        self.weight_decay = weight_decay;
+        self
+    }
+
+    pub fn initial_accumulator_value(mut self, initial_accumulator_value: f64) -> Self {
+        self.initial_accumulator_value = initial_accumulator_value;
+        self
+    }
+
+    pub fn eps(mut self, eps: f64) -> Self {
+        self.eps = eps;
+        self
+    }
+}
+
+/// Hiperparâmetros do Adadelta (o PyTorch usa `lr = 1.0`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdadeltaOptions {
+    /// Decaimento das médias móveis do gradiente e da atualização
+    pub rho: f64,
+    pub eps: f64,
+    pub weight_decay: f64,
+}
+
+impl Default for AdadeltaOptions {
+    fn default() -> Self {
+        AdadeltaOptions { rho: 0.9, eps: 1e-6, weight_decay: 0.0 }
+    }
+}
+
+impl AdadeltaOptions {
+    pub fn rho(mut self, rho: f64) -> Self {
+        self.rho = rho;
+        self
+    }
+
+    pub fn eps(mut self, eps: f64) -> Self {
+        self.eps = eps;
+        self
+    }
+
+    pub fn weight_decay(mut self, weight_decay: f64) -> Self {
+        self.weight_decay = weight_decay;
+        self
+    }
+}
+
+/// Hiperparâmetros do L-BFGS (o PyTorch usa `lr = 1.0`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LbfgsOptions {
+    /// Iterações por chamada de `step_with_closure`
+    pub max_iter: i64,
+    /// Avaliações da closure por passo; `None` usa `max_iter · 5/4`
+    pub max_eval: Option<i64>,
+    pub tolerance_grad: f64,
+    pub tolerance_change: f64,
+    pub history_size: i64,
+    /// Busca linear com as condições fortes de Wolfe; sem ela o passo é fixo
+    pub strong_wolfe: bool,
+}
+
+impl Default for LbfgsOptions {
+    fn default() -> Self {
+        LbfgsOptions {
+            max_iter: 20,
+            max_eval: None,
+            tolerance_grad: 1e-7,
+            tolerance_change: 1e-9,
+            history_size: 100,
+            strong_wolfe: false,
+        }
+    }
+}
+
+impl LbfgsOptions {
+    pub fn max_iter(mut self, max_iter: i64) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    pub fn max_eval(mut self, max_eval: i64) -> Self {
+        self.max_eval = Some(max_eval);
+        self
+    }
+
+    pub fn tolerance_grad(mut self, tolerance_grad: f64) -> Self {
+        self.tolerance_grad = tolerance_grad;
+        self
+    }
+
+    pub fn tolerance_change(mut self, tolerance_change: f64) -> Self {
+        self.tolerance_change = tolerance_change;
+        self
+    }
+
+    pub fn history_size(mut self, history_size: i64) -> Self {
+        self.history_size = history_size;
+        self
+    }
+
+    pub fn strong_wolfe(mut self, strong_wolfe: bool) -> Self {
+        self.strong_wolfe = strong_wolfe;
+        self
+    }
+}
+
+/// Algoritmo de otimização e seus hiperparâmetros
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Algorithm {
+    Sgd(SgdOptions),
+    Adam(AdamOptions),
+    /// Adam com weight decay desacoplado do gradiente
+    AdamW(AdamOptions),
+    RmsProp(RmsPropOptions),
+    Adagrad(AdagradOptions),
+    Adadelta(AdadeltaOptions),
+    /// Só avança com `step_with_closure` e aceita um único grupo de parâmetros
+    Lbfgs(LbfgsOptions),
+}
+
+impl Algorithm {
+    fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Sgd(_) => "CreateSGD",
+            Algorithm::Adam(_) | Algorithm::AdamW(_) => "CreateAdam",
+            Algorithm::RmsProp(_) => "CreateRMSprop",
+            Algorithm::Adagrad(_) => "CreateAdagrad",
+            Algorithm::Adadelta(_) => "CreateAdadelta",
+            Algorithm::Lbfgs(_) => "CreateLBFGS",
+        }
+    }
+}
+
+pub struct Optimizer {
+    pub ptr: *mut libc::c_void,
+}
+
+impl Optimizer {
+    /// Cria o otimizador sobre `groups`; grupos sem `lr` próprio usam `lr`
+    pub fn try_new(groups: Vec<ParamGroup>, lr: f64, algorithm: Algorithm) -> Result<Self> {
+        if groups.iter().all(|group| group.params.is_empty()) {
+            return Err(CopperError::InvalidArgument(
+                "otimizador criado sem parâmetros".to_string(),
+            ));
+        }
+        if matches!(algorithm, Algorithm::Lbfgs(_)) && groups.len() > 1 {
+            return Err(CopperError::InvalidArgument(format!(
+                "LBFGS aceita um único grupo de parâmetros, recebeu {}",
+                groups.len()
+            )));
+        }
+        let ptrs: Vec<*mut c_void> = groups.iter().flat_map(|group| group.params.iter().map(|p| p.ptr)).collect();
+        let sizes: Vec<c_int> = groups.iter().map(|group| group.params.len() as c_int).collect();
+        let lrs: Vec<f64> = groups.iter().map(|group| group.lr.unwrap_or(lr)).collect();
+        let (params, sizes, lrs, count) = (ptrs.as_ptr(), sizes.as_ptr(), lrs.as_ptr(), groups.len() as c_int);
+        let ptr = unsafe {
+            match algorithm {
+                Algorithm::Sgd(o) => {
+                    CreateSGD(params, sizes, lrs, count, lr, o.momentum, o.dampening, o.weight_decay, o.nesterov)
+                }
+                Algorithm::Adam(o) | Algorithm::AdamW(o) => CreateAdam(
+                    params,
+                    sizes,
+                    lrs,
+                    count,
+                    lr,
+                    o.betas.0,
+                    o.betas.1,
+                    o.eps,
+                    o.weight_decay,
+                    o.amsgrad,
+                    matches!(algorithm, Algorithm::AdamW(_)),
+                ),
+                Algorithm::RmsProp(o) => {
+                    CreateRMSprop(params, sizes, lrs, count, lr, o.alpha, o.eps, o.weight_decay, o.momentum, o.centered)
+                }
+                Algorithm::Adagrad(o) => CreateAdagrad(
+                    params,
+                    sizes,
+                    lrs,
+                    count,
+                    lr,
+                    o.lr_decay,
+                    o.weight_decay,
+                    o.initial_accumulator_value,
+                    o.eps,
+                ),
+                Algorithm::Adadelta(o) => CreateAdadelta(params, sizes, lrs, count, lr, o.rho, o.eps, o.weight_decay),
+                Algorithm::Lbfgs(o) => CreateLBFGS(
+                    params,
+                    sizes,
+                    lrs,
+                    count,
+                    lr,
+                    o.max_iter,
+                    o.max_eval.unwrap_or(-1),
+                    o.tolerance_grad,
+                    o.tolerance_change,
+                    o.history_size,
+                    o.strong_wolfe,
+                ),
+            }
+        };
+        Ok(Optimizer { ptr: check_ptr(ptr, algorithm.name())? })
+    }
+
+    /// Cria o otimizador sobre `groups`; grupos sem `lr` próprio usam `lr`
+    pub fn new(groups: Vec<ParamGroup>, lr: f64, algorithm: Algorithm) -> Self {
+        Optimizer::try_new(groups, lr, algorithm).expect("Error creating optimizer")
+    }
+
+    /// Otimizador sobre todos os parâmetros de `module`, num único grupo
+    pub fn try_for_module<M: Module + ?Sized>(module: &M, lr: f64, algorithm: Algorithm) -> Result<Self> {
+        Optimizer::try_new(vec![ParamGroup::try_from_module(module)?], lr, algorithm)
+    }
+
+    /// Otimizador sobre todos os parâmetros de `module`, num único grupo
+    pub fn for_module<M: Module + ?Sized>(module: &M, lr: f64, algorithm: Algorithm) -> Self {
+        Optimizer::try_for_module(module, lr, algorithm).expect("Error creating optimizer")
+    }
+
+    /// SGD sobre todos os parâmetros de `module` (uma camada ou uma rede inteira)
+    pub fn try_sgd<M: Module + ?Sized>(module: &M, lr: f32) -> Result<Self> {
+        Optimizer::try_for_module(module, lr as f64, Algorithm::Sgd(SgdOptions::default()))
+    }
+
+    /// SGD sobre todos os parâmetros de `module` (uma camada ou uma rede inteira)
+    pub fn sgd<M: Module + ?Sized>(module: &M, lr: f32) -> Self {
+        Optimizer::try_sgd(module, lr).expect("Error creating SGD optimizer")
+    }
+
+    /// Adam sobre todos os parâmetros de `module` (uma camada ou uma rede inteira)
+    pub fn try_adam<M: Module + ?Sized>(module: &M, lr: f32) -> Result<Self> {
+        Optimizer::try_for_module(module, lr as f64, Algorithm::Adam(AdamOptions::default()))
+    }
+
+    /// Adam sobre todos os parâmetros de `module` (uma camada ou uma rede inteira)
+    pub fn adam<M: Module + ?Sized>(module: &M, lr: f32) -> Self {
+        Optimizer::try_adam(module, lr).expect("Error creating Adam optimizer")
+    }
+
+    pub fn try_step(&self) -> Result<()> {
+        check_call("OptimizerStep", || unsafe { OptimizerStep(self.ptr) })
+    }
+
+    pub fn step(&self) {
+        self.try_step().expect("Error in optimizer step")
+    }
+
+    /// Passo que reavalia a perda quantas vezes o algoritmo precisar (obrigatório no LBFGS)
+    ///
+    /// `closure` só calcula a perda: os gradientes são zerados antes e o backward é
+    /// feito depois de cada avaliação. Devolve a perda da primeira avaliação.
+    pub fn try_step_with_closure<F: FnMut() -> Result<Tensor>>(&self, mut closure: F) -> Result<Tensor> {
+        let state = &mut closure as *mut F as *mut c_void;
+        let loss = unsafe { OptimizerStepClosure(self.ptr, closure_trampoline::<F>, state) };
+        Tensor::from_ptr(loss, "OptimizerStepClosure")
+    }
+
+    /// Passo que reavalia a perda quantas vezes o algoritmo precisar (obrigatório no LBFGS)
+    pub fn step_with_closure<F: FnMut() -> Result<Tensor>>(&self, closure: F) -> Tensor {
+        self.try_step_with_closure(closure).expect("Error in optimizer step")
+    }
+
+    pub fn try_zero_grad(&self) -> Result<()> {
+        check_call("OptimizerZeroGrad", || unsafe { OptimizerZeroGrad(self.ptr) })
+    }
+
+    pub fn zero_grad(&self) {
+        self.try_zero_grad().expect("Error in optimizer zero_grad")
+    }
+
+    /// Número de grupos de parâmetros
+    pub fn try_num_param_groups(&self) -> Result<usize> {
+        check_call("OptimizerNumGroups", || unsafe { OptimizerNumGroups(self.ptr) }).map(|count| count as usize)
+    }
+
+    /// Número de grupos de parâmetros
+    pub fn num_param_groups(&self) -> usize {
+        self.try_num_param_groups().expect("Error reading optimizer parameter groups")
+    }
+
+    /// Taxa de aprendizado do grupo `group`
+    pub fn try_group_lr(&self, group: usize) -> Result<f64> {
+        check_call("OptimizerGetLr", || unsafe { OptimizerGetLr(self.ptr, group as c_int) })
+    }
+
+    /// Taxa de aprendizado do grupo `group`
+    pub fn group_lr(&self, group: usize) -> f64 {
+        self.try_group_lr(group).expect("Error reading learning rate")
+    }
+
+    /// Taxa de aprendizado do primeiro grupo
+    pub fn try_lr(&self) -> Result<f64> {
+        self.try_group_lr(0)
+    }
+
+    /// Taxa de aprendizado do primeiro grupo
+    pub fn lr(&self) -> f64 {
+        self.try_lr().expect("Error reading learning rate")
+    }
+
+    /// Troca a taxa de aprendizado de todos os grupos (ex.: num agendamento de lr)
+    pub fn try_set_lr(&mut self, lr: f64) -> Result<()> {
+        check_call("OptimizerSetLr", || unsafe { OptimizerSetLr(self.ptr, -1, lr) })
+    }
+
+    /// Troca a taxa de aprendizado de todos os grupos (ex.: num agendamento de lr)
+    pub fn set_lr(&mut self, lr: f64) {
+        self.try_set_lr(lr).expect("Error setting learning rate")
+    }
+
+    /// Troca a taxa de aprendizado do grupo `group`
+    pub fn try_set_group_lr(&mut self, group: usize, lr: f64) -> Result<()> {
+        check_call("OptimizerSetLr", || unsafe { OptimizerSetLr(self.ptr, group as c_int, lr) })
+    }
+
+    /// Troca a taxa de aprendizado do grupo `group`
+    pub fn set_group_lr(&mut self, group: usize, lr: f64) {
+        self.try_set_group_lr(group, lr).expect("Error setting learning rate")
+    }
+}
+
+impl Drop for Optimizer {
+    fn drop(&mut self) {
+        unsafe { FreeOptimizer(self.ptr) };
+    }
+}
+
+extern "C" fn closure_trampoline<F: FnMut() -> Result<Tensor>>(state: *mut c_void, loss: *mut c_void) -> c_int {
+    run_callback("Optimizer::step_with_closure", || {
+        let closure = unsafe { &mut *(state as *mut F) };
+        let value = closure()?;
+        check_call("TensorListPush", || unsafe { TensorListPush(loss, value.ptr) })
+    })
+}
@@ -1,19 +1,40 @@
+use crate::dtype::{with_element_type, DType, Element};
+use crate::error::{check_call, check_ptr, CopperError, Result};
+use crate::shape;
 use crate::tensor_libortch::ffi::{
-    CreateMatrixTensor, 
-    CreateTensorOnes, 
+    CreateTensorFromData,
+    CreateTensorOnes,
     CreateTensorRand,
+    ManualSeed,
     CreateTensorZeros,
     CreateTensorRandn,
     CreateTensorEye,
     TensorZerosLike,
     TensorOnesLike,
-    TensorData, 
-    TensorRows, 
-    TensorCols, 
+    TensorData,
+    TensorDType,
+    TensorToDType,
+    TensorDim,
+    TensorSizes,
+    TensorReshape,
+    TensorTranspose,
+    TensorMatmul,
+    TensorBmm,
     FreeTensor,
     TensorReLU,
     TensorSigmoid,
     TensorTanh,
+    TensorSoftmax,
+    TensorLogSoftmax,
+    TensorGelu,
+    TensorSilu,
+    TensorLeakyRelu,
+    TensorElu,
+    TensorSelu,
+    TensorSoftplus,
+    TensorMish,
+    TensorHardtanh,
+    TensorRelu6,
     TensorSin,
     TensorCos,
     TensorExp,
@@ -23,467 +44,1015 @@ use crate::tensor_libortch::ffi::{
     TensorPow,
     TensorStd,
     TensorVar,
-    TensorArgmax,
-    TensorArgmin,
-    CreateLinear,
-    LinearForward,
-    MSELoss,
-    CrossEntropyLoss,
-    CreateSGD,
-    CreateAdam,
+    TensorProd,
+    TensorLogsumexp,
+    TensorNorm,
+    TensorAny,
+    TensorAll,
+    TensorSumDim,
+    TensorMeanDim,
+    TensorMaxDim,
+    TensorMinDim,
+    TensorStdDim,
+    TensorVarDim,
+    TensorArgmaxDim,
+    TensorArgminDim,
+    TensorProdDim,
+    TensorLogsumexpDim,
+    TensorNormDim,
+    TensorAnyDim,
+    TensorAllDim,
     Backward,
-    OptimizerStep,
-    OptimizerZeroGrad,
-    FreeOptimizer,
 };
 
+// O otimizador vive em `optim`, mas continua acessível por `tensor_libortch::tensor`
+pub use crate::tensor_libortch::optim::Optimizer;
+
+// As camadas vivem em `nn`, mas continuam acessíveis por `tensor_libortch::tensor`
+pub use crate::tensor_libortch::nn::{
+    Linear,
+    Module,
+    Sequential,
+    Conv,
+    Conv1d,
+    Conv2d,
+    Conv3d,
+    ConvOptions,
+    ConvTranspose,
+    ConvTranspose1d,
+    ConvTranspose2d,
+    ConvTranspose3d,
+    MaxPool,
+    MaxPool1d,
+    MaxPool2d,
+    MaxPool3d,
+    AvgPool,
+    AvgPool1d,
+    AvgPool2d,
+    AvgPool3d,
+    AdaptiveAvgPool,
+    AdaptiveAvgPool1d,
+    AdaptiveAvgPool2d,
+    AdaptiveAvgPool3d,
+    Rnn,
+    Lstm,
+    Gru,
+    RnnOptions,
+    Nonlinearity,
+    Embedding,
+    EmbeddingBag,
+    EmbeddingBagMode,
+    EmbeddingOptions,
+    BatchNorm,
+    BatchNorm1d,
+    BatchNorm2d,
+    BatchNorm3d,
+    InstanceNorm,
+    InstanceNorm1d,
+    InstanceNorm2d,
+    InstanceNorm3d,
+    LayerNorm,
+    GroupNorm,
+    NormOptions,
+    Dropout,
+    Dropout2d,
+    AlphaDropout,
+    AttentionMasks,
+    MultiheadAttention,
+    TransformerEncoderLayer,
+    TransformerDecoderLayer,
+    TransformerOptions,
+    Reduction,
+};
+
+/// Assinatura comum das reduções ao longo de uma dimensão no shim
+type ReduceDimFn = unsafe extern "C" fn(*mut libc::c_void, i64, bool) -> *mut libc::c_void;
+
 pub struct Tensor {
     pub ptr: *mut libc::c_void,
-    pub rows: i32,
-    pub cols: i32,
+    shape: Vec<i64>,
+    dtype: DType,
 }
 
 impl Tensor {
-    pub fn ones(rows: i32, cols: i32) -> Self {
-        let ptr: *mut libc::c_void = unsafe { CreateTensorOnes(rows, cols) };
-        if ptr.is_null() {
-            panic!("Error creating tensor");
-        }
+    /// Assume a posse de um ponteiro devolvido pelo shim
+    pub(crate) fn from_ptr(ptr: *mut libc::c_void, op: &'static str) -> Result<Tensor> {
+        let ptr = check_ptr(ptr, op)?;
+        // A partir daqui o Drop libera o ponteiro mesmo se a leitura da forma falhar
+        let mut tensor = Tensor { ptr, shape: Vec::new(), dtype: DType::Float32 };
+        let ndim = check_call("TensorDim", || unsafe { TensorDim(ptr) })?;
+        let mut sizes = vec![0i64; ndim.max(0) as usize];
+        check_call("TensorSizes", || unsafe { TensorSizes(ptr, sizes.as_mut_ptr()) })?;
+        tensor.shape = sizes;
+        let code = check_call("TensorDType", || unsafe { TensorDType(ptr) })?;
+        tensor.dtype = DType::from_code(code)?;
+        Ok(tensor)
+    }
 
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
+    /// Forma do tensor (vazia para escalares 0-D)
+    pub fn shape(&self) -> &[i64] {
+        &self.shape
+    }
 
-        Tensor { ptr, rows, cols }
+    /// Tipo dos elementos
+    pub fn dtype(&self) -> DType {
+        self.dtype
     }
 
-    pub fn from_values(values: &[f32], rows: i32, cols: i32) -> Self {
-        let ptr: *mut libc::c_void = unsafe { CreateMatrixTensor(values.as_ptr(), rows, cols) };
-        if ptr.is_null() {
-            panic!("Error creating tensor from values");
-        }
+    /// Número de dimensões
+    pub fn dim(&self) -> usize {
+        self.shape.len()
+    }
 
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
+    /// Número total de elementos
+    pub fn numel(&self) -> usize {
+        shape::numel(&self.shape)
+    }
 
-        Tensor { ptr, rows, cols }
+    /// Tamanho de uma dimensão; índices negativos contam a partir do fim
+    pub fn size(&self, dim: i64) -> Result<i64> {
+        Ok(self.shape[shape::normalize_dim(dim, &self.shape)?])
     }
 
-    pub fn rand(rows: i32, cols: i32) -> Self {
-        let ptr = unsafe { CreateTensorRand(rows, cols) };
-        if ptr.is_null() {
-            panic!("Error creating tensor");
-        }
+    pub fn try_ones(shape: &[i64]) -> Result<Self> {
+        Tensor::try_ones_dtype(shape, DType::Float32)
+    }
 
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
+    pub fn ones(shape: &[i64]) -> Self {
+        Tensor::try_ones(shape).expect("Error creating tensor")
+    }
 
-        Tensor { ptr, rows, cols }
+    /// Cria tensor de uns com o dtype informado
+    pub fn try_ones_dtype(shape: &[i64], dtype: DType) -> Result<Self> {
+        let ptr = unsafe { CreateTensorOnes(shape.as_ptr(), shape.len() as i32, dtype.code()) };
+        Tensor::from_ptr(ptr, "CreateTensorOnes")
     }
 
-    pub fn as_slice(&self) -> &[f32] {
-        let total = (self.rows * self.cols) as usize;
-        unsafe {
-            let data_ptr = TensorData(self.ptr);
-            std::slice::from_raw_parts(data_ptr, total)
+    /// Cria tensor de uns com o dtype informado
+    pub fn ones_dtype(shape: &[i64], dtype: DType) -> Self {
+        Tensor::try_ones_dtype(shape, dtype).expect("Error creating tensor")
+    }
+
+    pub fn try_from_values(values: &[f32], shape: &[i64]) -> Result<Self> {
+        Tensor::try_from_slice(values, shape)
+    }
+
+    pub fn from_values(values: &[f32], shape: &[i64]) -> Self {
+        Tensor::try_from_values(values, shape).expect("Error creating tensor from values")
+    }
+
+    /// Cria um tensor com qualquer tipo de elemento suportado (f32, f64, i64, i32, u8, bool)
+    pub fn try_from_slice<T: Element>(values: &[T], shape: &[i64]) -> Result<Self> {
+        shape::check_values(shape, values.len())?;
+        let ptr = unsafe {
+            CreateTensorFromData(
+                values.as_ptr() as *const libc::c_void,
+                shape.as_ptr(),
+                shape.len() as i32,
+                T::DTYPE.code(),
+            )
+        };
+        Tensor::from_ptr(ptr, "CreateTensorFromData")
+    }
+
+    /// Cria um tensor com qualquer tipo de elemento suportado (f32, f64, i64, i32, u8, bool)
+    pub fn from_slice<T: Element>(values: &[T], shape: &[i64]) -> Self {
+        Tensor::try_from_slice(values, shape).expect("Error creating tensor from values")
+    }
+
+    /// Cria um tensor escalar (0-D)
+    pub fn try_scalar(value: f32) -> Result<Self> {
+        Tensor::try_from_values(&[value], &[])
+    }
+
+    /// Cria um tensor escalar (0-D)
+    pub fn scalar(value: f32) -> Self {
+        Tensor::try_scalar(value).expect("Error creating scalar tensor")
+    }
+
+    pub fn try_rand(shape: &[i64]) -> Result<Self> {
+        let ptr = unsafe { CreateTensorRand(shape.as_ptr(), shape.len() as i32, DType::Float32.code()) };
+        Tensor::from_ptr(ptr, "CreateTensorRand")
+    }
+
+    pub fn rand(shape: &[i64]) -> Self {
+        Tensor::try_rand(shape).expect("Error creating tensor")
+    }
+
+    /// Fixa a semente do gerador do LibTorch (rand, randn, dropout)
+    pub fn try_manual_seed(seed: u64) -> Result<()> {
+        check_call("ManualSeed", || unsafe { ManualSeed(seed) })
+    }
+
+    /// Fixa a semente do gerador do LibTorch (rand, randn, dropout)
+    pub fn manual_seed(seed: u64) {
+        Tensor::try_manual_seed(seed).expect("Error setting random seed")
+    }
+
+    /// Copia os elementos para um `Vec<T>`; falha se `T` não corresponder ao dtype do tensor.
+    /// Devolve uma cópia porque views, parâmetros e gradientes compartilham o storage e
+    /// podem ser alterados no lugar enquanto o resultado estiver vivo
+    pub fn try_data_as<T: Element>(&self) -> Result<Vec<T>> {
+        if self.dtype != T::DTYPE {
+            return Err(CopperError::DTypeMismatch {
+                op: "data_as",
+                expected: T::DTYPE,
+                found: self.dtype,
+            });
         }
+        let total = self.numel();
+        if total == 0 {
+            return Ok(Vec::new());
+        }
+        self.try_with_contiguous(|t| {
+            let data_ptr = check_call("TensorData", || unsafe { TensorData(t.ptr) })?;
+            if data_ptr.is_null() {
+                return Err(crate::error::backend_error("TensorData"));
+            }
+            Ok(unsafe { std::slice::from_raw_parts(data_ptr as *const T, total) }.to_vec())
+        })
+    }
+
+    /// Copia os elementos para um `Vec<T>`; falha se `T` não corresponder ao dtype do tensor
+    pub fn data_as<T: Element>(&self) -> Vec<T> {
+        self.try_data_as().expect("Error reading tensor data")
+    }
+
+    /// Copia os elementos f32 para um `Vec<f32>`
+    pub fn try_as_slice(&self) -> Result<Vec<f32>> {
+        self.try_data_as::<f32>()
+    }
+
+    /// Copia os elementos f32 para um `Vec<f32>`
+    pub fn as_slice(&self) -> Vec<f32> {
+        self.try_as_slice().expect("Error reading tensor data")
+    }
+
+    /// Converte os elementos para outro dtype, sempre devolvendo uma cópia
+    pub fn try_to_dtype(&self, dtype: DType) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorToDType(self.ptr, dtype.code()) }, "TensorToDType")
+    }
+
+    /// Converte os elementos para outro dtype, sempre devolvendo uma cópia
+    pub fn to_dtype(&self, dtype: DType) -> Tensor {
+        self.try_to_dtype(dtype).expect("Error converting tensor dtype")
     }
 
     pub fn print(&self) {
-        println!("Variable[CPUFloatType {{{}, {}}}]", self.rows, self.cols);
-        let slice = self.as_slice();
-        for r in 0..self.rows {
-            for c in 0..self.cols {
-                let val = slice[(r * self.cols + c) as usize];
-                print!("{:.4} ", val);
-            }
-            println!();
-        }
+        let dims: Vec<String> = self.shape.iter().map(|d| d.to_string()).collect();
+        println!("Variable[CPU{}Type {{{}}}]", type_name(self.dtype), dims.join(", "));
+        let values = with_element_type!(self.dtype, T => {
+            shape::format_values(&self.try_data_as::<T>().expect("Error reading tensor data"), &self.shape)
+        });
+        print!("{}", values);
+    }
+
+    pub fn try_mse_loss(&self, target: &Tensor) -> Result<Tensor> {
+        self.try_mse_loss_with(target, Reduction::Mean)
     }
 
     pub fn mse_loss(&self, target: &Tensor) -> Tensor {
-        let loss_ptr = unsafe { MSELoss(self.ptr, target.ptr) };
-        if loss_ptr.is_null() {
-            panic!("Error calculating MSELoss");
-        }
-        let rows = unsafe { TensorRows(loss_ptr) };
-        let cols = unsafe { TensorCols(loss_ptr) };
-        Tensor { ptr: loss_ptr, rows, cols }
+        self.try_mse_loss(target).expect("Error calculating MSELoss")
+    }
+
+    pub fn try_backward(&self) -> Result<()> {
+        check_call("Backward", || unsafe { Backward(self.ptr) })
     }
 
     pub fn backward(&self) {
-        unsafe { Backward(self.ptr) };
+        self.try_backward().expect("Error in backward pass")
+    }
+
+    /// Aplica uma redução `*_dim` ao tensor achatado, reduzindo todos os elementos a um tensor 0-D
+    fn try_reduce_all(&self, reduce: fn(&Tensor, i64, bool) -> Result<Tensor>) -> Result<Tensor> {
+        reduce(&self.try_reshape(&[self.numel() as i64])?, 0, false)
+    }
+
+    /// Valor de um tensor com um único elemento, convertido para f32
+    fn try_item_f32(&self) -> Result<f32> {
+        let value = self.try_to_dtype(DType::Float32)?;
+        Ok(value.try_data_as::<f32>()?[0])
+    }
+
+    /// Calcula a soma de todos os elementos
+    pub fn try_sum(&self) -> Result<f32> {
+        self.try_reduce_all(Tensor::try_sum_dim)?.try_item_f32()
     }
 
     /// Calcula a soma de todos os elementos
     pub fn sum(&self) -> f32 {
-        self.as_slice().iter().sum()
+        self.try_sum().expect("Error calculating sum")
+    }
+
+    /// Calcula a média de todos os elementos (inteiros e booleanos são promovidos a f64)
+    pub fn try_mean(&self) -> Result<f32> {
+        if self.dtype.is_floating_point() {
+            self.try_reduce_all(Tensor::try_mean_dim)?.try_item_f32()
+        } else {
+            self.try_to_dtype(DType::Float64)?.try_mean()
+        }
     }
 
     /// Calcula a média de todos os elementos
     pub fn mean(&self) -> f32 {
-        let sum: f32 = self.as_slice().iter().sum();
-        sum / (self.rows * self.cols) as f32
+        self.try_mean().expect("Error calculating mean")
+    }
+
+    /// Calcula o valor máximo
+    pub fn try_max(&self) -> Result<f32> {
+        self.try_reduce_all(Tensor::try_max_dim)?.try_item_f32()
     }
 
     /// Calcula o valor máximo
     pub fn max(&self) -> f32 {
-        self.as_slice().iter()
-            .cloned()
-            .fold(f32::NEG_INFINITY, f32::max)
+        self.try_max().expect("Error calculating max")
+    }
+
+    /// Calcula o valor mínimo
+    pub fn try_min(&self) -> Result<f32> {
+        self.try_reduce_all(Tensor::try_min_dim)?.try_item_f32()
     }
 
     /// Calcula o valor mínimo
     pub fn min(&self) -> f32 {
-        self.as_slice().iter()
-            .cloned()
-            .fold(f32::INFINITY, f32::min)
+        self.try_min().expect("Error calculating min")
     }
 
-    /// Transpõe o tensor
-    pub fn transpose(&self) -> Tensor {
-        let data = self.as_slice();
-        let mut transposed = vec![0.0f32; (self.rows * self.cols) as usize];
-        
-        for r in 0..self.rows {
-            for c in 0..self.cols {
-                let src_idx = (r * self.cols + c) as usize;
-                let dst_idx = (c * self.rows + r) as usize;
-                transposed[dst_idx] = data[src_idx];
-            }
+    /// Transpõe as duas últimas dimensões do tensor
+    pub fn try_transpose(&self) -> Result<Tensor> {
+        if self.dim() < 2 {
+            return Err(CopperError::InvalidArgument(format!(
+                "transpose exige ao menos 2 dimensões, tensor tem forma {:?}",
+                self.shape
+            )));
         }
+        self.try_transpose_dims(-2, -1)
+    }
+
+    /// Transpõe as duas últimas dimensões do tensor
+    pub fn transpose(&self) -> Tensor {
+        self.try_transpose().expect("Error transposing tensor")
+    }
 
-        Tensor::from_values(&transposed, self.cols, self.rows)
+    /// Troca duas dimensões quaisquer do tensor
+    pub fn try_transpose_dims(&self, dim0: i64, dim1: i64) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorTranspose(self.ptr, dim0, dim1) }, "TensorTranspose")
+    }
+
+    /// Troca duas dimensões quaisquer do tensor
+    pub fn transpose_dims(&self, dim0: i64, dim1: i64) -> Tensor {
+        self.try_transpose_dims(dim0, dim1).expect("Error transposing tensor")
+    }
+
+    /// Aplica uma função a cada elemento
+    pub fn try_map<F>(&self, f: F) -> Result<Tensor>
+    where
+        F: Fn(f32) -> f32,
+    {
+        let data: Vec<f32> = self.try_as_slice()?.into_iter().map(f).collect();
+        Tensor::try_from_values(&data, &self.shape)
     }
 
     /// Aplica uma função a cada elemento
-    pub fn map<F>(&self, f: F) -> Tensor 
+    pub fn map<F>(&self, f: F) -> Tensor
     where
         F: Fn(f32) -> f32,
     {
-        let data: Vec<f32> = self.as_slice().iter().map(|&x| f(x)).collect();
-        Tensor::from_values(&data, self.rows, self.cols)
+        self.try_map(f).expect("Error mapping tensor")
     }
 
     /// Cria tensor de zeros
-    pub fn zeros(rows: i32, cols: i32) -> Self {
-        let values = vec![0.0f32; (rows * cols) as usize];
-        Tensor::from_values(&values, rows, cols)
+    pub fn try_zeros(shape: &[i64]) -> Result<Self> {
+        Tensor::try_zeros_dtype(shape, DType::Float32)
     }
 
-    /// Reshape do tensor
-    pub fn reshape(&self, new_rows: i32, new_cols: i32) -> Tensor {
-        if self.rows * self.cols != new_rows * new_cols {
-            panic!("Cannot reshape: total elements must remain the same");
-        }
-        let data = self.as_slice().to_vec();
-        Tensor::from_values(&data, new_rows, new_cols)
+    /// Cria tensor de zeros
+    pub fn zeros(shape: &[i64]) -> Self {
+        Tensor::try_zeros(shape).expect("Error creating zeros tensor")
+    }
+
+    /// Cria tensor de zeros com o dtype informado
+    pub fn try_zeros_dtype(shape: &[i64], dtype: DType) -> Result<Self> {
+        let ptr = unsafe { CreateTensorZeros(shape.as_ptr(), shape.len() as i32, dtype.code()) };
+        Tensor::from_ptr(ptr, "CreateTensorZeros")
+    }
+
+    /// Cria tensor de zeros com o dtype informado
+    pub fn zeros_dtype(shape: &[i64], dtype: DType) -> Self {
+        Tensor::try_zeros_dtype(shape, dtype).expect("Error creating zeros tensor")
+    }
+
+    /// Reshape do tensor; uma dimensão pode ser -1 para ser inferida
+    pub fn try_reshape(&self, new_shape: &[i64]) -> Result<Tensor> {
+        let ptr = unsafe { TensorReshape(self.ptr, new_shape.as_ptr(), new_shape.len() as i32) };
+        Tensor::from_ptr(ptr, "TensorReshape")
     }
 
-    /// Multiplicação de matrizes
+    /// Reshape do tensor; uma dimensão pode ser -1 para ser inferida
+    pub fn reshape(&self, new_shape: &[i64]) -> Tensor {
+        self.try_reshape(new_shape).expect("Cannot reshape tensor")
+    }
+
+    /// Produto de matrizes com as regras de `torch.matmul` (vetores, matrizes e lotes com broadcasting)
+    pub fn try_matmul(&self, other: &Tensor) -> Result<Tensor> {
+        shape::check_matmul(self.shape(), other.shape())?;
+        Tensor::from_ptr(unsafe { TensorMatmul(self.ptr, other.ptr) }, "TensorMatmul")
+    }
+
+    /// Produto de matrizes com as regras de `torch.matmul` (vetores, matrizes e lotes com broadcasting)
     pub fn matmul(&self, other: &Tensor) -> Tensor {
-        if self.cols != other.rows {
-            panic!("Invalid dimensions for matrix multiplication: ({}, {}) x ({}, {})", 
-                   self.rows, self.cols, other.rows, other.cols);
-        }
+        self.try_matmul(other).expect("Invalid dimensions for matrix multiplication")
+    }
 
-        let mut result = vec![0.0f32; (self.rows * other.cols) as usize];
-        let a = self.as_slice();
-        let b = other.as_slice();
-
-        for i in 0..self.rows {
-            for j in 0..other.cols {
-                let mut sum = 0.0;
-                for k in 0..self.cols {
-                    let a_idx = (i * self.cols + k) as usize;
-                    let b_idx = (k * other.cols + j) as usize;
-                    sum += a[a_idx] * b[b_idx];
-                }
-                let result_idx = (i * other.cols + j) as usize;
-                result[result_idx] = sum;
-            }
-        }
+    /// Produto de matrizes em lote: `[b, n, k] x [b, k, m] -> [b, n, m]`
+    pub fn try_bmm(&self, other: &Tensor) -> Result<Tensor> {
+        shape::check_bmm(self.shape(), other.shape())?;
+        Tensor::from_ptr(unsafe { TensorBmm(self.ptr, other.ptr) }, "TensorBmm")
+    }
 
-        Tensor::from_values(&result, self.rows, other.cols)
+    /// Produto de matrizes em lote: `[b, n, k] x [b, k, m] -> [b, n, m]`
+    pub fn bmm(&self, other: &Tensor) -> Tensor {
+        self.try_bmm(other).expect("Invalid dimensions for batched matrix multiplication")
     }
 
     // ==================== ACTIVATION FUNCTIONS ====================
-    
+
+    /// Aplica a função de ativação ReLU (Rectified Linear Unit)
+    /// ReLU(x) = max(0, x)
+    pub fn try_relu(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorReLU(self.ptr) }, "TensorReLU")
+    }
+
     /// Aplica a função de ativação ReLU (Rectified Linear Unit)
     /// ReLU(x) = max(0, x)
     pub fn relu(&self) -> Tensor {
-        let ptr = unsafe { TensorReLU(self.ptr) };
-        if ptr.is_null() {
-            panic!("Error applying ReLU");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        self.try_relu().expect("Error applying ReLU")
+    }
+
+    /// Aplica a função de ativação Sigmoid
+    /// Sigmoid(x) = 1 / (1 + e^(-x))
+    pub fn try_sigmoid(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorSigmoid(self.ptr) }, "TensorSigmoid")
     }
 
     /// Aplica a função de ativação Sigmoid
     /// Sigmoid(x) = 1 / (1 + e^(-x))
     pub fn sigmoid(&self) -> Tensor {
-        let ptr = unsafe { TensorSigmoid(self.ptr) };
-        if ptr.is_null() {
-            panic!("Error applying Sigmoid");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        self.try_sigmoid().expect("Error applying Sigmoid")
+    }
+
+    /// Aplica a função de ativação Tanh (Tangente Hiperbólica)
+    /// Tanh(x) = (e^x - e^(-x)) / (e^x + e^(-x))
+    pub fn try_tanh(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorTanh(self.ptr) }, "TensorTanh")
     }
 
     /// Aplica a função de ativação Tanh (Tangente Hiperbólica)
     /// Tanh(x) = (e^x - e^(-x)) / (e^x + e^(-x))
     pub fn tanh(&self) -> Tensor {
-        let ptr = unsafe { TensorTanh(self.ptr) };
-        if ptr.is_null() {
-            panic!("Error applying Tanh");
+        self.try_tanh().expect("Error applying Tanh")
+    }
+
+    /// Softmax ao longo de `dim`: cada fatia vira uma distribuição de probabilidade
+    pub fn try_softmax(&self, dim: i64) -> Result<Tensor> {
+        shape::normalize_dim(dim, self.shape())?;
+        Tensor::from_ptr(unsafe { TensorSoftmax(self.ptr, dim) }, "TensorSoftmax")
+    }
+
+    /// Softmax ao longo de `dim`: cada fatia vira uma distribuição de probabilidade
+    pub fn softmax(&self, dim: i64) -> Tensor {
+        self.try_softmax(dim).expect("Error applying Softmax")
+    }
+
+    /// Logaritmo do softmax ao longo de `dim`, calculado de forma estável
+    pub fn try_log_softmax(&self, dim: i64) -> Result<Tensor> {
+        shape::normalize_dim(dim, self.shape())?;
+        Tensor::from_ptr(unsafe { TensorLogSoftmax(self.ptr, dim) }, "TensorLogSoftmax")
+    }
+
+    /// Logaritmo do softmax ao longo de `dim`, calculado de forma estável
+    pub fn log_softmax(&self, dim: i64) -> Tensor {
+        self.try_log_softmax(dim).expect("Error applying LogSoftmax")
+    }
+
+    /// Aplica a GELU exata
+    /// GELU(x) = x · Φ(x) = 0.5 · x · (1 + erf(x / √2))
+    pub fn try_gelu(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorGelu(self.ptr) }, "TensorGelu")
+    }
+
+    /// Aplica a GELU exata
+    /// GELU(x) = x · Φ(x) = 0.5 · x · (1 + erf(x / √2))
+    pub fn gelu(&self) -> Tensor {
+        self.try_gelu().expect("Error applying GELU")
+    }
+
+    /// Aplica a SiLU (swish)
+    /// SiLU(x) = x · sigmoid(x)
+    pub fn try_silu(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorSilu(self.ptr) }, "TensorSilu")
+    }
+
+    /// Aplica a SiLU (swish)
+    /// SiLU(x) = x · sigmoid(x)
+    pub fn silu(&self) -> Tensor {
+        self.try_silu().expect("Error applying SiLU")
+    }
+
+    /// Aplica a Leaky ReLU
+    /// LeakyReLU(x) = x se x > 0, senão negative_slope · x
+    pub fn try_leaky_relu(&self, negative_slope: f64) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorLeakyRelu(self.ptr, negative_slope) }, "TensorLeakyRelu")
+    }
+
+    /// Aplica a Leaky ReLU
+    /// LeakyReLU(x) = x se x > 0, senão negative_slope · x
+    pub fn leaky_relu(&self, negative_slope: f64) -> Tensor {
+        self.try_leaky_relu(negative_slope).expect("Error applying LeakyReLU")
+    }
+
+    /// Aplica a ELU
+    /// ELU(x) = x se x > 0, senão alpha · (e^x - 1)
+    pub fn try_elu(&self, alpha: f64) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorElu(self.ptr, alpha) }, "TensorElu")
+    }
+
+    /// Aplica a ELU
+    /// ELU(x) = x se x > 0, senão alpha · (e^x - 1)
+    pub fn elu(&self, alpha: f64) -> Tensor {
+        self.try_elu(alpha).expect("Error applying ELU")
+    }
+
+    /// Aplica a SELU (ELU escalada das redes auto-normalizadas)
+    /// SELU(x) = λ · ELU(x, α), com λ ≈ 1.0507 e α ≈ 1.6733
+    pub fn try_selu(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorSelu(self.ptr) }, "TensorSelu")
+    }
+
+    /// Aplica a SELU (ELU escalada das redes auto-normalizadas)
+    /// SELU(x) = λ · ELU(x, α), com λ ≈ 1.0507 e α ≈ 1.6733
+    pub fn selu(&self) -> Tensor {
+        self.try_selu().expect("Error applying SELU")
+    }
+
+    /// Aplica a Softplus, uma versão suave da ReLU
+    /// Softplus(x) = ln(1 + e^x)
+    pub fn try_softplus(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorSoftplus(self.ptr) }, "TensorSoftplus")
+    }
+
+    /// Aplica a Softplus, uma versão suave da ReLU
+    /// Softplus(x) = ln(1 + e^x)
+    pub fn softplus(&self) -> Tensor {
+        self.try_softplus().expect("Error applying Softplus")
+    }
+
+    /// Aplica a Mish
+    /// Mish(x) = x · tanh(softplus(x))
+    pub fn try_mish(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorMish(self.ptr) }, "TensorMish")
+    }
+
+    /// Aplica a Mish
+    /// Mish(x) = x · tanh(softplus(x))
+    pub fn mish(&self) -> Tensor {
+        self.try_mish().expect("Error applying Mish")
+    }
+
+    /// Limita cada elemento ao intervalo `[min_val, max_val]`
+    pub fn try_hardtanh(&self, min_val: f64, max_val: f64) -> Result<Tensor> {
+        if min_val > max_val {
+            return Err(CopperError::InvalidArgument(format!(
+                "hardtanh: min_val ({}) maior que max_val ({})",
+                min_val, max_val
+            )));
         }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        Tensor::from_ptr(unsafe { TensorHardtanh(self.ptr, min_val, max_val) }, "TensorHardtanh")
+    }
+
+    /// Limita cada elemento ao intervalo `[min_val, max_val]`
+    pub fn hardtanh(&self, min_val: f64, max_val: f64) -> Tensor {
+        self.try_hardtanh(min_val, max_val).expect("Error applying Hardtanh")
+    }
+
+    /// Aplica a ReLU6
+    /// ReLU6(x) = min(max(0, x), 6)
+    pub fn try_relu6(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorRelu6(self.ptr) }, "TensorRelu6")
+    }
+
+    /// Aplica a ReLU6
+    /// ReLU6(x) = min(max(0, x), 6)
+    pub fn relu6(&self) -> Tensor {
+        self.try_relu6().expect("Error applying ReLU6")
     }
 
     // ==================== MATHEMATICAL FUNCTIONS ====================
-    
+
+    /// Aplica a função seno elemento a elemento
+    pub fn try_sin(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorSin(self.ptr) }, "TensorSin")
+    }
+
     /// Aplica a função seno elemento a elemento
     pub fn sin(&self) -> Tensor {
-        let ptr = unsafe { TensorSin(self.ptr) };
-        if ptr.is_null() {
-            panic!("Error applying sin");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        self.try_sin().expect("Error applying sin")
+    }
+
+    /// Aplica a função cosseno elemento a elemento
+    pub fn try_cos(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorCos(self.ptr) }, "TensorCos")
     }
 
     /// Aplica a função cosseno elemento a elemento
     pub fn cos(&self) -> Tensor {
-        let ptr = unsafe { TensorCos(self.ptr) };
-        if ptr.is_null() {
-            panic!("Error applying cos");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        self.try_cos().expect("Error applying cos")
+    }
+
+    /// Aplica a função exponencial (e^x) elemento a elemento
+    pub fn try_exp(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorExp(self.ptr) }, "TensorExp")
     }
 
     /// Aplica a função exponencial (e^x) elemento a elemento
     pub fn exp(&self) -> Tensor {
-        let ptr = unsafe { TensorExp(self.ptr) };
-        if ptr.is_null() {
-            panic!("Error applying exp");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        self.try_exp().expect("Error applying exp")
+    }
+
+    /// Aplica a função logaritmo natural elemento a elemento
+    pub fn try_log(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorLog(self.ptr) }, "TensorLog")
     }
 
     /// Aplica a função logaritmo natural elemento a elemento
     pub fn log(&self) -> Tensor {
-        let ptr = unsafe { TensorLog(self.ptr) };
-        if ptr.is_null() {
-            panic!("Error applying log");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        self.try_log().expect("Error applying log")
+    }
+
+    /// Aplica a função raiz quadrada elemento a elemento
+    pub fn try_sqrt(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorSqrt(self.ptr) }, "TensorSqrt")
     }
 
     /// Aplica a função raiz quadrada elemento a elemento
     pub fn sqrt(&self) -> Tensor {
-        let ptr = unsafe { TensorSqrt(self.ptr) };
-        if ptr.is_null() {
-            panic!("Error applying sqrt");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        self.try_sqrt().expect("Error applying sqrt")
+    }
+
+    /// Aplica a função valor absoluto elemento a elemento
+    pub fn try_abs(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorAbs(self.ptr) }, "TensorAbs")
     }
 
     /// Aplica a função valor absoluto elemento a elemento
     pub fn abs(&self) -> Tensor {
-        let ptr = unsafe { TensorAbs(self.ptr) };
-        if ptr.is_null() {
-            panic!("Error applying abs");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        self.try_abs().expect("Error applying abs")
+    }
+
+    /// Eleva cada elemento a uma potência
+    pub fn try_pow(&self, exponent: f32) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorPow(self.ptr, exponent) }, "TensorPow")
     }
 
     /// Eleva cada elemento a uma potência
     pub fn pow(&self, exponent: f32) -> Tensor {
-        let ptr = unsafe { TensorPow(self.ptr, exponent) };
-        if ptr.is_null() {
-            panic!("Error applying pow");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        self.try_pow(exponent).expect("Error applying pow")
     }
 
     // ==================== TENSOR CREATION (STATIC METHODS) ====================
-    
+
     /// Cria tensor com distribuição normal (média 0, desvio padrão 1)
-    pub fn randn(rows: i32, cols: i32) -> Self {
-        let ptr = unsafe { CreateTensorRandn(rows, cols) };
-        if ptr.is_null() {
-            panic!("Error creating randn tensor");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+    pub fn try_randn(shape: &[i64]) -> Result<Self> {
+        let ptr = unsafe { CreateTensorRandn(shape.as_ptr(), shape.len() as i32, DType::Float32.code()) };
+        Tensor::from_ptr(ptr, "CreateTensorRandn")
+    }
+
+    /// Cria tensor com distribuição normal (média 0, desvio padrão 1)
+    pub fn randn(shape: &[i64]) -> Self {
+        Tensor::try_randn(shape).expect("Error creating randn tensor")
+    }
+
+    /// Cria matriz identidade (diagonal com 1s)
+    pub fn try_eye(size: i32) -> Result<Self> {
+        Tensor::from_ptr(unsafe { CreateTensorEye(size) }, "CreateTensorEye")
     }
 
     /// Cria matriz identidade (diagonal com 1s)
     pub fn eye(size: i32) -> Self {
-        let ptr = unsafe { CreateTensorEye(size) };
-        if ptr.is_null() {
-            panic!("Error creating eye tensor");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        Tensor::try_eye(size).expect("Error creating eye tensor")
+    }
+
+    /// Cria tensor de zeros com a mesma forma que outro tensor
+    pub fn try_zeros_like(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorZerosLike(self.ptr) }, "TensorZerosLike")
     }
 
     /// Cria tensor de zeros com a mesma forma que outro tensor
     pub fn zeros_like(&self) -> Tensor {
-        let ptr = unsafe { TensorZerosLike(self.ptr) };
-        if ptr.is_null() {
-            panic!("Error creating zeros_like tensor");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        self.try_zeros_like().expect("Error creating zeros_like tensor")
+    }
+
+    /// Cria tensor de ones com a mesma forma que outro tensor
+    pub fn try_ones_like(&self) -> Result<Tensor> {
+        Tensor::from_ptr(unsafe { TensorOnesLike(self.ptr) }, "TensorOnesLike")
     }
 
     /// Cria tensor de ones com a mesma forma que outro tensor
     pub fn ones_like(&self) -> Tensor {
-        let ptr = unsafe { TensorOnesLike(self.ptr) };
-        if ptr.is_null() {
-            panic!("Error creating ones_like tensor");
-        }
-        let rows = unsafe { TensorRows(ptr) };
-        let cols = unsafe { TensorCols(ptr) };
-        Tensor { ptr, rows, cols }
+        self.try_ones_like().expect("Error creating ones_like tensor")
     }
 
     // ==================== STATISTICAL FUNCTIONS ====================
-    
+
+    /// Calcula o desvio padrão de todos os elementos
+    pub fn try_std(&self) -> Result<f32> {
+        check_call("TensorStd", || unsafe { TensorStd(self.ptr) })
+    }
+
     /// Calcula o desvio padrão de todos os elementos
     pub fn std(&self) -> f32 {
-        unsafe { TensorStd(self.ptr) }
+        self.try_std().expect("Error calculating std")
+    }
+
+    /// Calcula a variância de todos os elementos
+    pub fn try_var(&self) -> Result<f32> {
+        check_call("TensorVar", || unsafe { TensorVar(self.ptr) })
     }
 
     /// Calcula a variância de todos os elementos
     pub fn var(&self) -> f32 {
-        unsafe { TensorVar(self.ptr) }
+        self.try_var().expect("Error calculating var")
     }
 
-    /// Retorna o índice do valor máximo (flatten)
-    pub fn argmax(&self) -> i32 {
-        unsafe { TensorArgmax(self.ptr) }
+    /// Índice (i64, tensor 0-D) do valor máximo no tensor achatado, como `torch.argmax`
+    pub fn try_argmax(&self) -> Result<Tensor> {
+        self.try_reduce_all(Tensor::try_argmax_dim)
     }
 
-    /// Retorna o índice do valor mínimo (flatten)
-    pub fn argmin(&self) -> i32 {
-        unsafe { TensorArgmin(self.ptr) }
+    /// Índice (i64, tensor 0-D) do valor máximo no tensor achatado, como `torch.argmax`
+    pub fn argmax(&self) -> Tensor {
+        self.try_argmax().expect("Error calculating argmax")
     }
 
-    // ==================== LOSS FUNCTIONS ====================
-    
-    /// Calcula Cross Entropy Loss para classificação
-    pub fn cross_entropy_loss(&self, target: &Tensor) -> Tensor {
-        let loss_ptr = unsafe { CrossEntropyLoss(self.ptr, target.ptr) };
-        if loss_ptr.is_null() {
-            panic!("Error calculating CrossEntropyLoss");
-        }
-        let rows = unsafe { TensorRows(loss_ptr) };
-        let cols = unsafe { TensorCols(loss_ptr) };
-        Tensor { ptr: loss_ptr, rows, cols }
+    /// Índice do valor máximo no tensor achatado, como número
+    pub fn try_argmax_index(&self) -> Result<i64> {
+        let index = self.try_argmax()?;
+        Ok(index.try_data_as::<i64>()?[0])
     }
-}
 
-impl Clone for Tensor {
-    fn clone(&self) -> Self {
-        let values = self.as_slice().to_vec();
-        Tensor::from_values(&values, self.rows, self.cols)
+    /// Índice do valor máximo no tensor achatado, como número
+    pub fn argmax_index(&self) -> i64 {
+        self.try_argmax_index().expect("Error calculating argmax")
     }
-}
 
-impl Drop for Tensor {
-    fn drop(&mut self) {
-        unsafe {
-            FreeTensor(self.ptr);
-        }
+    /// Índice (i64, tensor 0-D) do valor mínimo no tensor achatado, como `torch.argmin`
+    pub fn try_argmin(&self) -> Result<Tensor> {
+        self.try_reduce_all(Tensor::try_argmin_dim)
     }
-}
 
-pub struct Linear {
-    pub ptr: *mut libc::c_void,
-    pub in_features: i32,
-    pub out_features: i32,
-}
+    /// Índice (i64, tensor 0-D) do valor mínimo no tensor achatado, como `torch.argmin`
+    pub fn argmin(&self) -> Tensor {
+        self.try_argmin().expect("Error calculating argmin")
+    }
 
-impl Linear {
-    pub fn new(in_features: i32, out_features: i32) -> Self {
-        let ptr = unsafe { CreateLinear(in_features, out_features) };
-        if ptr.is_null() {
-            panic!("Error creating Linear layer");
-        }
-        Linear { ptr, in_features, out_features }
+    /// Índice do valor mínimo no tensor achatado, como número
+    pub fn try_argmin_index(&self) -> Result<i64> {
+        let index = self.try_argmin()?;
+        Ok(index.try_data_as::<i64>()?[0])
     }
 
-    pub fn forward(&self, input: &Tensor) -> Tensor {
-        let out_ptr = unsafe { LinearForward(self.ptr, input.ptr) };
-        if out_ptr.is_null() {
-            panic!("Error in Linear forward");
-        }
-        let rows = unsafe { TensorRows(out_ptr) };
-        let cols = unsafe { TensorCols(out_ptr) };
-        Tensor { ptr: out_ptr, rows, cols }
+    /// Índice do valor mínimo no tensor achatado, como número
+    pub fn argmin_index(&self) -> i64 {
+        self.try_argmin_index().expect("Error calculating argmin")
     }
-}
 
-impl Drop for Linear {
-    fn drop(&mut self) {
-        // Optionally implement a FreeLinear if you add it to the C++ side
+    /// Calcula o produto de todos os elementos
+    pub fn try_prod(&self) -> Result<f32> {
+        check_call("TensorProd", || unsafe { TensorProd(self.ptr) })
     }
-}
 
-pub struct Optimizer {
-    pub ptr: *mut libc::c_void,
-}
+    /// Calcula o produto de todos os elementos
+    pub fn prod(&self) -> f32 {
+        self.try_prod().expect("Error calculating prod")
+    }
 
-impl Optimizer {
-    pub fn sgd(linear: &Linear, lr: f32) -> Self {
-        let ptr = unsafe { CreateSGD(linear.ptr, lr) };
-        if ptr.is_null() {
-            panic!("Error creating SGD optimizer");
-        }
-        Optimizer { ptr }
+    /// Calcula log(sum(exp(x))) de forma numericamente estável
+    pub fn try_logsumexp(&self) -> Result<f32> {
+        check_call("TensorLogsumexp", || unsafe { TensorLogsumexp(self.ptr) })
     }
 
-    pub fn adam(linear: &Linear, lr: f32) -> Self {
-        let ptr = unsafe { CreateAdam(linear.ptr, lr) };
-        if ptr.is_null() {
-            panic!("Error creating Adam optimizer");
-        }
-        Optimizer { ptr }
+    /// Calcula log(sum(exp(x))) de forma numericamente estável
+    pub fn logsumexp(&self) -> f32 {
+        self.try_logsumexp().expect("Error calculating logsumexp")
+    }
+
+    /// Calcula a norma euclidiana (L2) de todos os elementos
+    pub fn try_norm(&self) -> Result<f32> {
+        check_call("TensorNorm", || unsafe { TensorNorm(self.ptr) })
+    }
+
+    /// Calcula a norma euclidiana (L2) de todos os elementos
+    pub fn norm(&self) -> f32 {
+        self.try_norm().expect("Error calculating norm")
+    }
+
+    /// Verifica se algum elemento é diferente de zero
+    pub fn try_any(&self) -> Result<bool> {
+        Ok(check_call("TensorAny", || unsafe { TensorAny(self.ptr) })? != 0)
+    }
+
+    /// Verifica se algum elemento é diferente de zero
+    pub fn any(&self) -> bool {
+        self.try_any().expect("Error calculating any")
+    }
+
+    /// Verifica se todos os elementos são diferentes de zero
+    pub fn try_all(&self) -> Result<bool> {
+        Ok(check_call("TensorAll", || unsafe { TensorAll(self.ptr) })? != 0)
+    }
+
+    /// Verifica se todos os elementos são diferentes de zero
+    pub fn all(&self) -> bool {
+        self.try_all().expect("Error calculating all")
+    }
+
+    // ==================== REDUCTIONS ALONG A DIMENSION ====================
+
+    fn try_reduce_dim(&self, op: ReduceDimFn, name: &'static str, dim: i64, keepdim: bool) -> Result<Tensor> {
+        shape::normalize_dim(dim, &self.shape)?;
+        Tensor::from_ptr(unsafe { op(self.ptr, dim, keepdim) }, name)
+    }
+
+    /// Soma ao longo de `dim`; com `keepdim` a dimensão reduzida fica com tamanho 1
+    pub fn try_sum_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorSumDim, "TensorSumDim", dim, keepdim)
+    }
+
+    /// Soma ao longo de `dim`; com `keepdim` a dimensão reduzida fica com tamanho 1
+    pub fn sum_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_sum_dim(dim, keepdim).expect("Error calculating sum")
+    }
+
+    /// Média ao longo de `dim`
+    pub fn try_mean_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorMeanDim, "TensorMeanDim", dim, keepdim)
+    }
+
+    /// Média ao longo de `dim`
+    pub fn mean_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_mean_dim(dim, keepdim).expect("Error calculating mean")
+    }
+
+    /// Valor máximo ao longo de `dim`
+    pub fn try_max_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorMaxDim, "TensorMaxDim", dim, keepdim)
+    }
+
+    /// Valor máximo ao longo de `dim`
+    pub fn max_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_max_dim(dim, keepdim).expect("Error calculating max")
+    }
+
+    /// Valor mínimo ao longo de `dim`
+    pub fn try_min_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorMinDim, "TensorMinDim", dim, keepdim)
+    }
+
+    /// Valor mínimo ao longo de `dim`
+    pub fn min_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_min_dim(dim, keepdim).expect("Error calculating min")
+    }
+
+    /// Desvio padrão (amostral) ao longo de `dim`
+    pub fn try_std_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorStdDim, "TensorStdDim", dim, keepdim)
+    }
+
+    /// Desvio padrão (amostral) ao longo de `dim`
+    pub fn std_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_std_dim(dim, keepdim).expect("Error calculating std")
+    }
+
+    /// Variância (amostral) ao longo de `dim`
+    pub fn try_var_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorVarDim, "TensorVarDim", dim, keepdim)
+    }
+
+    /// Variância (amostral) ao longo de `dim`
+    pub fn var_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_var_dim(dim, keepdim).expect("Error calculating var")
+    }
+
+    /// Índices (i64) do valor máximo ao longo de `dim`
+    pub fn try_argmax_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorArgmaxDim, "TensorArgmaxDim", dim, keepdim)
+    }
+
+    /// Índices (i64) do valor máximo ao longo de `dim`
+    pub fn argmax_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_argmax_dim(dim, keepdim).expect("Error calculating argmax")
+    }
+
+    /// Índices (i64) do valor mínimo ao longo de `dim`
+    pub fn try_argmin_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorArgminDim, "TensorArgminDim", dim, keepdim)
+    }
+
+    /// Índices (i64) do valor mínimo ao longo de `dim`
+    pub fn argmin_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_argmin_dim(dim, keepdim).expect("Error calculating argmin")
+    }
+
+    /// Produto ao longo de `dim`
+    pub fn try_prod_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorProdDim, "TensorProdDim", dim, keepdim)
+    }
+
+    /// Produto ao longo de `dim`
+    pub fn prod_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_prod_dim(dim, keepdim).expect("Error calculating prod")
+    }
+
+    /// log(sum(exp(x))) ao longo de `dim`
+    pub fn try_logsumexp_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorLogsumexpDim, "TensorLogsumexpDim", dim, keepdim)
+    }
+
+    /// log(sum(exp(x))) ao longo de `dim`
+    pub fn logsumexp_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_logsumexp_dim(dim, keepdim).expect("Error calculating logsumexp")
+    }
+
+    /// Norma euclidiana (L2) ao longo de `dim`
+    pub fn try_norm_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorNormDim, "TensorNormDim", dim, keepdim)
     }
 
-    pub fn step(&self) {
-        unsafe { OptimizerStep(self.ptr) };
+    /// Norma euclidiana (L2) ao longo de `dim`
+    pub fn norm_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_norm_dim(dim, keepdim).expect("Error calculating norm")
     }
 
-    pub fn zero_grad(&self) {
-        unsafe { OptimizerZeroGrad(self.ptr) };
+    /// Tensor bool indicando se algum elemento ao longo de `dim` é diferente de zero
+    pub fn try_any_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorAnyDim, "TensorAnyDim", dim, keepdim)
+    }
+
+    /// Tensor bool indicando se algum elemento ao longo de `dim` é diferente de zero
+    pub fn any_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_any_dim(dim, keepdim).expect("Error calculating any")
+    }
+
+    /// Tensor bool indicando se todos os elementos ao longo de `dim` são diferentes de zero
+    pub fn try_all_dim(&self, dim: i64, keepdim: bool) -> Result<Tensor> {
+        self.try_reduce_dim(TensorAllDim, "TensorAllDim", dim, keepdim)
+    }
+
+    /// Tensor bool indicando se todos os elementos ao longo de `dim` são diferentes de zero
+    pub fn all_dim(&self, dim: i64, keepdim: bool) -> Tensor {
+        self.try_all_dim(dim, keepdim).expect("Error calculating all")
+    }
+
+    // ==================== LOSS FUNCTIONS ====================
+
+    /// Calcula Cross Entropy Loss para classificação
+    pub fn try_cross_entropy_loss(&self, target: &Tensor) -> Result<Tensor> {
+        self.try_cross_entropy_loss_with(target, None, None, Reduction::Mean)
+    }
+
+    /// Calcula Cross Entropy Loss para classificação
+    pub fn cross_entropy_loss(&self, target: &Tensor) -> Tensor {
+        self.try_cross_entropy_loss(target).expect("Error calculating CrossEntropyLoss")
+    }
+
+    /// Cria uma cópia independente do tensor
+    pub fn try_clone(&self) -> Result<Tensor> {
+        self.try_to_dtype(self.dtype)
+    }
+}
+
+/// Nome do tipo no cabeçalho impresso, no formato do PyTorch (CPUFloatType, CPULongType...)
+fn type_name(dtype: DType) -> &'static str {
+    match dtype {
+        DType::Float32 => "Float",
+        DType::Float64 => "Double",
+        DType::Int64 => "Long",
+        DType::Int32 => "Int",
+        DType::UInt8 => "Byte",
+        DType::Bool => "Bool",
     }
 }
 
-impl Drop for Optimizer {
+impl Clone for Tensor {
+    fn clone(&self) -> Self {
+        self.try_clone().expect("Error cloning tensor")
+    }
+}
+
+impl Drop for Tensor {
     fn drop(&mut self) {
-        unsafe { FreeOptimizer(self.ptr) };
+        unsafe {
+            FreeTensor(self.ptr);
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,185 @@
+// Activation Functions
+// Ativações executadas como ops eager do TensorFlow. As que não têm kernel próprio
+// (GELU, SiLU, Mish, Hardtanh e ELU com alpha) são compostas de ops nativas.
+
+use crate::error::{CopperError, Result};
+use crate::shape;
+use crate::tensor_tensorflow::eager::{self, Attr};
+use crate::tensor_tensorflow::tensors_flow::FlowTensors;
+
+impl FlowTensors {
+    pub(crate) fn try_unary(&self, tf_op: &'static str) -> Result<FlowTensors> {
+        eager::execute(tf_op, &[self], &[])
+    }
+
+    /// Softmax e LogSoftmax do TensorFlow atuam na última dimensão; outra `dim` é
+    /// trocada com a última por Transpose antes e depois da op
+    fn try_softmax_op(&self, tf_op: &'static str, dim: i64) -> Result<FlowTensors> {
+        let index = shape::normalize_dim(dim, self.dims())?;
+        let last = self.dims().len() - 1;
+        if index == last {
+            return self.try_unary(tf_op);
+        }
+        // Trocar dois eixos é uma permutação que é a própria inversa
+        let mut perm: Vec<i64> = (0..self.dims().len() as i64).collect();
+        perm.swap(index, last);
+        let perm = FlowTensors::try_from_slice(&perm, &[perm.len() as i64])?;
+        let moved = eager::execute("Transpose", &[self, &perm], &[])?;
+        eager::execute("Transpose", &[&moved.try_unary(tf_op)?, &perm], &[])
+    }
+
+    /// ReLU(x) = max(0, x)
+    pub fn try_relu(&self) -> Result<FlowTensors> {
+        self.try_unary("Relu")
+    }
+
+    /// ReLU(x) = max(0, x)
+    pub fn relu(&self) -> Option<FlowTensors> {
+        self.try_relu().ok()
+    }
+
+    /// Sigmoid(x) = 1 / (1 + e^(-x))
+    pub fn try_sigmoid(&self) -> Result<FlowTensors> {
+        self.try_unary("Sigmoid")
+    }
+
+    /// Sigmoid(x) = 1 / (1 + e^(-x))
+    pub fn sigmoid(&self) -> Option<FlowTensors> {
+        self.try_sigmoid().ok()
+    }
+
+    /// Tangente hiperbólica
+    pub fn try_tanh(&self) -> Result<FlowTensors> {
+        self.try_unary("Tanh")
+    }
+
+    /// Tangente hiperbólica
+    pub fn tanh(&self) -> Option<FlowTensors> {
+        self.try_tanh().ok()
+    }
+
+    /// Softmax ao longo de `dim`
+    pub fn try_softmax(&self, dim: i64) -> Result<FlowTensors> {
+        self.try_softmax_op("Softmax", dim)
+    }
+
+    /// Softmax ao longo de `dim`
+    pub fn softmax(&self, dim: i64) -> Option<FlowTensors> {
+        self.try_softmax(dim).ok()
+    }
+
+    /// Logaritmo do softmax ao longo de `dim`
+    pub fn try_log_softmax(&self, dim: i64) -> Result<FlowTensors> {
+        self.try_softmax_op("LogSoftmax", dim)
+    }
+
+    /// Logaritmo do softmax ao longo de `dim`
+    pub fn log_softmax(&self, dim: i64) -> Option<FlowTensors> {
+        self.try_log_softmax(dim).ok()
+    }
+
+    /// GELU exata: 0.5 · x · (1 + erf(x / √2))
+    pub fn try_gelu(&self) -> Result<FlowTensors> {
+        let erf = self.try_mul_scalar(std::f32::consts::FRAC_1_SQRT_2)?.try_unary("Erf")?;
+        self.try_mul(&erf.try_add_scalar(1.0)?)?.try_mul_scalar(0.5)
+    }
+
+    /// GELU exata: 0.5 · x · (1 + erf(x / √2))
+    pub fn gelu(&self) -> Option<FlowTensors> {
+        self.try_gelu().ok()
+    }
+
+    /// SiLU(x) = x · sigmoid(x)
+    pub fn try_silu(&self) -> Result<FlowTensors> {
+        self.try_mul(&self.try_sigmoid()?)
+    }
+
+    /// SiLU(x) = x · sigmoid(x)
+    pub fn silu(&self) -> Option<FlowTensors> {
+        self.try_silu().ok()
+    }
+
+    /// LeakyReLU(x) = x se x > 0, senão negative_slope · x
+    pub fn try_leaky_relu(&self, negative_slope: f64) -> Result<FlowTensors> {
+        eager::execute("LeakyRelu", &[self], &[Attr::Float("alpha", negative_slope as f32)])
+    }
+
+    /// LeakyReLU(x) = x se x > 0, senão negative_slope · x
+    pub fn leaky_relu(&self, negative_slope: f64) -> Option<FlowTensors> {
+        self.try_leaky_relu(negative_slope).ok()
+    }
+
+    /// ELU(x) = x se x > 0, senão alpha · (e^x - 1)
+    pub fn try_elu(&self, alpha: f64) -> Result<FlowTensors> {
+        let elu = self.try_unary("Elu")?;
+        if alpha == 1.0 {
+            return Ok(elu);
+        }
+        // A op Elu usa alpha = 1: escala só a parte negativa, elu - relu
+        let relu = self.try_relu()?;
+        relu.try_add(&elu.try_sub(&relu)?.try_mul_scalar(alpha as f32)?)
+    }
+
+    /// ELU(x) = x se x > 0, senão alpha · (e^x - 1)
+    pub fn elu(&self, alpha: f64) -> Option<FlowTensors> {
+        self.try_elu(alpha).ok()
+    }
+
+    /// SELU(x) = λ · ELU(x, α), com as mesmas constantes do PyTorch
+    pub fn try_selu(&self) -> Result<FlowTensors> {
+        self.try_unary("Selu")
+    }
+
+    /// SELU(x) = λ · ELU(x, α), com as mesmas constantes do PyTorch
+    pub fn selu(&self) -> Option<FlowTensors> {
+        self.try_selu().ok()
+    }
+
+    /// Softplus(x) = ln(1 + e^x)
+    pub fn try_softplus(&self) -> Result<FlowTensors> {
+        self.try_unary("Softplus")
+    }
+
+    /// Softplus(x) = ln(1 + e^x)
+    pub fn softplus(&self) -> Option<FlowTensors> {
+        self.try_softplus().ok()
+    }
+
+    /// Mish(x) = x · tanh(softplus(x))
+    pub fn try_mish(&self) -> Result<FlowTensors> {
+        self.try_mul(&self.try_softplus()?.try_tanh()?)
+    }
+
+    /// Mish(x) = x · tanh(softplus(x))
+    pub fn mish(&self) -> Option<FlowTensors> {
+        self.try_mish().ok()
+    }
+
+    /// Limita cada elemento ao intervalo `[min_val, max_val]`
+    pub fn try_hardtanh(&self, min_val: f64, max_val: f64) -> Result<FlowTensors> {
+        if min_val > max_val {
+            return Err(CopperError::InvalidArgument(format!(
+                "hardtanh: min_val ({}) maior que max_val ({})",
+                min_val, max_val
+            )));
+        }
+        let low = self.scalar_like(min_val as f32)?;
+        let high = self.scalar_like(max_val as f32)?;
+        eager::execute("ClipByValue", &[self, &low, &high], &[])
+    }
+
+    /// Limita cada elemento ao intervalo `[min_val, max_val]`
+    pub fn hardtanh(&self, min_val: f64, max_val: f64) -> Option<FlowTensors> {
+        self.try_hardtanh(min_val, max_val).ok()
+    }
+
+    /// ReLU6(x) = min(max(0, x), 6)
+    pub fn try_relu6(&self) -> Result<FlowTensors> {
+        self.try_unary("Relu6")
+    }
+
+    /// ReLU6(x) = min(max(0, x), 6)
+    pub fn relu6(&self) -> Option<FlowTensors> {
+        self.try_relu6().ok()
+    }
+}
@@ -0,0 +1,87 @@
+// Eager Ops
+// Executa ops do TensorFlow diretamente sobre FlowTensors (TFE_Execute no shim)
+
+use crate::dtype::DType;
+use crate::error::{check_ptr, CopperError, Result};
+use crate::tensor_tensorflow::ffi::{TFAttr, TFExecuteOp};
+use crate::tensor_tensorflow::tensors_flow::FlowTensors;
+use std::ffi::CString;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+// Mesma ordem do enum CopperTFAttrKind em cpp/lib.cpp
+const ATTR_INT: c_int = 0;
+const ATTR_FLOAT: c_int = 1;
+const ATTR_BOOL: c_int = 2;
+const ATTR_TYPE: c_int = 3;
+
+/// Atributo de uma op eager
+#[allow(dead_code)]
+pub(crate) enum Attr {
+    Int(&'static str, i64),
+    Float(&'static str, f32),
+    Bool(&'static str, bool),
+    Type(&'static str, DType),
+}
+
+impl Attr {
+    fn name(&self) -> &'static str {
+        match self {
+            Attr::Int(name, _) | Attr::Float(name, _) | Attr::Bool(name, _) | Attr::Type(name, _) => name,
+        }
+    }
+}
+
+fn c_string(value: &str) -> Result<CString> {
+    CString::new(value).map_err(|_| CopperError::InvalidArgument(format!("nome inválido: {:?}", value)))
+}
+
+/// Executa `op` e devolve `num_outputs` tensores
+pub(crate) fn execute_n(
+    op: &'static str,
+    inputs: &[&FlowTensors],
+    attrs: &[Attr],
+    num_outputs: usize,
+) -> Result<Vec<FlowTensors>> {
+    let op_name = c_string(op)?;
+    let attr_names = attrs.iter().map(|attr| c_string(attr.name())).collect::<Result<Vec<_>>>()?;
+    let raw_attrs: Vec<TFAttr> = attrs
+        .iter()
+        .zip(&attr_names)
+        .map(|(attr, name)| {
+            let (kind, i, f) = match *attr {
+                Attr::Int(_, value) => (ATTR_INT, value, 0.0),
+                Attr::Float(_, value) => (ATTR_FLOAT, 0, value),
+                Attr::Bool(_, value) => (ATTR_BOOL, value as i64, 0.0),
+                Attr::Type(_, dtype) => (ATTR_TYPE, dtype.code() as i64, 0.0),
+            };
+            TFAttr { name: name.as_ptr(), kind, i, f }
+        })
+        .collect();
+    let input_ptrs: Vec<*mut c_void> = inputs.iter().map(|tensor| tensor.ptr).collect();
+    let mut outputs: Vec<*mut c_void> = vec![ptr::null_mut(); num_outputs];
+
+    let result = unsafe {
+        TFExecuteOp(
+            op_name.as_ptr(),
+            input_ptrs.as_ptr(),
+            input_ptrs.len() as c_int,
+            raw_attrs.as_ptr(),
+            raw_attrs.len() as c_int,
+            outputs.as_mut_ptr(),
+            num_outputs as c_int,
+        )
+    };
+    check_ptr(result, op)?;
+
+    outputs
+        .into_iter()
+        .map(|ptr| unsafe { FlowTensors::from_raw(ptr) })
+        .collect()
+}
+
+/// Executa `op` e devolve sua única saída
+pub(crate) fn execute(op: &'static str, inputs: &[&FlowTensors], attrs: &[Attr]) -> Result<FlowTensors> {
+    let mut outputs = execute_n(op, inputs, attrs, 1)?;
+    Ok(outputs.remove(0))
+}
@@ -1,5 +1,14 @@
 use std::os::raw::{c_char, c_void, c_int};
 
+/// Atributo de uma op eager (espelha CopperTFAttr em cpp/lib.cpp)
+#[repr(C)]
+pub struct TFAttr {
+    pub name: *const c_char,
+    pub kind: c_int,
+    pub i: i64,
+    pub f: f32,
+}
+
 
 #[link(name = "ai_copper", kind = "dylib")]
 unsafe extern "C" { 
@@ -14,8 +23,20 @@ unsafe extern "C" {
         output_tensors: *mut *mut c_void,
         num_outputs: c_int,
     ) -> *mut c_void;
-    pub fn CreateTFTensor(values: *const f32, dims: *const i64, num_dims: c_int) -> *mut c_void;
-    pub fn GetTensorData(tensor_ptr: *mut c_void) -> *mut f32;
+    pub fn CreateTFTensor(values: *const c_void, dims: *const i64, num_dims: c_int, dtype: c_int) -> *mut c_void;
+    pub fn GetTensorData(tensor_ptr: *mut c_void) -> *mut c_void;
+    pub fn TFTensorDType(tensor_ptr: *mut c_void) -> c_int;
+    pub fn TFTensorNumDims(tensor_ptr: *mut c_void) -> c_int;
+    pub fn TFTensorDim(tensor_ptr: *mut c_void, index: c_int) -> i64;
     pub fn FreeTFTensor(tensor_ptr: *mut c_void);
+    pub fn TFExecuteOp(
+        op_name: *const c_char,
+        inputs: *const *mut c_void,
+        num_inputs: c_int,
+        attrs: *const TFAttr,
+        num_attrs: c_int,
+        outputs: *mut *mut c_void,
+        num_outputs: c_int,
+    ) -> *mut c_void;
     pub fn FreeModel(model_handle: *mut c_void);
 }
@@ -0,0 +1,132 @@
+use crate::dtype::{DType, Element};
+use crate::error::{CopperError, Result};
+use crate::shape;
+use crate::tensor_tensorflow::eager::{self, Attr};
+use crate::tensor_tensorflow::tensors_flow::FlowTensors;
+use std::ops::Index;
+
+impl FlowTensors {
+    /// StridedSlice com `begin`/`end`/`step` em `dim` e as demais dimensões inteiras
+    fn try_strided_slice(&self, dim: usize, begin: i64, end: i64, step: i64, attrs: &[Attr]) -> Result<FlowTensors> {
+        let ndim = self.dims().len();
+        let mut begins = vec![0i64; ndim];
+        let mut ends = self.dims().to_vec();
+        let mut steps = vec![1i64; ndim];
+        begins[dim] = begin;
+        ends[dim] = end;
+        steps[dim] = step;
+        let spec = [ndim as i64];
+        let begins = FlowTensors::try_from_slice(&begins, &spec)?;
+        let ends = FlowTensors::try_from_slice(&ends, &spec)?;
+        let steps = FlowTensors::try_from_slice(&steps, &spec)?;
+        eager::execute("StridedSlice", &[self, &begins, &ends, &steps], attrs)
+    }
+
+    /// `length` elementos de `dim` a partir de `start` (cópia, o TensorFlow não tem views)
+    pub fn try_narrow(&self, dim: i64, start: i64, length: i64) -> Result<FlowTensors> {
+        let index = shape::normalize_dim(dim, self.dims())?;
+        let start = shape::narrow_start(self.dims()[index], start, length)?;
+        self.try_strided_slice(index, start, start + length, 1, &[])
+    }
+
+    /// `length` elementos de `dim` a partir de `start` (cópia, o TensorFlow não tem views)
+    pub fn narrow(&self, dim: i64, start: i64, length: i64) -> Option<FlowTensors> {
+        self.try_narrow(dim, start, length).ok()
+    }
+
+    /// Posição `index` de `dim`, que é removida da forma
+    pub fn try_select(&self, dim: i64, index: i64) -> Result<FlowTensors> {
+        let dim = shape::normalize_dim(dim, self.dims())?;
+        let index = shape::normalize_index(index, self.dims()[dim])?;
+        self.try_strided_slice(dim, index, index + 1, 1, &[Attr::Int("shrink_axis_mask", 1 << dim)])
+    }
+
+    /// Posição `index` de `dim`, que é removida da forma
+    pub fn select(&self, dim: i64, index: i64) -> Option<FlowTensors> {
+        self.try_select(dim, index).ok()
+    }
+
+    /// `start..end` com passo `step` ao longo de `dim`; use `i64::MAX` como `end` para ir até o fim
+    pub fn try_slice(&self, dim: i64, start: i64, end: i64, step: i64) -> Result<FlowTensors> {
+        let index = shape::normalize_dim(dim, self.dims())?;
+        let (start, len) = shape::slice_range(self.dims()[index], start, end, step)?;
+        self.try_strided_slice(index, start, shape::slice_end(start, len, step), step, &[])
+    }
+
+    /// `start..end` com passo `step` ao longo de `dim`; use `i64::MAX` como `end` para ir até o fim
+    pub fn slice(&self, dim: i64, start: i64, end: i64, step: i64) -> Option<FlowTensors> {
+        self.try_slice(dim, start, end, step).ok()
+    }
+
+    /// Copia as posições de `dim` listadas em `index` (tensor 1-D de inteiros)
+    pub fn try_index_select(&self, dim: i64, index: &FlowTensors) -> Result<FlowTensors> {
+        let dim = shape::normalize_dim(dim, self.dims())?;
+        if index.dims().len() > 1 {
+            return Err(CopperError::InvalidArgument(format!(
+                "index_select exige índices 1-D, recebeu forma {:?}",
+                index.dims()
+            )));
+        }
+        if !matches!(index.dtype(), DType::Int64 | DType::Int32) {
+            return Err(CopperError::DTypeMismatch { op: "index_select", expected: DType::Int64, found: index.dtype() });
+        }
+        let axis = FlowTensors::try_from_slice(&[dim as i64], &[])?;
+        eager::execute("GatherV2", &[self, index, &axis], &[])
+    }
+
+    /// Copia as posições de `dim` listadas em `index` (tensor 1-D de inteiros)
+    pub fn index_select(&self, dim: i64, index: &FlowTensors) -> Option<FlowTensors> {
+        self.try_index_select(dim, index).ok()
+    }
+
+    /// Copia para um tensor 1-D os elementos em que `mask` (bool, com broadcasting) é verdadeiro
+    pub fn try_masked_select(&self, mask: &FlowTensors) -> Result<FlowTensors> {
+        if mask.dtype() != DType::Bool {
+            return Err(CopperError::DTypeMismatch { op: "masked_select", expected: DType::Bool, found: mask.dtype() });
+        }
+        let dims = shape::check_broadcast("masked_select", self.dims(), mask.dims())?;
+        let target = FlowTensors::try_from_slice(&dims, &[dims.len() as i64])?;
+        let broadcast = |t: &FlowTensors| -> Result<Option<FlowTensors>> {
+            if t.dims() == dims.as_slice() {
+                Ok(None)
+            } else {
+                eager::execute("BroadcastTo", &[t, &target], &[]).map(Some)
+            }
+        };
+        let values = broadcast(self)?;
+        let mask_values = broadcast(mask)?;
+        // Where devolve as coordenadas [n, ndim] dos elementos verdadeiros
+        let coords = eager::execute("Where", &[mask_values.as_ref().unwrap_or(mask)], &[])?;
+        eager::execute("GatherNd", &[values.as_ref().unwrap_or(self), &coords], &[])
+    }
+
+    /// Copia para um tensor 1-D os elementos em que `mask` (bool, com broadcasting) é verdadeiro
+    pub fn masked_select(&self, mask: &FlowTensors) -> Option<FlowTensors> {
+        self.try_masked_select(mask).ok()
+    }
+
+    /// Referência para o elemento em `index` no buffer (row-major) do tensor
+    fn try_element<T: Element>(&self, index: &[i64]) -> Result<&T> {
+        let offset = shape::element_offset(index, self.dims(), &shape::contiguous_strides(self.dims()))?;
+        Ok(&self.try_data_as::<T>()?[offset as usize])
+    }
+
+    /// Lê o elemento em `index` (uma posição por dimensão; negativos contam a partir do fim)
+    pub fn try_get<T: Element>(&self, index: &[i64]) -> Result<T> {
+        self.try_element(index).copied()
+    }
+
+    /// Lê o elemento em `index` (uma posição por dimensão; negativos contam a partir do fim)
+    pub fn get<T: Element>(&self, index: &[i64]) -> Option<T> {
+        self.try_get(index).ok()
+    }
+}
+
+/// `tensor[[i, j]]` para tensores f32
+impl<const N: usize> Index<[i64; N]> for FlowTensors {
+    type Output = f32;
+
+    fn index(&self, index: [i64; N]) -> &f32 {
+        self.try_element(&index).expect("Invalid tensor index")
+    }
+}
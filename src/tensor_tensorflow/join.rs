@@ -0,0 +1,116 @@
+use crate::dtype::DType;
+use crate::error::Result;
+use crate::shape;
+use crate::tensor_tensorflow::eager::{self, Attr};
+use crate::tensor_tensorflow::tensors_flow::FlowTensors;
+
+fn shapes<'a>(tensors: &[&'a FlowTensors]) -> Vec<&'a [i64]> {
+    tensors.iter().map(|t| t.dims()).collect()
+}
+
+fn dtypes(tensors: &[&FlowTensors]) -> Vec<DType> {
+    tensors.iter().map(|t| t.dtype()).collect()
+}
+
+/// Concatena os tensores ao longo de `dim` (ConcatV2); as demais dimensões devem coincidir
+pub fn try_cat(tensors: &[&FlowTensors], dim: i64) -> Result<FlowTensors> {
+    let shapes = shapes(tensors);
+    shape::cat_shape(&shapes, dim)?;
+    shape::check_same_dtype("cat", &dtypes(tensors))?;
+    let axis = FlowTensors::try_from_slice(&[shape::normalize_dim(dim, shapes[0])? as i64], &[])?;
+    let mut inputs = tensors.to_vec();
+    inputs.push(&axis);
+    // Entradas em lista desligam a inferência de atributos do TFE, então todos são explícitos
+    eager::execute(
+        "ConcatV2",
+        &inputs,
+        &[
+            Attr::Int("N", tensors.len() as i64),
+            Attr::Type("T", tensors[0].dtype()),
+            Attr::Type("Tidx", DType::Int64),
+        ],
+    )
+}
+
+/// Concatena os tensores ao longo de `dim` (ConcatV2); as demais dimensões devem coincidir
+pub fn cat(tensors: &[&FlowTensors], dim: i64) -> Option<FlowTensors> {
+    try_cat(tensors, dim).ok()
+}
+
+/// Empilha tensores de mesma forma em uma nova dimensão `dim` (Pack)
+pub fn try_stack(tensors: &[&FlowTensors], dim: i64) -> Result<FlowTensors> {
+    let dim = shape::stack_dim(&shapes(tensors), dim)?;
+    shape::check_same_dtype("stack", &dtypes(tensors))?;
+    eager::execute(
+        "Pack",
+        tensors,
+        &[
+            Attr::Int("N", tensors.len() as i64),
+            Attr::Type("T", tensors[0].dtype()),
+            Attr::Int("axis", dim as i64),
+        ],
+    )
+}
+
+/// Empilha tensores de mesma forma em uma nova dimensão `dim` (Pack)
+pub fn stack(tensors: &[&FlowTensors], dim: i64) -> Option<FlowTensors> {
+    try_stack(tensors, dim).ok()
+}
+
+/// Divide `tensor` ao longo de `dim` em partes com os tamanhos informados (SplitV)
+pub fn try_split_with_sizes(tensor: &FlowTensors, sizes: &[i64], dim: i64) -> Result<Vec<FlowTensors>> {
+    let index = shape::normalize_dim(dim, tensor.dims())?;
+    shape::check_split_sizes(tensor.dims()[index], sizes)?;
+    let size_splits = FlowTensors::try_from_slice(sizes, &[sizes.len() as i64])?;
+    let axis = FlowTensors::try_from_slice(&[index as i32], &[])?;
+    eager::execute_n(
+        "SplitV",
+        &[tensor, &size_splits, &axis],
+        &[Attr::Int("num_split", sizes.len() as i64)],
+        sizes.len(),
+    )
+}
+
+/// Divide `tensor` ao longo de `dim` em partes com os tamanhos informados (SplitV)
+pub fn split_with_sizes(tensor: &FlowTensors, sizes: &[i64], dim: i64) -> Option<Vec<FlowTensors>> {
+    try_split_with_sizes(tensor, sizes, dim).ok()
+}
+
+/// Divide `tensor` ao longo de `dim` em blocos de `split_size` (o último pode ser menor)
+pub fn try_split(tensor: &FlowTensors, split_size: i64, dim: i64) -> Result<Vec<FlowTensors>> {
+    let index = shape::normalize_dim(dim, tensor.dims())?;
+    try_split_with_sizes(tensor, &shape::split_sizes(tensor.dims()[index], split_size)?, dim)
+}
+
+/// Divide `tensor` ao longo de `dim` em blocos de `split_size` (o último pode ser menor)
+pub fn split(tensor: &FlowTensors, split_size: i64, dim: i64) -> Option<Vec<FlowTensors>> {
+    try_split(tensor, split_size, dim).ok()
+}
+
+/// Divide `tensor` em até `chunks` partes ao longo de `dim`
+pub fn try_chunk(tensor: &FlowTensors, chunks: i64, dim: i64) -> Result<Vec<FlowTensors>> {
+    let index = shape::normalize_dim(dim, tensor.dims())?;
+    try_split_with_sizes(tensor, &shape::chunk_sizes(tensor.dims()[index], chunks)?, dim)
+}
+
+/// Divide `tensor` em até `chunks` partes ao longo de `dim`
+pub fn chunk(tensor: &FlowTensors, chunks: i64, dim: i64) -> Option<Vec<FlowTensors>> {
+    try_chunk(tensor, chunks, dim).ok()
+}
+
+/// Separa `tensor` em um tensor por posição de `dim`, removendo essa dimensão (Unpack)
+pub fn try_unbind(tensor: &FlowTensors, dim: i64) -> Result<Vec<FlowTensors>> {
+    let index = shape::normalize_dim(dim, tensor.dims())?;
+    let num = tensor.dims()[index];
+    eager::execute_n(
+        "Unpack",
+        &[tensor],
+        &[Attr::Int("num", num), Attr::Int("axis", index as i64)],
+        num as usize,
+    )
+}
+
+/// Separa `tensor` em um tensor por posição de `dim`, removendo essa dimensão (Unpack)
+pub fn unbind(tensor: &FlowTensors, dim: i64) -> Option<Vec<FlowTensors>> {
+    try_unbind(tensor, dim).ok()
+}
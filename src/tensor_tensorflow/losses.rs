@@ -0,0 +1,478 @@
+// Loss Functions
+// As mesmas perdas do LibTorch (tensor_libortch::nn::loss) compostas de ops eager do
+// TensorFlow, com as fórmulas do PyTorch. A CTC usa a op CTCLoss, que fixa o blank na
+// última classe: as classes são reordenadas antes da chamada.
+
+use crate::dtype::DType;
+use crate::error::{CopperError, Result};
+use crate::tensor_libortch::nn::{check_ctc_lengths, Reduction};
+use crate::tensor_tensorflow::eager::{self, Attr};
+use crate::tensor_tensorflow::tensors_flow::FlowTensors;
+
+/// Aplica `reduction` à perda de cada elemento
+fn try_reduce(loss: FlowTensors, reduction: Reduction) -> Result<FlowTensors> {
+    match reduction {
+        Reduction::None => Ok(loss),
+        Reduction::Mean => loss.try_reshape(&[-1])?.try_mean_dim(0, false),
+        Reduction::Sum => loss.try_reshape(&[-1])?.try_sum_dim(0, false),
+    }
+}
+
+impl FlowTensors {
+    /// Converte uma comparação (bool) para o dtype de `self`, como máscara 0/1
+    fn try_mask_like(&self, flags: &FlowTensors) -> Result<FlowTensors> {
+        eager::execute("Cast", &[flags], &[Attr::Type("DstT", self.dtype())])
+    }
+
+    /// Multiplica por `weight`, quando houver
+    fn try_weighted(self, weight: Option<&FlowTensors>) -> Result<FlowTensors> {
+        match weight {
+            Some(weight) => self.try_mul(weight),
+            None => Ok(self),
+        }
+    }
+
+    /// Erro quadrático entre `self` e `target`, com a redução escolhida
+    pub fn try_mse_loss_with(&self, target: &FlowTensors, reduction: Reduction) -> Result<FlowTensors> {
+        let diff = self.try_sub(target)?;
+        try_reduce(diff.try_mul(&diff)?, reduction)
+    }
+
+    /// Erro quadrático entre `self` e `target`, com a redução escolhida
+    pub fn mse_loss_with(&self, target: &FlowTensors, reduction: Reduction) -> Option<FlowTensors> {
+        self.try_mse_loss_with(target, reduction).ok()
+    }
+
+    /// Erro quadrático médio entre `self` e `target`
+    pub fn try_mse_loss(&self, target: &FlowTensors) -> Result<FlowTensors> {
+        self.try_mse_loss_with(target, Reduction::Mean)
+    }
+
+    /// Erro quadrático médio entre `self` e `target`
+    pub fn mse_loss(&self, target: &FlowTensors) -> Option<FlowTensors> {
+        self.try_mse_loss(target).ok()
+    }
+
+    /// Cross entropy sobre logits `[N, C, *]` e alvos inteiros `[N, *]`: log_softmax na
+    /// dimensão das classes seguido de `nll_loss`
+    pub fn try_cross_entropy_loss_with(
+        &self,
+        target: &FlowTensors,
+        weight: Option<&FlowTensors>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Result<FlowTensors> {
+        let class_dim = if self.dims().len() >= 2 { 1 } else { 0 };
+        self.try_log_softmax(class_dim)?.try_nll_loss(target, weight, ignore_index, reduction)
+    }
+
+    /// Cross entropy com pesos por classe, `ignore_index` e a redução escolhida
+    pub fn cross_entropy_loss_with(
+        &self,
+        target: &FlowTensors,
+        weight: Option<&FlowTensors>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Option<FlowTensors> {
+        self.try_cross_entropy_loss_with(target, weight, ignore_index, reduction).ok()
+    }
+
+    /// Cross entropy média sobre logits `[N, C, *]` e alvos inteiros `[N, *]`
+    pub fn try_cross_entropy_loss(&self, target: &FlowTensors) -> Result<FlowTensors> {
+        self.try_cross_entropy_loss_with(target, None, None, Reduction::Mean)
+    }
+
+    /// Cross entropy média sobre logits `[N, C, *]` e alvos inteiros `[N, *]`
+    pub fn cross_entropy_loss(&self, target: &FlowTensors) -> Option<FlowTensors> {
+        self.try_cross_entropy_loss(target).ok()
+    }
+
+    /// Erro absoluto |self - target|
+    pub fn try_l1_loss(&self, target: &FlowTensors, reduction: Reduction) -> Result<FlowTensors> {
+        try_reduce(self.try_sub(target)?.try_unary("Abs")?, reduction)
+    }
+
+    /// Erro absoluto |self - target|
+    pub fn l1_loss(&self, target: &FlowTensors, reduction: Reduction) -> Option<FlowTensors> {
+        self.try_l1_loss(target, reduction).ok()
+    }
+
+    /// Huber sem redução: com `h = min(|d|, delta)`, `0.5 · h² + delta · (|d| - h)`
+    fn try_huber_elements(&self, target: &FlowTensors, delta: f64) -> Result<FlowTensors> {
+        let abs = self.try_sub(target)?.try_unary("Abs")?;
+        let clipped = eager::execute("Minimum", &[&abs, &abs.scalar_like(delta as f32)?], &[])?;
+        let quadratic = clipped.try_mul(&clipped)?.try_mul_scalar(0.5)?;
+        quadratic.try_add(&abs.try_sub(&clipped)?.try_mul_scalar(delta as f32)?)
+    }
+
+    /// Quadrática (dividida por `beta`) para erros menores que `beta`, linear acima
+    pub fn try_smooth_l1_loss(&self, target: &FlowTensors, beta: f64, reduction: Reduction) -> Result<FlowTensors> {
+        if beta < 0.0 {
+            return Err(CopperError::InvalidArgument(format!(
+                "smooth_l1_loss: beta não pode ser negativo, recebeu {}",
+                beta
+            )));
+        }
+        if beta == 0.0 {
+            return self.try_l1_loss(target, reduction);
+        }
+        // smooth_l1 com beta é a Huber com delta = beta dividida por beta
+        let loss = self.try_huber_elements(target, beta)?.try_div_scalar(beta as f32)?;
+        try_reduce(loss, reduction)
+    }
+
+    /// Quadrática (dividida por `beta`) para erros menores que `beta`, linear acima
+    pub fn smooth_l1_loss(&self, target: &FlowTensors, beta: f64, reduction: Reduction) -> Option<FlowTensors> {
+        self.try_smooth_l1_loss(target, beta, reduction).ok()
+    }
+
+    /// Huber: quadrática para erros menores que `delta`, linear com inclinação `delta` acima
+    pub fn try_huber_loss(&self, target: &FlowTensors, delta: f64, reduction: Reduction) -> Result<FlowTensors> {
+        if delta <= 0.0 {
+            return Err(CopperError::InvalidArgument(format!(
+                "huber_loss: delta deve ser positivo, recebeu {}",
+                delta
+            )));
+        }
+        try_reduce(self.try_huber_elements(target, delta)?, reduction)
+    }
+
+    /// Huber: quadrática para erros menores que `delta`, linear com inclinação `delta` acima
+    pub fn huber_loss(&self, target: &FlowTensors, delta: f64, reduction: Reduction) -> Option<FlowTensors> {
+        self.try_huber_loss(target, delta, reduction).ok()
+    }
+
+    /// Binary cross entropy sobre probabilidades em `[0, 1]`; como no PyTorch, os logaritmos
+    /// são limitados a -100 para que 0 e 1 não produzam infinitos
+    pub fn try_bce_loss(&self, target: &FlowTensors, weight: Option<&FlowTensors>, reduction: Reduction) -> Result<FlowTensors> {
+        let floor = self.scalar_like(-100.0)?;
+        let clamped_log = |t: &FlowTensors| -> Result<FlowTensors> {
+            eager::execute("Maximum", &[&t.try_unary("Log")?, &floor], &[])
+        };
+        let log_p = clamped_log(self)?;
+        let log_not_p = clamped_log(&self.try_rsub_scalar(1.0)?)?;
+        // -(y · log p + (1 - y) · log(1 - p)) = -(y · (log p - log(1 - p)) + log(1 - p))
+        let loss = target.try_mul(&log_p.try_sub(&log_not_p)?)?.try_add(&log_not_p)?.try_mul_scalar(-1.0)?;
+        try_reduce(loss.try_weighted(weight)?, reduction)
+    }
+
+    /// Binary cross entropy sobre probabilidades em `[0, 1]`
+    pub fn bce_loss(&self, target: &FlowTensors, weight: Option<&FlowTensors>, reduction: Reduction) -> Option<FlowTensors> {
+        self.try_bce_loss(target, weight, reduction).ok()
+    }
+
+    /// Sigmoid seguida de binary cross entropy, calculada como
+    /// `(1 - y) · x + (1 + (pos_weight - 1) · y) · softplus(-x)`, estável para logits grandes
+    pub fn try_bce_with_logits_loss(
+        &self,
+        target: &FlowTensors,
+        weight: Option<&FlowTensors>,
+        pos_weight: Option<&FlowTensors>,
+        reduction: Reduction,
+    ) -> Result<FlowTensors> {
+        let log_sigmoid_neg = self.try_mul_scalar(-1.0)?.try_softplus()?;
+        let positive_term = match pos_weight {
+            Some(pos_weight) => {
+                let scale = pos_weight.try_sub_scalar(1.0)?.try_mul(target)?.try_add_scalar(1.0)?;
+                scale.try_mul(&log_sigmoid_neg)?
+            }
+            None => log_sigmoid_neg,
+        };
+        let loss = target.try_rsub_scalar(1.0)?.try_mul(self)?.try_add(&positive_term)?;
+        try_reduce(loss.try_weighted(weight)?, reduction)
+    }
+
+    /// Sigmoid seguida de binary cross entropy, numericamente estável
+    pub fn bce_with_logits_loss(
+        &self,
+        target: &FlowTensors,
+        weight: Option<&FlowTensors>,
+        pos_weight: Option<&FlowTensors>,
+        reduction: Reduction,
+    ) -> Option<FlowTensors> {
+        self.try_bce_with_logits_loss(target, weight, pos_weight, reduction).ok()
+    }
+
+    /// Negative log likelihood sobre log-probabilidades `[N, C, *]` (ou `[C]`) e alvos
+    /// inteiros `[N, *]`. A classe de cada alvo é escolhida por OneHot; alvos fora de
+    /// `[0, C)` ou iguais a `ignore_index` recebem peso zero. Na média, divide pela soma
+    /// dos pesos dos alvos considerados
+    pub fn try_nll_loss(
+        &self,
+        target: &FlowTensors,
+        weight: Option<&FlowTensors>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Result<FlowTensors> {
+        let dims = self.dims();
+        if dims.is_empty() {
+            return Err(CopperError::InvalidArgument("nll_loss exige entrada com a dimensão de classes".to_string()));
+        }
+        let class_dim = if dims.len() >= 2 { 1 } else { 0 };
+        let mut expected = dims.to_vec();
+        let num_classes = expected.remove(class_dim);
+        if target.dims() != expected.as_slice() {
+            return Err(CopperError::ShapeMismatch { op: "nll_loss", left: dims.to_vec(), right: target.dims().to_vec() });
+        }
+        if !matches!(target.dtype(), DType::Int64 | DType::Int32) {
+            return Err(CopperError::DTypeMismatch { op: "nll_loss", expected: DType::Int64, found: target.dtype() });
+        }
+
+        // Classes na última dimensão, alinhadas com o one-hot dos alvos
+        let moved = if dims.len() > 2 {
+            let perm: Vec<i64> = (0..dims.len() as i64).filter(|&d| d != 1).chain(std::iter::once(1)).collect();
+            let perm = FlowTensors::try_from_slice(&perm, &[perm.len() as i64])?;
+            Some(eager::execute("Transpose", &[self, &perm], &[])?)
+        } else {
+            None
+        };
+        let moved = moved.as_ref().unwrap_or(self);
+        let target = target.try_to_dtype(DType::Int64)?;
+        let depth = FlowTensors::try_from_slice(&[num_classes as i32], &[])?;
+        let one_hot = eager::execute(
+            "OneHot",
+            &[&target, &depth, &self.scalar_like(1.0)?, &self.scalar_like(0.0)?],
+            &[],
+        )?;
+        let picked = one_hot.try_mul(moved)?.try_sum_dim(-1, false)?;
+        let mut weights = match weight {
+            Some(weight) => one_hot.try_mul(weight)?.try_sum_dim(-1, false)?,
+            None => one_hot.try_sum_dim(-1, false)?,
+        };
+        if let Some(ignore_index) = ignore_index {
+            let ignored = FlowTensors::try_from_slice(&[ignore_index], &[])?;
+            let kept = eager::execute("NotEqual", &[&target, &ignored], &[])?;
+            weights = weights.try_mul(&self.try_mask_like(&kept)?)?;
+        }
+        let loss = picked.try_mul(&weights)?.try_mul_scalar(-1.0)?;
+        match reduction {
+            Reduction::Mean => try_reduce(loss, Reduction::Sum)?.try_div(&try_reduce(weights, Reduction::Sum)?),
+            _ => try_reduce(loss, reduction),
+        }
+    }
+
+    /// Negative log likelihood sobre log-probabilidades `[N, C, *]` e alvos inteiros `[N, *]`
+    pub fn nll_loss(
+        &self,
+        target: &FlowTensors,
+        weight: Option<&FlowTensors>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Option<FlowTensors> {
+        self.try_nll_loss(target, weight, ignore_index, reduction).ok()
+    }
+
+    /// Divergência KL de `target` para a distribuição de `self` (em log-probabilidades);
+    /// termos com `target = 0` valem zero (Xlogy)
+    pub fn try_kl_div_loss(&self, target: &FlowTensors, log_target: bool, reduction: Reduction) -> Result<FlowTensors> {
+        let loss = if log_target {
+            target.try_unary("Exp")?.try_mul(&target.try_sub(self)?)?
+        } else {
+            eager::execute("Xlogy", &[target, target], &[])?.try_sub(&target.try_mul(self)?)?
+        };
+        try_reduce(loss, reduction)
+    }
+
+    /// Divergência KL de `target` para a distribuição de `self` (em log-probabilidades)
+    pub fn kl_div_loss(&self, target: &FlowTensors, log_target: bool, reduction: Reduction) -> Option<FlowTensors> {
+        self.try_kl_div_loss(target, log_target, reduction).ok()
+    }
+
+    /// `1 - cos(self, other)` para pares com `target = 1` e `max(0, cos - margin)` para
+    /// pares com `target = -1`; a similaridade é calculada na última dimensão
+    pub fn try_cosine_embedding_loss(
+        &self,
+        other: &FlowTensors,
+        target: &FlowTensors,
+        margin: f64,
+        reduction: Reduction,
+    ) -> Result<FlowTensors> {
+        // Mesmo epsilon do PyTorch nas normas, que evita divisão por zero
+        const EPSILON: f32 = 1e-12;
+        let dot = self.try_mul(other)?.try_sum_dim(-1, false)?;
+        let norm1 = self.try_mul(self)?.try_sum_dim(-1, false)?.try_add_scalar(EPSILON)?;
+        let norm2 = other.try_mul(other)?.try_sum_dim(-1, false)?.try_add_scalar(EPSILON)?;
+        let cos = dot.try_div(&norm1.try_mul(&norm2)?.try_unary("Sqrt")?)?;
+        let similar = dot.try_mask_like(&eager::execute("Equal", &[target, &target.scalar_like(1.0)?], &[])?)?;
+        let dissimilar = dot.try_mask_like(&eager::execute("Equal", &[target, &target.scalar_like(-1.0)?], &[])?)?;
+        let positive = cos.try_rsub_scalar(1.0)?.try_mul(&similar)?;
+        let negative = cos.try_sub_scalar(margin as f32)?.try_relu()?.try_mul(&dissimilar)?;
+        try_reduce(positive.try_add(&negative)?, reduction)
+    }
+
+    /// Perda de similaridade de cosseno entre pares rotulados com 1 ou -1
+    pub fn cosine_embedding_loss(
+        &self,
+        other: &FlowTensors,
+        target: &FlowTensors,
+        margin: f64,
+        reduction: Reduction,
+    ) -> Option<FlowTensors> {
+        self.try_cosine_embedding_loss(other, target, margin, reduction).ok()
+    }
+
+    /// `max(0, -target · (self - other) + margin)`
+    pub fn try_margin_ranking_loss(
+        &self,
+        other: &FlowTensors,
+        target: &FlowTensors,
+        margin: f64,
+        reduction: Reduction,
+    ) -> Result<FlowTensors> {
+        let loss = target.try_mul(&self.try_sub(other)?)?.try_rsub_scalar(margin as f32)?.try_relu()?;
+        try_reduce(loss, reduction)
+    }
+
+    /// `max(0, -target · (self - other) + margin)`
+    pub fn margin_ranking_loss(
+        &self,
+        other: &FlowTensors,
+        target: &FlowTensors,
+        margin: f64,
+        reduction: Reduction,
+    ) -> Option<FlowTensors> {
+        self.try_margin_ranking_loss(other, target, margin, reduction).ok()
+    }
+
+    /// Distância de norma `p` na última dimensão, como `pairwise_distance` (com `eps`
+    /// somado à diferença)
+    fn try_pairwise_distance(&self, other: &FlowTensors, p: f64) -> Result<FlowTensors> {
+        let abs = self.try_sub(other)?.try_add_scalar(1e-6)?.try_unary("Abs")?;
+        if p == 1.0 {
+            return abs.try_sum_dim(-1, false);
+        }
+        let powered = eager::execute("Pow", &[&abs, &abs.scalar_like(p as f32)?], &[])?;
+        let sum = powered.try_sum_dim(-1, false)?;
+        eager::execute("Pow", &[&sum, &sum.scalar_like((1.0 / p) as f32)?], &[])
+    }
+
+    /// `max(0, d(self, positive) - d(self, negative) + margin)`, com `self` como âncora e
+    /// `d` a distância de norma `p` na última dimensão
+    pub fn try_triplet_margin_loss(
+        &self,
+        positive: &FlowTensors,
+        negative: &FlowTensors,
+        margin: f64,
+        p: f64,
+        reduction: Reduction,
+    ) -> Result<FlowTensors> {
+        if p <= 0.0 {
+            return Err(CopperError::InvalidArgument(format!(
+                "triplet_margin_loss: p deve ser positivo, recebeu {}",
+                p
+            )));
+        }
+        let to_positive = self.try_pairwise_distance(positive, p)?;
+        let to_negative = self.try_pairwise_distance(negative, p)?;
+        let loss = to_positive.try_sub(&to_negative)?.try_add_scalar(margin as f32)?.try_relu()?;
+        try_reduce(loss, reduction)
+    }
+
+    /// `max(0, d(self, positive) - d(self, negative) + margin)`, com `self` como âncora
+    pub fn triplet_margin_loss(
+        &self,
+        positive: &FlowTensors,
+        negative: &FlowTensors,
+        margin: f64,
+        p: f64,
+        reduction: Reduction,
+    ) -> Option<FlowTensors> {
+        self.try_triplet_margin_loss(positive, negative, margin, p, reduction).ok()
+    }
+
+    /// Connectionist Temporal Classification sobre log-probabilidades `[T, N, C]`.
+    /// `targets` é `[N, S]` (com padding) ou 1-D com os alvos concatenados. A op CTCLoss
+    /// aplica log_softmax às entradas, o que não altera log-probabilidades já normalizadas
+    pub fn try_ctc_loss(
+        &self,
+        targets: &FlowTensors,
+        input_lengths: &[i64],
+        target_lengths: &[i64],
+        blank: i64,
+        reduction: Reduction,
+    ) -> Result<FlowTensors> {
+        check_ctc_lengths(input_lengths, target_lengths)?;
+        let dims = self.dims();
+        if dims.len() != 3 || dims[1] != input_lengths.len() as i64 {
+            return Err(CopperError::InvalidArgument(format!(
+                "ctc_loss espera log_probs [T, N, C] com N = {}, recebeu {:?}",
+                input_lengths.len(),
+                dims
+            )));
+        }
+        let num_classes = dims[2];
+        if !(0..num_classes).contains(&blank) {
+            return Err(CopperError::InvalidArgument(format!(
+                "ctc_loss: blank {} fora de [0, {})",
+                blank, num_classes
+            )));
+        }
+
+        // Alvos esparsos no formato da op: coordenadas [amostra, posição] e classes com o
+        // blank removido da numeração
+        let labels = targets.try_to_dtype(DType::Int64)?;
+        let labels = labels.try_data_as::<i64>()?;
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        let mut offset = 0usize;
+        for (sample, &length) in target_lengths.iter().enumerate() {
+            let start = match targets.dims() {
+                [_] => offset,
+                [_, max_length] if length <= *max_length => sample * *max_length as usize,
+                _ => {
+                    return Err(CopperError::InvalidArgument(format!(
+                        "ctc_loss espera alvos 1-D ou [N, S] com S >= {}, recebeu {:?}",
+                        length,
+                        targets.dims()
+                    )))
+                }
+            };
+            let row = labels.get(start..start + length as usize).ok_or_else(|| {
+                CopperError::InvalidArgument(format!("ctc_loss: alvo {} excede os dados de targets", sample))
+            })?;
+            for (position, &label) in row.iter().enumerate() {
+                if label == blank || !(0..num_classes).contains(&label) {
+                    return Err(CopperError::InvalidArgument(format!(
+                        "ctc_loss: classe de alvo inválida {} (blank = {})",
+                        label, blank
+                    )));
+                }
+                indices.extend([sample as i64, position as i64]);
+                values.push((if label > blank { label - 1 } else { label }) as i32);
+            }
+            offset += length as usize;
+        }
+
+        let order: Vec<i64> = (0..num_classes).filter(|&c| c != blank).chain(std::iter::once(blank)).collect();
+        let order = FlowTensors::try_from_slice(&order, &[num_classes])?;
+        let axis = FlowTensors::try_from_slice(&[2i64], &[])?;
+        let inputs = eager::execute("GatherV2", &[self, &order, &axis], &[])?;
+        let label_indices = FlowTensors::try_from_slice(&indices, &[values.len() as i64, 2])?;
+        let label_values = FlowTensors::try_from_slice(&values, &[values.len() as i64])?;
+        let lengths: Vec<i32> = input_lengths.iter().map(|&len| len as i32).collect();
+        let sequence_length = FlowTensors::try_from_slice(&lengths, &[lengths.len() as i64])?;
+        let mut outputs = eager::execute_n("CTCLoss", &[&inputs, &label_indices, &label_values, &sequence_length], &[], 2)?;
+        let loss = outputs.remove(0);
+
+        match reduction {
+            Reduction::Mean => {
+                let divisors: Vec<f32> = target_lengths.iter().map(|&len| len.max(1) as f32).collect();
+                let divisors = FlowTensors::try_from_slice(&divisors, &[divisors.len() as i64])?.try_to_dtype(loss.dtype())?;
+                try_reduce(loss.try_div(&divisors)?, Reduction::Mean)
+            }
+            _ => try_reduce(loss, reduction),
+        }
+    }
+
+    /// Connectionist Temporal Classification sobre log-probabilidades `[T, N, C]`
+    pub fn ctc_loss(
+        &self,
+        targets: &FlowTensors,
+        input_lengths: &[i64],
+        target_lengths: &[i64],
+        blank: i64,
+        reduction: Reduction,
+    ) -> Option<FlowTensors> {
+        self.try_ctc_loss(targets, input_lengths, target_lengths, blank, reduction).ok()
+    }
+}
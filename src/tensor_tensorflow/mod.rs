@@ -1,2 +1,9 @@
 pub mod ffi; 
-pub mod tensors_flow;
\ No newline at end of file
+pub mod tensors_flow;
+pub mod operators;
+pub mod reductions;
+pub mod activations;
+pub mod losses;
+pub mod indexing;
+pub mod join;
+pub(crate) mod eager;
\ No newline at end of file
@@ -0,0 +1,146 @@
+use crate::arith::impl_arithmetic_ops;
+use crate::dtype::{with_element_type, Element};
+use crate::error::{CopperError, Result};
+use crate::shape;
+use crate::tensor_tensorflow::eager;
+use crate::tensor_tensorflow::tensors_flow::FlowTensors;
+
+impl FlowTensors {
+    /// Executa uma op binária do TensorFlow, que já faz broadcasting
+    fn try_binary(&self, other: &FlowTensors, op: &'static str, tf_op: &'static str) -> Result<FlowTensors> {
+        shape::check_broadcast(op, self.dims(), other.dims())?;
+        eager::execute(tf_op, &[self, other], &[])
+    }
+
+    /// Tensor escalar (0-D) com o mesmo dtype deste tensor
+    pub(crate) fn scalar_like(&self, value: f32) -> Result<FlowTensors> {
+        with_element_type!(self.dtype(), T => FlowTensors::try_from_slice(&[T::from_f64(value as f64)], &[]))
+    }
+
+    /// Soma elemento a elemento, com broadcasting
+    pub fn try_add(&self, other: &FlowTensors) -> Result<FlowTensors> {
+        self.try_binary(other, "add", "AddV2")
+    }
+
+    /// Subtração elemento a elemento, com broadcasting
+    pub fn try_sub(&self, other: &FlowTensors) -> Result<FlowTensors> {
+        self.try_binary(other, "sub", "Sub")
+    }
+
+    /// Multiplicação elemento a elemento, com broadcasting
+    pub fn try_mul(&self, other: &FlowTensors) -> Result<FlowTensors> {
+        self.try_binary(other, "mul", "Mul")
+    }
+
+    /// Divisão elemento a elemento, com broadcasting
+    pub fn try_div(&self, other: &FlowTensors) -> Result<FlowTensors> {
+        self.try_binary(other, "div", "RealDiv")
+    }
+
+    /// Soma um escalar a cada elemento
+    pub fn try_add_scalar(&self, value: f32) -> Result<FlowTensors> {
+        self.try_add(&self.scalar_like(value)?)
+    }
+
+    /// Subtrai um escalar de cada elemento
+    pub fn try_sub_scalar(&self, value: f32) -> Result<FlowTensors> {
+        self.try_sub(&self.scalar_like(value)?)
+    }
+
+    /// Calcula `value - x` para cada elemento
+    pub fn try_rsub_scalar(&self, value: f32) -> Result<FlowTensors> {
+        self.scalar_like(value)?.try_sub(self)
+    }
+
+    /// Multiplica cada elemento por um escalar
+    pub fn try_mul_scalar(&self, value: f32) -> Result<FlowTensors> {
+        self.try_mul(&self.scalar_like(value)?)
+    }
+
+    /// Divide cada elemento por um escalar
+    pub fn try_div_scalar(&self, value: f32) -> Result<FlowTensors> {
+        self.try_div(&self.scalar_like(value)?)
+    }
+
+    /// Calcula `value / x` para cada elemento
+    pub fn try_rdiv_scalar(&self, value: f32) -> Result<FlowTensors> {
+        self.scalar_like(value)?.try_div(self)
+    }
+
+    // ==================== IN-PLACE ====================
+
+    /// Copia o resultado de uma op para o buffer deste tensor, que é mantido
+    fn try_assign_from(&mut self, result: FlowTensors, op: &'static str) -> Result<()> {
+        if result.dims() != self.dims() {
+            return Err(CopperError::ShapeMismatch {
+                op,
+                left: self.dims().to_vec(),
+                right: result.dims().to_vec(),
+            });
+        }
+        if result.dtype() != self.dtype() {
+            return Err(CopperError::DTypeMismatch { op, expected: self.dtype(), found: result.dtype() });
+        }
+        let bytes = shape::numel(self.dims()) * self.dtype().size();
+        if bytes == 0 {
+            return Ok(());
+        }
+        let src = unsafe { crate::tensor_tensorflow::ffi::GetTensorData(result.ptr) };
+        let dst = unsafe { crate::tensor_tensorflow::ffi::GetTensorData(self.ptr) };
+        if src.is_null() || dst.is_null() {
+            return Err(crate::error::backend_error("GetTensorData"));
+        }
+        unsafe { std::ptr::copy_nonoverlapping(src as *const u8, dst as *mut u8, bytes) };
+        Ok(())
+    }
+
+    /// Soma `other` a este tensor, mantendo o buffer (`+=`)
+    pub fn try_add_in_place(&mut self, other: &FlowTensors) -> Result<()> {
+        let result = self.try_add(other)?;
+        self.try_assign_from(result, "add_in_place")
+    }
+
+    /// Subtrai `other` deste tensor, mantendo o buffer (`-=`)
+    pub fn try_sub_in_place(&mut self, other: &FlowTensors) -> Result<()> {
+        let result = self.try_sub(other)?;
+        self.try_assign_from(result, "sub_in_place")
+    }
+
+    /// Multiplica este tensor por `other`, mantendo o buffer (`*=`)
+    pub fn try_mul_in_place(&mut self, other: &FlowTensors) -> Result<()> {
+        let result = self.try_mul(other)?;
+        self.try_assign_from(result, "mul_in_place")
+    }
+
+    /// Divide este tensor por `other`, mantendo o buffer (`/=`)
+    pub fn try_div_in_place(&mut self, other: &FlowTensors) -> Result<()> {
+        let result = self.try_div(other)?;
+        self.try_assign_from(result, "div_in_place")
+    }
+
+    /// Soma um escalar a cada elemento, no lugar
+    pub fn try_add_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        let result = self.try_add_scalar(value)?;
+        self.try_assign_from(result, "add_scalar_in_place")
+    }
+
+    /// Subtrai um escalar de cada elemento, no lugar
+    pub fn try_sub_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        let result = self.try_sub_scalar(value)?;
+        self.try_assign_from(result, "sub_scalar_in_place")
+    }
+
+    /// Multiplica cada elemento por um escalar, no lugar
+    pub fn try_mul_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        let result = self.try_mul_scalar(value)?;
+        self.try_assign_from(result, "mul_scalar_in_place")
+    }
+
+    /// Divide cada elemento por um escalar, no lugar
+    pub fn try_div_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        let result = self.try_div_scalar(value)?;
+        self.try_assign_from(result, "div_scalar_in_place")
+    }
+}
+
+impl_arithmetic_ops!(FlowTensors);
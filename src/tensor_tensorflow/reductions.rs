@@ -0,0 +1,254 @@
+use crate::dtype::DType;
+use crate::error::{CopperError, Result};
+use crate::shape;
+use crate::tensor_tensorflow::eager::{self, Attr};
+use crate::tensor_tensorflow::tensors_flow::FlowTensors;
+
+impl FlowTensors {
+    /// Tensor 1-D com o eixo a reduzir, validando `dim`
+    fn axis(&self, dim: i64) -> Result<(usize, FlowTensors)> {
+        let index = shape::normalize_dim(dim, self.dims())?;
+        Ok((index, FlowTensors::try_from_slice(&[index as i64], &[1])?))
+    }
+
+    /// Executa uma op de redução do TensorFlow (Sum, Mean, Max...) ao longo de `dim`
+    fn try_reduce_dim(&self, tf_op: &'static str, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        let (_, axis) = self.axis(dim)?;
+        eager::execute(tf_op, &[self, &axis], &[Attr::Bool("keep_dims", keepdim)])
+    }
+
+    /// ArgMax/ArgMin, que não têm `keep_dims` no TensorFlow
+    fn try_arg_reduce_dim(&self, tf_op: &'static str, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        let index = shape::normalize_dim(dim, self.dims())?;
+        let axis = FlowTensors::try_from_slice(&[index as i64], &[])?;
+        let indices = eager::execute(tf_op, &[self, &axis], &[Attr::Type("output_type", DType::Int64)])?;
+        if keepdim {
+            let mut dims = self.dims().to_vec();
+            dims[index] = 1;
+            indices.try_reshape(&dims)
+        } else {
+            Ok(indices)
+        }
+    }
+
+    /// Converte para bool antes de Any/All, que só aceitam tensores bool
+    fn try_bool_reduce_dim(&self, tf_op: &'static str, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        let flags = eager::execute("Cast", &[self], &[Attr::Type("DstT", DType::Bool)])?;
+        flags.try_reduce_dim(tf_op, dim, keepdim)
+    }
+
+    /// Reduz o tensor inteiro (achatado) com `reduce` e lê o escalar resultante
+    fn try_reduce_all<F>(&self, reduce: F) -> Result<f64>
+    where
+        F: Fn(&FlowTensors) -> Result<FlowTensors>,
+    {
+        let flat = self.try_reshape(&[-1])?;
+        let value = reduce(&flat)?.try_to_dtype(DType::Float64)?;
+        value
+            .try_data_as::<f64>()?
+            .first()
+            .copied()
+            .ok_or_else(|| CopperError::InvalidArgument("redução de tensor vazio".to_string()))
+    }
+
+    // ==================== FULL REDUCTIONS ====================
+
+    /// Calcula o produto de todos os elementos
+    pub fn try_prod(&self) -> Result<f32> {
+        Ok(self.try_reduce_all(|flat| flat.try_prod_dim(0, false))? as f32)
+    }
+
+    /// Calcula o produto de todos os elementos
+    pub fn prod(&self) -> Option<f32> {
+        self.try_prod().ok()
+    }
+
+    /// Calcula log(sum(exp(x))) de forma numericamente estável
+    pub fn try_logsumexp(&self) -> Result<f32> {
+        Ok(self.try_reduce_all(|flat| flat.try_logsumexp_dim(0, false))? as f32)
+    }
+
+    /// Calcula log(sum(exp(x))) de forma numericamente estável
+    pub fn logsumexp(&self) -> Option<f32> {
+        self.try_logsumexp().ok()
+    }
+
+    /// Calcula a norma euclidiana (L2) de todos os elementos
+    pub fn try_norm(&self) -> Result<f32> {
+        Ok(self.try_reduce_all(|flat| flat.try_norm_dim(0, false))? as f32)
+    }
+
+    /// Calcula a norma euclidiana (L2) de todos os elementos
+    pub fn norm(&self) -> Option<f32> {
+        self.try_norm().ok()
+    }
+
+    /// Verifica se algum elemento é diferente de zero
+    pub fn try_any(&self) -> Result<bool> {
+        Ok(self.try_reduce_all(|flat| flat.try_any_dim(0, false))? != 0.0)
+    }
+
+    /// Verifica se algum elemento é diferente de zero
+    pub fn any(&self) -> Option<bool> {
+        self.try_any().ok()
+    }
+
+    /// Verifica se todos os elementos são diferentes de zero
+    pub fn try_all(&self) -> Result<bool> {
+        Ok(self.try_reduce_all(|flat| flat.try_all_dim(0, false))? != 0.0)
+    }
+
+    /// Verifica se todos os elementos são diferentes de zero
+    pub fn all(&self) -> Option<bool> {
+        self.try_all().ok()
+    }
+
+    // ==================== REDUCTIONS ALONG A DIMENSION ====================
+
+    /// Soma ao longo de `dim`; com `keepdim` a dimensão reduzida fica com tamanho 1
+    pub fn try_sum_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        self.try_reduce_dim("Sum", dim, keepdim)
+    }
+
+    /// Soma ao longo de `dim`; com `keepdim` a dimensão reduzida fica com tamanho 1
+    pub fn sum_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_sum_dim(dim, keepdim).ok()
+    }
+
+    /// Média ao longo de `dim`
+    pub fn try_mean_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        self.try_reduce_dim("Mean", dim, keepdim)
+    }
+
+    /// Média ao longo de `dim`
+    pub fn mean_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_mean_dim(dim, keepdim).ok()
+    }
+
+    /// Valor máximo ao longo de `dim`
+    pub fn try_max_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        self.try_reduce_dim("Max", dim, keepdim)
+    }
+
+    /// Valor máximo ao longo de `dim`
+    pub fn max_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_max_dim(dim, keepdim).ok()
+    }
+
+    /// Valor mínimo ao longo de `dim`
+    pub fn try_min_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        self.try_reduce_dim("Min", dim, keepdim)
+    }
+
+    /// Valor mínimo ao longo de `dim`
+    pub fn min_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_min_dim(dim, keepdim).ok()
+    }
+
+    /// Variância (amostral, divide por n - 1) ao longo de `dim`
+    pub fn try_var_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        let (index, _) = self.axis(dim)?;
+        let mean = self.try_mean_dim(dim, true)?;
+        let centered = self.try_sub(&mean)?;
+        let squared = eager::execute("Square", &[&centered], &[])?;
+        let n = self.dims()[index];
+        squared.try_sum_dim(dim, keepdim)?.try_div_scalar((n - 1) as f32)
+    }
+
+    /// Variância (amostral, divide por n - 1) ao longo de `dim`
+    pub fn var_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_var_dim(dim, keepdim).ok()
+    }
+
+    /// Desvio padrão (amostral) ao longo de `dim`
+    pub fn try_std_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        let var = self.try_var_dim(dim, keepdim)?;
+        eager::execute("Sqrt", &[&var], &[])
+    }
+
+    /// Desvio padrão (amostral) ao longo de `dim`
+    pub fn std_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_std_dim(dim, keepdim).ok()
+    }
+
+    /// Índices (i64) do valor máximo ao longo de `dim`
+    pub fn try_argmax_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        self.try_arg_reduce_dim("ArgMax", dim, keepdim)
+    }
+
+    /// Índices (i64) do valor máximo ao longo de `dim`
+    pub fn argmax_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_argmax_dim(dim, keepdim).ok()
+    }
+
+    /// Índices (i64) do valor mínimo ao longo de `dim`
+    pub fn try_argmin_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        self.try_arg_reduce_dim("ArgMin", dim, keepdim)
+    }
+
+    /// Índices (i64) do valor mínimo ao longo de `dim`
+    pub fn argmin_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_argmin_dim(dim, keepdim).ok()
+    }
+
+    /// Produto ao longo de `dim`
+    pub fn try_prod_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        self.try_reduce_dim("Prod", dim, keepdim)
+    }
+
+    /// Produto ao longo de `dim`
+    pub fn prod_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_prod_dim(dim, keepdim).ok()
+    }
+
+    /// log(sum(exp(x))) ao longo de `dim`, subtraindo o máximo antes da exponencial
+    pub fn try_logsumexp_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        let max = self.try_max_dim(dim, true)?;
+        let shifted = self.try_sub(&max)?;
+        let exp = eager::execute("Exp", &[&shifted], &[])?;
+        let sum = exp.try_sum_dim(dim, keepdim)?;
+        let log = eager::execute("Log", &[&sum], &[])?;
+        if keepdim {
+            log.try_add(&max)
+        } else {
+            log.try_add(&self.try_max_dim(dim, false)?)
+        }
+    }
+
+    /// log(sum(exp(x))) ao longo de `dim`, subtraindo o máximo antes da exponencial
+    pub fn logsumexp_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_logsumexp_dim(dim, keepdim).ok()
+    }
+
+    /// Norma euclidiana (L2) ao longo de `dim`
+    pub fn try_norm_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        let squared = eager::execute("Square", &[self], &[])?;
+        let sum = squared.try_sum_dim(dim, keepdim)?;
+        eager::execute("Sqrt", &[&sum], &[])
+    }
+
+    /// Norma euclidiana (L2) ao longo de `dim`
+    pub fn norm_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_norm_dim(dim, keepdim).ok()
+    }
+
+    /// Tensor bool indicando se algum elemento ao longo de `dim` é diferente de zero
+    pub fn try_any_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        self.try_bool_reduce_dim("Any", dim, keepdim)
+    }
+
+    /// Tensor bool indicando se algum elemento ao longo de `dim` é diferente de zero
+    pub fn any_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_any_dim(dim, keepdim).ok()
+    }
+
+    /// Tensor bool indicando se todos os elementos ao longo de `dim` são diferentes de zero
+    pub fn try_all_dim(&self, dim: i64, keepdim: bool) -> Result<FlowTensors> {
+        self.try_bool_reduce_dim("All", dim, keepdim)
+    }
+
+    /// Tensor bool indicando se todos os elementos ao longo de `dim` são diferentes de zero
+    pub fn all_dim(&self, dim: i64, keepdim: bool) -> Option<FlowTensors> {
+        self.try_all_dim(dim, keepdim).ok()
+    }
+}
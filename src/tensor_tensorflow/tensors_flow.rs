@@ -1,10 +1,15 @@
+use crate::dtype::{cast_values, with_element_type, DType, Element};
+use crate::error::{check_call, check_ptr, CopperError, Result};
+use crate::shape;
+use crate::tensor_tensorflow::eager;
 use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_void, c_int};
 use std::ptr;
 
 pub struct FlowTensors {
-    ptr: *mut c_void, // Ponteiro para TF_Tensor*
+    pub(crate) ptr: *mut c_void, // Ponteiro para TF_Tensor*
     dims: Vec<i64>,   // Dimensões do tensor (suporta qualquer número de dimensões)
+    dtype: DType,     // Tipo dos elementos
 }
 
 pub struct TensorFlowModel {
@@ -13,54 +18,70 @@ pub struct TensorFlowModel {
 
 impl TensorFlowModel {
     /// Carrega um modelo SavedModel
-    pub fn load(model_path: &str, tags: &str) -> Option<Self> {
-        unsafe {
-            let model_path_c = CString::new(model_path).ok()?;
-            let tags_c = CString::new(tags).ok()?;
-            let handle = crate::tensor_tensorflow::ffi::LoadSavedModel(
+    pub fn try_load(model_path: &str, tags: &str) -> Result<Self> {
+        let model_path_c = CString::new(model_path)
+            .map_err(|_| CopperError::InvalidArgument(format!("caminho inválido: {:?}", model_path)))?;
+        let tags_c = CString::new(tags)
+            .map_err(|_| CopperError::InvalidArgument(format!("tags inválidas: {:?}", tags)))?;
+        let handle = unsafe {
+            crate::tensor_tensorflow::ffi::LoadSavedModel(
                 model_path_c.as_ptr(),
                 tags_c.as_ptr(),
-            );
-            if handle.is_null() {
-                return None;
-            }
-            Some(TensorFlowModel { handle })
-        }
+            )
+        };
+        let handle = check_ptr(handle, "LoadSavedModel")?;
+        Ok(TensorFlowModel { handle })
+    }
+
+    /// Carrega um modelo SavedModel
+    pub fn load(model_path: &str, tags: &str) -> Option<Self> {
+        TensorFlowModel::try_load(model_path, tags).ok()
     }
 
     /// Executa inferência no modelo
-    pub fn run(
+    pub fn try_run(
         &self,
         input_names: &[&str],
         input_tensors: &[&FlowTensors],
         output_names: &[&str],
-    ) -> Option<Vec<FlowTensors>> {
-        unsafe {
-            // Converter nomes de entrada e saída para C strings
-            let input_names_c: Vec<CString> = input_names
-                .iter()
-                .map(|&name| CString::new(name).unwrap())
-                .collect();
-            let input_names_ptr: Vec<*const c_char> =
-                input_names_c.iter().map(|cstr| cstr.as_ptr()).collect();
+    ) -> Result<Vec<FlowTensors>> {
+        if input_names.len() != input_tensors.len() {
+            return Err(CopperError::InvalidArgument(format!(
+                "{} nomes de entrada para {} tensores",
+                input_names.len(),
+                input_tensors.len()
+            )));
+        }
 
-            let output_names_c: Vec<CString> = output_names
+        // Converter nomes de entrada e saída para C strings
+        let to_c_strings = |names: &[&str]| -> Result<Vec<CString>> {
+            names
                 .iter()
-                .map(|&name| CString::new(name).unwrap())
-                .collect();
-            let output_names_ptr: Vec<*const c_char> =
-                output_names_c.iter().map(|cstr| cstr.as_ptr()).collect();
-
-            // Obter ponteiros dos tensores de entrada
-            let input_tensors_ptr: Vec<*mut c_void> =
-                input_tensors.iter().map(|tensor| tensor.ptr).collect();
-
-            // Preparar espaço para tensores de saída
-            let mut output_tensors_ptr: Vec<*mut c_void> =
-                vec![ptr::null_mut(); output_names.len()];
-
-            // Chamar RunSession
-            let result = crate::tensor_tensorflow::ffi::RunSession(
+                .map(|&name| {
+                    CString::new(name)
+                        .map_err(|_| CopperError::InvalidArgument(format!("nome inválido: {:?}", name)))
+                })
+                .collect()
+        };
+        let input_names_c = to_c_strings(input_names)?;
+        let input_names_ptr: Vec<*const c_char> =
+            input_names_c.iter().map(|cstr| cstr.as_ptr()).collect();
+
+        let output_names_c = to_c_strings(output_names)?;
+        let output_names_ptr: Vec<*const c_char> =
+            output_names_c.iter().map(|cstr| cstr.as_ptr()).collect();
+
+        // Obter ponteiros dos tensores de entrada
+        let input_tensors_ptr: Vec<*mut c_void> =
+            input_tensors.iter().map(|tensor| tensor.ptr).collect();
+
+        // Preparar espaço para tensores de saída
+        let mut output_tensors_ptr: Vec<*mut c_void> =
+            vec![ptr::null_mut(); output_names.len()];
+
+        // Chamar RunSession
+        let result = unsafe {
+            crate::tensor_tensorflow::ffi::RunSession(
                 self.handle,
                 input_names_ptr.as_ptr(),
                 input_tensors_ptr.as_ptr(),
@@ -68,31 +89,28 @@ impl TensorFlowModel {
                 output_names_ptr.as_ptr(),
                 output_tensors_ptr.as_mut_ptr(),
                 output_names.len() as c_int,
-            );
-
-            if result.is_null() {
-                return None;
-            }
-
-            // Criar FlowTensors para as saídas
-            let output_tensors: Vec<FlowTensors> = output_tensors_ptr
-                .into_iter()
-                .enumerate()
-                .filter_map(|(_i, ptr)| {
-                    if ptr.is_null() {
-                        return None;
-                    }
-                    // Supor dimensões de saída (você deve obter as dimensões reais do modelo)
-                    let dims = vec![1, 1]; // Exemplo: ajustar conforme o modelo
-                    Some(FlowTensors {
-                        ptr,
-                        dims,
-                    })
-                })
-                .collect();
+            )
+        };
+        check_ptr(result, "RunSession")?;
+
+        // Criar FlowTensors para as saídas com as dimensões reais de cada TF_Tensor
+        let output_tensors: Vec<FlowTensors> = output_tensors_ptr
+            .into_iter()
+            .filter(|ptr| !ptr.is_null())
+            .map(|ptr| unsafe { FlowTensors::from_raw(ptr) })
+            .collect::<Result<_>>()?;
+
+        Ok(output_tensors)
+    }
 
-            Some(output_tensors)
-        }
+    /// Executa inferência no modelo
+    pub fn run(
+        &self,
+        input_names: &[&str],
+        input_tensors: &[&FlowTensors],
+        output_names: &[&str],
+    ) -> Option<Vec<FlowTensors>> {
+        self.try_run(input_names, input_tensors, output_names).ok()
     }
 }
 
@@ -111,41 +129,118 @@ unsafe impl Send for TensorFlowModel {}
 unsafe impl Sync for TensorFlowModel {}
 
 impl FlowTensors {
+    /// Assume a posse de um TF_Tensor* e lê suas dimensões e dtype
+    ///
+    /// # Safety
+    /// `ptr` deve ser um TF_Tensor* válido que não seja liberado em outro lugar.
+    pub(crate) unsafe fn from_raw(ptr: *mut c_void) -> Result<Self> {
+        // A partir daqui o Drop libera o TF_Tensor mesmo se a leitura falhar
+        let mut tensor = FlowTensors { ptr, dims: Vec::new(), dtype: DType::Float32 };
+        let num_dims = crate::tensor_tensorflow::ffi::TFTensorNumDims(ptr);
+        tensor.dims = (0..num_dims.max(0))
+            .map(|i| crate::tensor_tensorflow::ffi::TFTensorDim(ptr, i))
+            .collect();
+        let code = check_call("TFTensorDType", || crate::tensor_tensorflow::ffi::TFTensorDType(ptr))?;
+        tensor.dtype = DType::from_code(code)?;
+        Ok(tensor)
+    }
+
+    /// Cria um tensor a partir de um array de valores e dimensões
+    pub fn try_new(values: &[f32], dims: &[i64]) -> Result<Self> {
+        FlowTensors::try_from_slice(values, dims)
+    }
+
     /// Cria um tensor a partir de um array de valores e dimensões
     pub fn new(values: &[f32], dims: &[i64]) -> Option<Self> {
-        unsafe {
-            let tensor_ptr = crate::tensor_tensorflow::ffi::CreateTFTensor(
-                values.as_ptr(),
+        FlowTensors::try_new(values, dims).ok()
+    }
+
+    /// Cria um tensor com qualquer tipo de elemento suportado (f32, f64, i64, i32, u8, bool)
+    pub fn try_from_slice<T: Element>(values: &[T], dims: &[i64]) -> Result<Self> {
+        shape::check_values(dims, values.len())?;
+        let tensor_ptr = unsafe {
+            crate::tensor_tensorflow::ffi::CreateTFTensor(
+                values.as_ptr() as *const c_void,
                 dims.as_ptr(),
                 dims.len() as c_int,
-            );
-            if tensor_ptr.is_null() {
-                return None;
-            }
-            Some(FlowTensors {
-                ptr: tensor_ptr,
-                dims: dims.to_vec(),
-            })
-        }
+                T::DTYPE.code(),
+            )
+        };
+        let tensor_ptr = check_ptr(tensor_ptr, "CreateTFTensor")?;
+        Ok(FlowTensors {
+            ptr: tensor_ptr,
+            dims: dims.to_vec(),
+            dtype: T::DTYPE,
+        })
+    }
+
+    /// Cria um tensor com qualquer tipo de elemento suportado (f32, f64, i64, i32, u8, bool)
+    pub fn from_slice<T: Element>(values: &[T], dims: &[i64]) -> Option<Self> {
+        FlowTensors::try_from_slice(values, dims).ok()
+    }
+
+    /// Obtém os dados do tensor como um slice de f32
+    pub fn try_data(&self) -> Result<&[f32]> {
+        self.try_data_as::<f32>()
     }
 
     /// Obtém os dados do tensor como um slice de f32
     pub fn data(&self) -> Option<&[f32]> {
-        unsafe {
-            let data_ptr = crate::tensor_tensorflow::ffi::GetTensorData(self.ptr);
-            if data_ptr.is_null() {
-                eprintln!("Erro: GetTensorData retornou ponteiro nulo");
-                return None;
-            }
-            let size = self.dims.iter().product::<i64>() as usize;
-            if size == 0 {
-                eprintln!("Aviso: Tensor com tamanho zero");
-                return Some(&[]);
+        self.data_as::<f32>()
+    }
+
+    /// Obtém os dados como `&[T]`; falha se `T` não corresponder ao dtype do tensor
+    pub fn try_data_as<T: Element>(&self) -> Result<&[T]> {
+        if self.dtype != T::DTYPE {
+            return Err(CopperError::DTypeMismatch {
+                op: "data_as",
+                expected: T::DTYPE,
+                found: self.dtype,
+            });
+        }
+        let size = shape::numel(&self.dims);
+        if size == 0 {
+            return Ok(&[]);
+        }
+        let data_ptr = unsafe { crate::tensor_tensorflow::ffi::GetTensorData(self.ptr) };
+        if data_ptr.is_null() {
+            return Err(crate::error::backend_error("GetTensorData"));
+        }
+        Ok(unsafe { std::slice::from_raw_parts(data_ptr as *const T, size) })
+    }
+
+    /// Obtém os dados como `&[T]`; falha se `T` não corresponder ao dtype do tensor
+    pub fn data_as<T: Element>(&self) -> Option<&[T]> {
+        match self.try_data_as() {
+            Ok(data) => Some(data),
+            Err(err) => {
+                log::error!("{}", err);
+                None
             }
-            Some(std::slice::from_raw_parts(data_ptr, size))
         }
     }
 
+    /// Obtém o tipo dos elementos
+    pub fn dtype(&self) -> DType {
+        self.dtype
+    }
+
+    /// Converte os elementos para outro dtype, sempre devolvendo uma cópia
+    pub fn try_to_dtype(&self, dtype: DType) -> Result<FlowTensors> {
+        with_element_type!(self.dtype, S => {
+            let values = self.try_data_as::<S>()?;
+            if dtype == self.dtype {
+                return FlowTensors::try_from_slice(values, &self.dims);
+            }
+            with_element_type!(dtype, D => FlowTensors::try_from_slice(&cast_values::<S, D>(values), &self.dims))
+        })
+    }
+
+    /// Converte os elementos para outro dtype, sempre devolvendo uma cópia
+    pub fn to_dtype(&self, dtype: DType) -> Option<FlowTensors> {
+        self.try_to_dtype(dtype).ok()
+    }
+
     /// Obtém as dimensões do tensor
     pub fn dims(&self) -> &[i64] {
         &self.dims
@@ -241,17 +336,53 @@ impl FlowTensors {
         FlowTensors::new(&values, dims)
     }
 
-    /// Reshape do tensor
+    /// Reshape do tensor; uma dimensão pode ser -1 para ser inferida
+    pub fn try_reshape(&self, new_dims: &[i64]) -> Result<FlowTensors> {
+        let shape = FlowTensors::try_from_slice(new_dims, &[new_dims.len() as i64])?;
+        eager::execute("Reshape", &[self, &shape], &[])
+    }
+
+    /// Reshape do tensor; uma dimensão pode ser -1 para ser inferida
     pub fn reshape(&self, new_dims: &[i64]) -> Option<FlowTensors> {
-        let old_size: i64 = self.dims.iter().product();
-        let new_size: i64 = new_dims.iter().product();
-        
-        if old_size != new_size {
-            return None;
+        self.try_reshape(new_dims).ok()
+    }
+
+    /// Produto de matrizes com as regras de `torch.matmul` (vetores, matrizes e lotes com broadcasting)
+    pub fn try_matmul(&self, other: &FlowTensors) -> Result<FlowTensors> {
+        let out_dims = shape::check_matmul(&self.dims, &other.dims)?;
+        if self.dims.len() == 2 && other.dims.len() == 2 {
+            return eager::execute("MatMul", &[self, other], &[]);
         }
 
-        let data = self.data()?.to_vec();
-        FlowTensors::new(&data, new_dims)
+        // Vetores 1-D viram matrizes [1, k] / [k, 1]; o resultado volta para a forma final
+        let left = if self.dims.len() == 1 { Some(self.try_reshape(&[1, self.dims[0]])?) } else { None };
+        let right = if other.dims.len() == 1 { Some(other.try_reshape(&[other.dims[0], 1])?) } else { None };
+        let product = eager::execute(
+            "BatchMatMulV2",
+            &[left.as_ref().unwrap_or(self), right.as_ref().unwrap_or(other)],
+            &[],
+        )?;
+        if product.dims == out_dims {
+            Ok(product)
+        } else {
+            product.try_reshape(&out_dims)
+        }
+    }
+
+    /// Produto de matrizes com as regras de `torch.matmul` (vetores, matrizes e lotes com broadcasting)
+    pub fn matmul(&self, other: &FlowTensors) -> Option<FlowTensors> {
+        self.try_matmul(other).ok()
+    }
+
+    /// Produto de matrizes em lote: `[b, n, k] x [b, k, m] -> [b, n, m]`
+    pub fn try_bmm(&self, other: &FlowTensors) -> Result<FlowTensors> {
+        shape::check_bmm(&self.dims, &other.dims)?;
+        eager::execute("BatchMatMulV2", &[self, other], &[])
+    }
+
+    /// Produto de matrizes em lote: `[b, n, k] x [b, k, m] -> [b, n, m]`
+    pub fn bmm(&self, other: &FlowTensors) -> Option<FlowTensors> {
+        self.try_bmm(other).ok()
     }
 }
 
@@ -0,0 +1,136 @@
+use super::{Backend, UnifiedTensor};
+use crate::error::{CopperError, Result};
+use crate::tensor_libortch::join as torch_join;
+use crate::tensor_libortch::tensor::Tensor as LibTorchTensor;
+use crate::tensor_tensorflow::join as tf_join;
+use crate::tensor_tensorflow::tensors_flow::FlowTensors;
+
+/// Junta os tensores no backend do primeiro, convertendo os demais se necessário
+fn try_join(
+    op: &'static str,
+    tensors: &[&UnifiedTensor],
+    dim: i64,
+    torch_op: fn(&[&LibTorchTensor], i64) -> Result<LibTorchTensor>,
+    tf_op: fn(&[&FlowTensors], i64) -> Result<FlowTensors>,
+) -> Result<UnifiedTensor> {
+    let backend = tensors
+        .first()
+        .map(|t| t.backend())
+        .ok_or_else(|| CopperError::InvalidArgument(format!("{} exige ao menos um tensor", op)))?;
+    let converted = tensors
+        .iter()
+        .map(|t| if t.backend() == backend { Ok(None) } else { t.try_to_backend(backend).map(Some) })
+        .collect::<Result<Vec<_>>>()?;
+    let aligned: Vec<&UnifiedTensor> = tensors
+        .iter()
+        .zip(&converted)
+        .map(|(&t, converted)| converted.as_ref().unwrap_or(t))
+        .collect();
+
+    match backend {
+        Backend::LibTorch => {
+            let inputs: Vec<&LibTorchTensor> = aligned
+                .iter()
+                .map(|t| match t {
+                    UnifiedTensor::LibTorch(t) => t,
+                    UnifiedTensor::TensorFlow(_) => unreachable!("tensores convertidos para o mesmo backend"),
+                })
+                .collect();
+            Ok(UnifiedTensor::LibTorch(torch_op(&inputs, dim)?))
+        }
+        Backend::TensorFlow => {
+            let inputs: Vec<&FlowTensors> = aligned
+                .iter()
+                .map(|t| match t {
+                    UnifiedTensor::TensorFlow(t) => t,
+                    UnifiedTensor::LibTorch(_) => unreachable!("tensores convertidos para o mesmo backend"),
+                })
+                .collect();
+            Ok(UnifiedTensor::TensorFlow(tf_op(&inputs, dim)?))
+        }
+    }
+}
+
+/// Divide o tensor no seu próprio backend
+fn try_divide(
+    tensor: &UnifiedTensor,
+    torch_op: impl FnOnce(&LibTorchTensor) -> Result<Vec<LibTorchTensor>>,
+    tf_op: impl FnOnce(&FlowTensors) -> Result<Vec<FlowTensors>>,
+) -> Result<Vec<UnifiedTensor>> {
+    match tensor {
+        UnifiedTensor::LibTorch(t) => Ok(torch_op(t)?.into_iter().map(UnifiedTensor::LibTorch).collect()),
+        UnifiedTensor::TensorFlow(t) => Ok(tf_op(t)?.into_iter().map(UnifiedTensor::TensorFlow).collect()),
+    }
+}
+
+/// Concatena os tensores ao longo de `dim`, no backend do primeiro
+pub fn try_cat(tensors: &[&UnifiedTensor], dim: i64) -> Result<UnifiedTensor> {
+    try_join("cat", tensors, dim, torch_join::try_cat, tf_join::try_cat)
+}
+
+/// Concatena os tensores ao longo de `dim`, no backend do primeiro
+pub fn cat(tensors: &[&UnifiedTensor], dim: i64) -> UnifiedTensor {
+    try_cat(tensors, dim).expect("Failed to concatenate tensors")
+}
+
+/// Empilha tensores de mesma forma em uma nova dimensão `dim`, no backend do primeiro
+pub fn try_stack(tensors: &[&UnifiedTensor], dim: i64) -> Result<UnifiedTensor> {
+    try_join("stack", tensors, dim, torch_join::try_stack, tf_join::try_stack)
+}
+
+/// Empilha tensores de mesma forma em uma nova dimensão `dim`, no backend do primeiro
+pub fn stack(tensors: &[&UnifiedTensor], dim: i64) -> UnifiedTensor {
+    try_stack(tensors, dim).expect("Failed to stack tensors")
+}
+
+/// Divide o tensor ao longo de `dim` em partes com os tamanhos informados
+pub fn try_split_with_sizes(tensor: &UnifiedTensor, sizes: &[i64], dim: i64) -> Result<Vec<UnifiedTensor>> {
+    try_divide(
+        tensor,
+        |t| torch_join::try_split_with_sizes(t, sizes, dim),
+        |t| tf_join::try_split_with_sizes(t, sizes, dim),
+    )
+}
+
+/// Divide o tensor ao longo de `dim` em partes com os tamanhos informados
+pub fn split_with_sizes(tensor: &UnifiedTensor, sizes: &[i64], dim: i64) -> Vec<UnifiedTensor> {
+    try_split_with_sizes(tensor, sizes, dim).expect("Failed to split tensor")
+}
+
+/// Divide o tensor ao longo de `dim` em blocos de `split_size` (o último pode ser menor)
+pub fn try_split(tensor: &UnifiedTensor, split_size: i64, dim: i64) -> Result<Vec<UnifiedTensor>> {
+    try_divide(
+        tensor,
+        |t| torch_join::try_split(t, split_size, dim),
+        |t| tf_join::try_split(t, split_size, dim),
+    )
+}
+
+/// Divide o tensor ao longo de `dim` em blocos de `split_size` (o último pode ser menor)
+pub fn split(tensor: &UnifiedTensor, split_size: i64, dim: i64) -> Vec<UnifiedTensor> {
+    try_split(tensor, split_size, dim).expect("Failed to split tensor")
+}
+
+/// Divide o tensor em até `chunks` partes ao longo de `dim`
+pub fn try_chunk(tensor: &UnifiedTensor, chunks: i64, dim: i64) -> Result<Vec<UnifiedTensor>> {
+    try_divide(
+        tensor,
+        |t| torch_join::try_chunk(t, chunks, dim),
+        |t| tf_join::try_chunk(t, chunks, dim),
+    )
+}
+
+/// Divide o tensor em até `chunks` partes ao longo de `dim`
+pub fn chunk(tensor: &UnifiedTensor, chunks: i64, dim: i64) -> Vec<UnifiedTensor> {
+    try_chunk(tensor, chunks, dim).expect("Failed to chunk tensor")
+}
+
+/// Separa o tensor em um tensor por posição de `dim`, removendo essa dimensão
+pub fn try_unbind(tensor: &UnifiedTensor, dim: i64) -> Result<Vec<UnifiedTensor>> {
+    try_divide(tensor, |t| torch_join::try_unbind(t, dim), |t| tf_join::try_unbind(t, dim))
+}
+
+/// Separa o tensor em um tensor por posição de `dim`, removendo essa dimensão
+pub fn unbind(tensor: &UnifiedTensor, dim: i64) -> Vec<UnifiedTensor> {
+    try_unbind(tensor, dim).expect("Failed to unbind tensor")
+}
@@ -4,7 +4,12 @@
 mod tensor;
 mod device;
 mod backend;
+mod random;
+pub mod join;
 
 pub use tensor::UnifiedTensor;
 pub use device::Device;
 pub use backend::Backend;
+pub use random::{manual_seed, try_manual_seed};
+pub(crate) use random::with_rng;
+pub use join::{cat, chunk, split, split_with_sizes, stack, unbind};
@@ -0,0 +1,29 @@
+// Random Number Generation
+// Gerador usado pelo caminho TensorFlow de rand, randn e dropout. Cada thread começa
+// com entropia do sistema; `manual_seed` fixa a semente desta thread e a do LibTorch.
+
+use crate::error::Result;
+use crate::tensor_libortch::tensor::Tensor as LibTorchTensor;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::cell::RefCell;
+
+thread_local! {
+    static RNG: RefCell<StdRng> = RefCell::new(StdRng::from_entropy());
+}
+
+/// Executa `f` com o gerador da thread atual
+pub(crate) fn with_rng<R>(f: impl FnOnce(&mut StdRng) -> R) -> R {
+    RNG.with(|rng| f(&mut rng.borrow_mut()))
+}
+
+/// Fixa a semente dos dois backends, tornando rand, randn e dropout reprodutíveis
+pub fn try_manual_seed(seed: u64) -> Result<()> {
+    with_rng(|rng| *rng = StdRng::seed_from_u64(seed));
+    LibTorchTensor::try_manual_seed(seed)
+}
+
+/// Fixa a semente dos dois backends, tornando rand, randn e dropout reprodutíveis
+pub fn manual_seed(seed: u64) {
+    try_manual_seed(seed).expect("Failed to set random seed")
+}
@@ -1,7 +1,11 @@
-use super::{Backend, Device};
+use super::{with_rng, Backend, Device};
+use crate::dtype::{with_element_type, DType, Element};
+use crate::error::{CopperError, Result};
+use crate::shape;
+use crate::tensor_libortch::nn::{check_dropout_p, DropoutKind, Reduction};
 use crate::tensor_libortch::tensor::Tensor as LibTorchTensor;
 use crate::tensor_tensorflow::tensors_flow::FlowTensors;
-use std::ops::{Add, Sub, Mul, Div};
+use crate::arith::impl_arithmetic_ops;
 
 /// Tensor unificado que pode usar LibTorch ou TensorFlow como backend
 pub enum UnifiedTensor {
@@ -11,106 +15,143 @@ pub enum UnifiedTensor {
 
 impl UnifiedTensor {
     /// Cria um tensor de zeros
-    pub fn zeros(rows: i32, cols: i32, backend: Backend, _device: Device) -> Self {
+    pub fn try_zeros(shape: &[i64], backend: Backend, _device: Device) -> Result<Self> {
         match backend {
-            Backend::LibTorch => {
-                let values = vec![0.0f32; (rows * cols) as usize];
-                UnifiedTensor::LibTorch(LibTorchTensor::from_values(&values, rows, cols))
-            }
+            Backend::LibTorch => Ok(UnifiedTensor::LibTorch(LibTorchTensor::try_zeros(shape)?)),
             Backend::TensorFlow => {
-                let values = vec![0.0f32; (rows * cols) as usize];
-                let dims = vec![rows as i64, cols as i64];
-                UnifiedTensor::TensorFlow(
-                    FlowTensors::new(&values, &dims)
-                        .expect("Failed to create TensorFlow tensor")
-                )
+                let values = vec![0.0f32; shape::numel(shape)];
+                Ok(UnifiedTensor::TensorFlow(FlowTensors::try_new(&values, shape)?))
             }
         }
     }
 
+    /// Cria um tensor de zeros
+    pub fn zeros(shape: &[i64], backend: Backend, device: Device) -> Self {
+        UnifiedTensor::try_zeros(shape, backend, device).expect("Failed to create tensor")
+    }
+
     /// Cria um tensor de uns
-    pub fn ones(rows: i32, cols: i32, backend: Backend, _device: Device) -> Self {
+    pub fn try_ones(shape: &[i64], backend: Backend, _device: Device) -> Result<Self> {
         match backend {
-            Backend::LibTorch => {
-                UnifiedTensor::LibTorch(LibTorchTensor::ones(rows, cols))
-            }
+            Backend::LibTorch => Ok(UnifiedTensor::LibTorch(LibTorchTensor::try_ones(shape)?)),
             Backend::TensorFlow => {
-                let values = vec![1.0f32; (rows * cols) as usize];
-                let dims = vec![rows as i64, cols as i64];
-                UnifiedTensor::TensorFlow(
-                    FlowTensors::new(&values, &dims)
-                        .expect("Failed to create TensorFlow tensor")
-                )
+                let values = vec![1.0f32; shape::numel(shape)];
+                Ok(UnifiedTensor::TensorFlow(FlowTensors::try_new(&values, shape)?))
             }
         }
     }
 
+    /// Cria um tensor de uns
+    pub fn ones(shape: &[i64], backend: Backend, device: Device) -> Self {
+        UnifiedTensor::try_ones(shape, backend, device).expect("Failed to create tensor")
+    }
+
     /// Cria um tensor com valores aleatórios
-    pub fn rand(rows: i32, cols: i32, backend: Backend, _device: Device) -> Self {
+    pub fn try_rand(shape: &[i64], backend: Backend, _device: Device) -> Result<Self> {
         match backend {
-            Backend::LibTorch => {
-                UnifiedTensor::LibTorch(LibTorchTensor::rand(rows, cols))
-            }
+            Backend::LibTorch => Ok(UnifiedTensor::LibTorch(LibTorchTensor::try_rand(shape)?)),
             Backend::TensorFlow => {
                 use rand::Rng;
-                let mut rng = rand::thread_rng();
-                let values: Vec<f32> = (0..(rows * cols))
-                    .map(|_| rng.gen::<f32>())
-                    .collect();
-                let dims = vec![rows as i64, cols as i64];
-                UnifiedTensor::TensorFlow(
-                    FlowTensors::new(&values, &dims)
-                        .expect("Failed to create TensorFlow tensor")
-                )
+                let values: Vec<f32> = with_rng(|rng| (0..shape::numel(shape)).map(|_| rng.gen::<f32>()).collect());
+                Ok(UnifiedTensor::TensorFlow(FlowTensors::try_new(&values, shape)?))
             }
         }
     }
 
+    /// Cria um tensor com valores aleatórios
+    pub fn rand(shape: &[i64], backend: Backend, device: Device) -> Self {
+        UnifiedTensor::try_rand(shape, backend, device).expect("Failed to create tensor")
+    }
+
     /// Cria um tensor a partir de valores
-    pub fn from_values(values: &[f32], rows: i32, cols: i32, backend: Backend, _device: Device) -> Self {
+    pub fn try_from_values(values: &[f32], shape: &[i64], backend: Backend, _device: Device) -> Result<Self> {
         match backend {
             Backend::LibTorch => {
-                UnifiedTensor::LibTorch(LibTorchTensor::from_values(values, rows, cols))
+                Ok(UnifiedTensor::LibTorch(LibTorchTensor::try_from_values(values, shape)?))
             }
             Backend::TensorFlow => {
-                let dims = vec![rows as i64, cols as i64];
-                UnifiedTensor::TensorFlow(
-                    FlowTensors::new(values, &dims)
-                        .expect("Failed to create TensorFlow tensor")
-                )
+                Ok(UnifiedTensor::TensorFlow(FlowTensors::try_new(values, shape)?))
             }
         }
     }
 
-    /// Retorna os dados do tensor como slice
-    pub fn as_slice(&self) -> &[f32] {
+    /// Cria um tensor a partir de valores
+    pub fn from_values(values: &[f32], shape: &[i64], backend: Backend, device: Device) -> Self {
+        UnifiedTensor::try_from_values(values, shape, backend, device)
+            .expect("Failed to create tensor")
+    }
+
+    /// Cria um tensor com qualquer tipo de elemento suportado (f32, f64, i64, i32, u8, bool)
+    pub fn try_from_slice<T: Element>(values: &[T], shape: &[i64], backend: Backend, _device: Device) -> Result<Self> {
+        match backend {
+            Backend::LibTorch => Ok(UnifiedTensor::LibTorch(LibTorchTensor::try_from_slice(values, shape)?)),
+            Backend::TensorFlow => Ok(UnifiedTensor::TensorFlow(FlowTensors::try_from_slice(values, shape)?)),
+        }
+    }
+
+    /// Cria um tensor com qualquer tipo de elemento suportado (f32, f64, i64, i32, u8, bool)
+    pub fn from_slice<T: Element>(values: &[T], shape: &[i64], backend: Backend, device: Device) -> Self {
+        UnifiedTensor::try_from_slice(values, shape, backend, device).expect("Failed to create tensor")
+    }
+
+    /// Copia os dados para um `Vec<T>`; falha se `T` não corresponder ao dtype do tensor
+    pub fn try_data_as<T: Element>(&self) -> Result<Vec<T>> {
+        match self {
+            UnifiedTensor::LibTorch(t) => t.try_data_as(),
+            UnifiedTensor::TensorFlow(t) => Ok(t.try_data_as()?.to_vec()),
+        }
+    }
+
+    /// Copia os dados para um `Vec<T>`; falha se `T` não corresponder ao dtype do tensor
+    pub fn data_as<T: Element>(&self) -> Vec<T> {
+        self.try_data_as().expect("Failed to get tensor data")
+    }
+
+    /// Retorna o tipo dos elementos
+    pub fn dtype(&self) -> DType {
         match self {
-            UnifiedTensor::LibTorch(t) => t.as_slice(),
-            UnifiedTensor::TensorFlow(t) => {
-                t.data().expect("Failed to get tensor data")
-            }
+            UnifiedTensor::LibTorch(t) => t.dtype(),
+            UnifiedTensor::TensorFlow(t) => t.dtype(),
         }
     }
 
+    /// Converte os elementos para outro dtype, mantendo o backend
+    pub fn try_to_dtype(&self, dtype: DType) -> Result<Self> {
+        match self {
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_to_dtype(dtype)?)),
+            UnifiedTensor::TensorFlow(t) => Ok(UnifiedTensor::TensorFlow(t.try_to_dtype(dtype)?)),
+        }
+    }
+
+    /// Converte os elementos para outro dtype, mantendo o backend
+    pub fn to_dtype(&self, dtype: DType) -> Self {
+        self.try_to_dtype(dtype).expect("Failed to convert tensor dtype")
+    }
+
+    /// Copia os dados f32 do tensor para um `Vec<f32>`
+    pub fn try_as_slice(&self) -> Result<Vec<f32>> {
+        match self {
+            UnifiedTensor::LibTorch(t) => t.try_as_slice(),
+            UnifiedTensor::TensorFlow(t) => Ok(t.try_data()?.to_vec()),
+        }
+    }
+
+    /// Copia os dados f32 do tensor para um `Vec<f32>`
+    pub fn as_slice(&self) -> Vec<f32> {
+        self.try_as_slice().expect("Failed to get tensor data")
+    }
+
     /// Imprime o tensor
     pub fn print(&self) {
         match self {
             UnifiedTensor::LibTorch(t) => t.print(),
             UnifiedTensor::TensorFlow(t) => {
-                println!("TensorFlow Tensor {:?}", t.dims());
-                let data = t.data().expect("Failed to get tensor data");
-                let dims = t.dims();
-                if dims.len() == 2 {
-                    for r in 0..dims[0] {
-                        for c in 0..dims[1] {
-                            let idx = (r * dims[1] + c) as usize;
-                            print!("{:.4} ", data[idx]);
-                        }
-                        println!();
-                    }
-                } else {
-                    println!("{:?}", data);
-                }
+                println!("TensorFlow Tensor {:?} ({})", t.dims(), t.dtype());
+                let values = with_element_type!(t.dtype(), T => {
+                    let data = t.try_data_as::<T>().expect("Failed to get tensor data");
+                    shape::format_values(data, t.dims())
+                });
+                print!("{}", values);
             }
         }
     }
@@ -123,402 +164,1502 @@ impl UnifiedTensor {
         }
     }
 
-    /// Retorna as dimensões do tensor (rows, cols)
-    pub fn shape(&self) -> (i32, i32) {
+    /// Retorna as dimensões do tensor
+    pub fn shape(&self) -> &[i64] {
         match self {
-            UnifiedTensor::LibTorch(t) => (t.rows, t.cols),
-            UnifiedTensor::TensorFlow(t) => {
-                let dims = t.dims();
-                if dims.len() == 2 {
-                    (dims[0] as i32, dims[1] as i32)
-                } else {
-                    (1, dims.iter().product::<i64>() as i32)
-                }
+            UnifiedTensor::LibTorch(t) => t.shape(),
+            UnifiedTensor::TensorFlow(t) => t.dims(),
+        }
+    }
+
+    /// Converte o tensor para outro backend
+    pub fn try_to_backend(&self, target_backend: Backend) -> Result<Self> {
+        if let UnifiedTensor::LibTorch(t) = self {
+            if target_backend == Backend::LibTorch {
+                return Ok(UnifiedTensor::LibTorch(t.try_clone()?));
+            }
+            if !t.is_contiguous() {
+                return UnifiedTensor::LibTorch(t.try_contiguous()?).try_to_backend(target_backend);
             }
         }
+
+        // TensorFlow tensors não implementam Clone facilmente, então recriamos
+        with_element_type!(self.dtype(), T => {
+            let data = self.try_data_as::<T>()?;
+            UnifiedTensor::try_from_slice(&data, self.shape(), target_backend, Device::CPU)
+        })
     }
 
     /// Converte o tensor para outro backend
     pub fn to_backend(&self, target_backend: Backend) -> Self {
-        if self.backend() == target_backend {
-            return match self {
-                UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.clone()),
-                UnifiedTensor::TensorFlow(_) => {
-                    // TensorFlow tensors não implementam Clone facilmente, então recriamos
-                    let data = self.as_slice().to_vec();
-                    let (rows, cols) = self.shape();
-                    UnifiedTensor::from_values(&data, rows, cols, target_backend, Device::CPU)
-                }
-            };
+        self.try_to_backend(target_backend).expect("Failed to convert tensor")
+    }
+
+    /// Aplica uma redução `*_dim` ao tensor achatado, reduzindo todos os elementos a um tensor 0-D
+    fn try_reduce_all(
+        &self,
+        torch_op: fn(&LibTorchTensor, i64, bool) -> Result<LibTorchTensor>,
+        tf_op: fn(&FlowTensors, i64, bool) -> Result<FlowTensors>,
+    ) -> Result<Self> {
+        let flat = [shape::numel(self.shape()) as i64];
+        match self {
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(torch_op(&t.try_reshape(&flat)?, 0, false)?)),
+            UnifiedTensor::TensorFlow(t) => Ok(UnifiedTensor::TensorFlow(tf_op(&t.try_reshape(&flat)?, 0, false)?)),
         }
+    }
 
-        let data = self.as_slice().to_vec();
-        let (rows, cols) = self.shape();
-        UnifiedTensor::from_values(&data, rows, cols, target_backend, Device::CPU)
+    /// Valor de um tensor com um único elemento, convertido para f32
+    fn try_item_f32(&self) -> Result<f32> {
+        let value = self.try_to_dtype(DType::Float32)?;
+        Ok(value.try_data_as::<f32>()?[0])
+    }
+
+    /// Calcula a soma de todos os elementos
+    pub fn try_sum(&self) -> Result<f32> {
+        match self {
+            UnifiedTensor::LibTorch(t) => t.try_sum(),
+            UnifiedTensor::TensorFlow(_) => {
+                self.try_reduce_all(LibTorchTensor::try_sum_dim, FlowTensors::try_sum_dim)?.try_item_f32()
+            }
+        }
     }
 
     /// Calcula a soma de todos os elementos
     pub fn sum(&self) -> f32 {
-        self.as_slice().iter().sum()
+        self.try_sum().expect("Failed to calculate sum")
+    }
+
+    /// Calcula a média de todos os elementos (inteiros e booleanos são promovidos a f64)
+    pub fn try_mean(&self) -> Result<f32> {
+        if let UnifiedTensor::LibTorch(t) = self {
+            return t.try_mean();
+        }
+        if !self.dtype().is_floating_point() {
+            return self.try_to_dtype(DType::Float64)?.try_mean();
+        }
+        self.try_reduce_all(LibTorchTensor::try_mean_dim, FlowTensors::try_mean_dim)?.try_item_f32()
     }
 
     /// Calcula a média de todos os elementos
     pub fn mean(&self) -> f32 {
-        let data = self.as_slice();
-        let sum: f32 = data.iter().sum();
-        sum / data.len() as f32
+        self.try_mean().expect("Failed to calculate mean")
+    }
+
+    /// Calcula o valor máximo
+    pub fn try_max(&self) -> Result<f32> {
+        self.try_reduce_all(LibTorchTensor::try_max_dim, FlowTensors::try_max_dim)?.try_item_f32()
     }
 
     /// Calcula o valor máximo
     pub fn max(&self) -> f32 {
-        self.as_slice().iter()
-            .cloned()
-            .fold(f32::NEG_INFINITY, f32::max)
+        self.try_max().expect("Failed to calculate max")
+    }
+
+    /// Calcula o valor mínimo
+    pub fn try_min(&self) -> Result<f32> {
+        self.try_reduce_all(LibTorchTensor::try_min_dim, FlowTensors::try_min_dim)?.try_item_f32()
     }
 
     /// Calcula o valor mínimo
     pub fn min(&self) -> f32 {
-        self.as_slice().iter()
-            .cloned()
-            .fold(f32::INFINITY, f32::min)
+        self.try_min().expect("Failed to calculate min")
     }
 
-    /// Transpõe o tensor (apenas para matrizes 2D)
-    pub fn transpose(&self) -> Self {
-        let (rows, cols) = self.shape();
-        let data = self.as_slice();
-        let mut transposed = vec![0.0f32; (rows * cols) as usize];
-        
+    /// Transpõe as duas últimas dimensões (apenas matrizes 2D no TensorFlow)
+    pub fn try_transpose(&self) -> Result<Self> {
+        if let UnifiedTensor::LibTorch(t) = self {
+            return Ok(UnifiedTensor::LibTorch(t.try_transpose()?));
+        }
+
+        let shape = self.shape();
+        if shape.len() != 2 {
+            return Err(CopperError::InvalidArgument(format!(
+                "transpose no TensorFlow exige uma matriz 2D, tensor tem forma {:?}",
+                shape
+            )));
+        }
+        let (rows, cols) = (shape[0] as usize, shape[1] as usize);
+        let data = self.try_as_slice()?;
+        let mut transposed = vec![0.0f32; rows * cols];
+
         for r in 0..rows {
             for c in 0..cols {
-                let src_idx = (r * cols + c) as usize;
-                let dst_idx = (c * rows + r) as usize;
-                transposed[dst_idx] = data[src_idx];
+                transposed[c * rows + r] = data[r * cols + c];
             }
         }
 
-        UnifiedTensor::from_values(&transposed, cols, rows, self.backend(), Device::CPU)
+        UnifiedTensor::try_from_values(&transposed, &[cols as i64, rows as i64], self.backend(), Device::CPU)
+    }
+
+    /// Transpõe as duas últimas dimensões (apenas matrizes 2D no TensorFlow)
+    pub fn transpose(&self) -> Self {
+        self.try_transpose().expect("Failed to transpose tensor")
     }
 
     /// Aplica uma função a cada elemento
-    pub fn map<F>(&self, f: F) -> Self 
+    pub fn try_map<F>(&self, f: F) -> Result<Self>
     where
         F: Fn(f32) -> f32,
     {
-        let data: Vec<f32> = self.as_slice().iter().map(|&x| f(x)).collect();
-        let (rows, cols) = self.shape();
-        UnifiedTensor::from_values(&data, rows, cols, self.backend(), Device::CPU)
+        if let UnifiedTensor::LibTorch(t) = self {
+            return Ok(UnifiedTensor::LibTorch(t.try_map(f)?));
+        }
+        let data: Vec<f32> = self.try_as_slice()?.into_iter().map(f).collect();
+        UnifiedTensor::try_from_values(&data, self.shape(), self.backend(), Device::CPU)
+    }
+
+    /// Aplica uma função a cada elemento
+    pub fn map<F>(&self, f: F) -> Self
+    where
+        F: Fn(f32) -> f32,
+    {
+        self.try_map(f).expect("Failed to map tensor")
+    }
+
+    /// Retorna uma cópia do tensor
+    pub fn try_clone_tensor(&self) -> Result<Self> {
+        self.try_to_backend(self.backend())
     }
 
     /// Retorna uma cópia do tensor
     pub fn clone_tensor(&self) -> Self {
-        let data = self.as_slice().to_vec();
-        let (rows, cols) = self.shape();
-        UnifiedTensor::from_values(&data, rows, cols, self.backend(), Device::CPU)
+        self.try_clone_tensor().expect("Failed to clone tensor")
     }
 
     // ==================== ACTIVATION FUNCTIONS ====================
-    
-    /// Aplica ReLU (apenas LibTorch)
-    pub fn relu(&self) -> Self {
+
+    /// Aplica a ativação do backend correspondente (ops nativas nos dois)
+    fn try_activation(
+        &self,
+        libtorch: impl FnOnce(&LibTorchTensor) -> Result<LibTorchTensor>,
+        tensorflow: impl FnOnce(&FlowTensors) -> Result<FlowTensors>,
+    ) -> Result<Self> {
         match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.relu()),
-            UnifiedTensor::TensorFlow(_) => {
-                // Implementação manual para TensorFlow
-                self.map(|x| if x > 0.0 { x } else { 0.0 })
-            }
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(libtorch(t)?)),
+            UnifiedTensor::TensorFlow(t) => Ok(UnifiedTensor::TensorFlow(tensorflow(t)?)),
         }
     }
 
-    /// Aplica Sigmoid (apenas LibTorch)
+    /// ReLU(x) = max(0, x)
+    pub fn try_relu(&self) -> Result<Self> {
+        self.try_activation(|t| t.try_relu(), |t| t.try_relu())
+    }
+
+    /// ReLU(x) = max(0, x)
+    pub fn relu(&self) -> Self {
+        self.try_relu().expect("Failed to apply ReLU")
+    }
+
+    /// Sigmoid(x) = 1 / (1 + e^(-x))
+    pub fn try_sigmoid(&self) -> Result<Self> {
+        self.try_activation(|t| t.try_sigmoid(), |t| t.try_sigmoid())
+    }
+
+    /// Sigmoid(x) = 1 / (1 + e^(-x))
     pub fn sigmoid(&self) -> Self {
+        self.try_sigmoid().expect("Failed to apply Sigmoid")
+    }
+
+    /// Tangente hiperbólica
+    pub fn try_tanh(&self) -> Result<Self> {
+        self.try_activation(|t| t.try_tanh(), |t| t.try_tanh())
+    }
+
+    /// Tangente hiperbólica
+    pub fn tanh(&self) -> Self {
+        self.try_tanh().expect("Failed to apply Tanh")
+    }
+
+    /// Softmax ao longo de `dim`
+    pub fn try_softmax(&self, dim: i64) -> Result<Self> {
+        self.try_activation(|t| t.try_softmax(dim), |t| t.try_softmax(dim))
+    }
+
+    /// Softmax ao longo de `dim`
+    pub fn softmax(&self, dim: i64) -> Self {
+        self.try_softmax(dim).expect("Failed to apply Softmax")
+    }
+
+    /// Logaritmo do softmax ao longo de `dim`
+    pub fn try_log_softmax(&self, dim: i64) -> Result<Self> {
+        self.try_activation(|t| t.try_log_softmax(dim), |t| t.try_log_softmax(dim))
+    }
+
+    /// Logaritmo do softmax ao longo de `dim`
+    pub fn log_softmax(&self, dim: i64) -> Self {
+        self.try_log_softmax(dim).expect("Failed to apply LogSoftmax")
+    }
+
+    /// GELU exata: 0.5 · x · (1 + erf(x / √2))
+    pub fn try_gelu(&self) -> Result<Self> {
+        self.try_activation(|t| t.try_gelu(), |t| t.try_gelu())
+    }
+
+    /// GELU exata: 0.5 · x · (1 + erf(x / √2))
+    pub fn gelu(&self) -> Self {
+        self.try_gelu().expect("Failed to apply GELU")
+    }
+
+    /// SiLU(x) = x · sigmoid(x)
+    pub fn try_silu(&self) -> Result<Self> {
+        self.try_activation(|t| t.try_silu(), |t| t.try_silu())
+    }
+
+    /// SiLU(x) = x · sigmoid(x)
+    pub fn silu(&self) -> Self {
+        self.try_silu().expect("Failed to apply SiLU")
+    }
+
+    /// LeakyReLU(x) = x se x > 0, senão negative_slope · x
+    pub fn try_leaky_relu(&self, negative_slope: f64) -> Result<Self> {
+        self.try_activation(|t| t.try_leaky_relu(negative_slope), |t| t.try_leaky_relu(negative_slope))
+    }
+
+    /// LeakyReLU(x) = x se x > 0, senão negative_slope · x
+    pub fn leaky_relu(&self, negative_slope: f64) -> Self {
+        self.try_leaky_relu(negative_slope).expect("Failed to apply LeakyReLU")
+    }
+
+    /// ELU(x) = x se x > 0, senão alpha · (e^x - 1)
+    pub fn try_elu(&self, alpha: f64) -> Result<Self> {
+        self.try_activation(|t| t.try_elu(alpha), |t| t.try_elu(alpha))
+    }
+
+    /// ELU(x) = x se x > 0, senão alpha · (e^x - 1)
+    pub fn elu(&self, alpha: f64) -> Self {
+        self.try_elu(alpha).expect("Failed to apply ELU")
+    }
+
+    /// SELU(x) = λ · ELU(x, α)
+    pub fn try_selu(&self) -> Result<Self> {
+        self.try_activation(|t| t.try_selu(), |t| t.try_selu())
+    }
+
+    /// SELU(x) = λ · ELU(x, α)
+    pub fn selu(&self) -> Self {
+        self.try_selu().expect("Failed to apply SELU")
+    }
+
+    /// Softplus(x) = ln(1 + e^x)
+    pub fn try_softplus(&self) -> Result<Self> {
+        self.try_activation(|t| t.try_softplus(), |t| t.try_softplus())
+    }
+
+    /// Softplus(x) = ln(1 + e^x)
+    pub fn softplus(&self) -> Self {
+        self.try_softplus().expect("Failed to apply Softplus")
+    }
+
+    /// Mish(x) = x · tanh(softplus(x))
+    pub fn try_mish(&self) -> Result<Self> {
+        self.try_activation(|t| t.try_mish(), |t| t.try_mish())
+    }
+
+    /// Mish(x) = x · tanh(softplus(x))
+    pub fn mish(&self) -> Self {
+        self.try_mish().expect("Failed to apply Mish")
+    }
+
+    /// Limita cada elemento ao intervalo `[min_val, max_val]`
+    pub fn try_hardtanh(&self, min_val: f64, max_val: f64) -> Result<Self> {
+        self.try_activation(|t| t.try_hardtanh(min_val, max_val), |t| t.try_hardtanh(min_val, max_val))
+    }
+
+    /// Limita cada elemento ao intervalo `[min_val, max_val]`
+    pub fn hardtanh(&self, min_val: f64, max_val: f64) -> Self {
+        self.try_hardtanh(min_val, max_val).expect("Failed to apply Hardtanh")
+    }
+
+    /// ReLU6(x) = min(max(0, x), 6)
+    pub fn try_relu6(&self) -> Result<Self> {
+        self.try_activation(|t| t.try_relu6(), |t| t.try_relu6())
+    }
+
+    /// ReLU6(x) = min(max(0, x), 6)
+    pub fn relu6(&self) -> Self {
+        self.try_relu6().expect("Failed to apply ReLU6")
+    }
+
+    // ==================== DROPOUT ====================
+
+    /// Zera cada elemento com probabilidade `p` e escala os demais por `1 / (1 - p)`;
+    /// com `train = false` devolve uma cópia. No TensorFlow a máscara vem do gerador
+    /// fixado por `manual_seed`
+    pub fn try_dropout(&self, p: f64, train: bool) -> Result<Self> {
         match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.sigmoid()),
-            UnifiedTensor::TensorFlow(_) => {
-                // Implementação manual para TensorFlow
-                self.map(|x| 1.0 / (1.0 + (-x).exp()))
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_dropout(p, train)?)),
+            UnifiedTensor::TensorFlow(_) => self.try_host_dropout(p, train, DropoutKind::Element, "dropout"),
+        }
+    }
+
+    /// Zera cada elemento com probabilidade `p` e escala os demais por `1 / (1 - p)`
+    pub fn dropout(&self, p: f64, train: bool) -> Self {
+        self.try_dropout(p, train).expect("Failed to apply dropout")
+    }
+
+    /// Zera canais inteiros de uma entrada `[N, C, H, W]` ou `[C, H, W]` com probabilidade `p`
+    pub fn try_dropout2d(&self, p: f64, train: bool) -> Result<Self> {
+        match self {
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_dropout2d(p, train)?)),
+            UnifiedTensor::TensorFlow(_) => self.try_host_dropout(p, train, DropoutKind::Feature, "dropout2d"),
+        }
+    }
+
+    /// Zera canais inteiros de uma entrada `[N, C, H, W]` ou `[C, H, W]` com probabilidade `p`
+    pub fn dropout2d(&self, p: f64, train: bool) -> Self {
+        self.try_dropout2d(p, train).expect("Failed to apply dropout2d")
+    }
+
+    /// Dropout para ativações SELU: mantém média zero e variância unitária
+    pub fn try_alpha_dropout(&self, p: f64, train: bool) -> Result<Self> {
+        match self {
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_alpha_dropout(p, train)?)),
+            UnifiedTensor::TensorFlow(_) => self.try_host_dropout(p, train, DropoutKind::Alpha, "alpha_dropout"),
+        }
+    }
+
+    /// Dropout para ativações SELU: mantém média zero e variância unitária
+    pub fn alpha_dropout(&self, p: f64, train: bool) -> Self {
+        self.try_alpha_dropout(p, train).expect("Failed to apply alpha dropout")
+    }
+
+    /// Sorteia a máscara no host e a aplica com `x * scale + shift`, as mesmas fórmulas
+    /// do LibTorch
+    fn try_host_dropout(&self, p: f64, train: bool, kind: DropoutKind, op: &str) -> Result<Self> {
+        use rand::Rng;
+        check_dropout_p(p, op)?;
+        if !train || p == 0.0 {
+            return self.try_clone_tensor();
+        }
+        if p == 1.0 {
+            return self.try_mul_scalar(0.0);
+        }
+        let dims = self.shape();
+        // Elementos consecutivos que compartilham um sorteio: o plano [H, W] no dropout2d
+        let group = match kind {
+            DropoutKind::Feature if dims.len() == 3 || dims.len() == 4 => {
+                (dims[dims.len() - 2] * dims[dims.len() - 1]).max(1) as usize
             }
+            DropoutKind::Feature => {
+                return Err(CopperError::InvalidArgument(format!(
+                    "dropout2d espera entrada 3-D ou 4-D, recebeu {:?}",
+                    dims
+                )))
+            }
+            _ => 1,
+        };
+        let numel = shape::numel(dims);
+        let keep: Vec<bool> = with_rng(|rng| (0..numel / group).map(|_| rng.gen_bool(1.0 - p)).collect());
+        let keep = keep.into_iter().flat_map(|k| std::iter::repeat_n(k, group));
+        let (scale, shift): (Vec<f32>, Vec<f32>) = match kind {
+            DropoutKind::Alpha => {
+                // Valor de saturação da SELU (-λα) e a transformação afim que restaura
+                // média e variância
+                let alpha = 1.7580993408473766;
+                let a = 1.0 / ((alpha * alpha * p + 1.0) * (1.0 - p)).sqrt();
+                let b = alpha * a * p;
+                keep.map(|k| if k { (a as f32, b as f32) } else { (0.0, (b - alpha * a) as f32) }).unzip()
+            }
+            _ => {
+                let scale = (1.0 / (1.0 - p)) as f32;
+                keep.map(|k| (if k { scale } else { 0.0 }, 0.0)).unzip()
+            }
+        };
+        let as_tensor = |values: &[f32]| -> Result<Self> {
+            let tensor = UnifiedTensor::try_from_values(values, dims, self.backend(), Device::CPU)?;
+            if tensor.dtype() == self.dtype() { Ok(tensor) } else { tensor.try_to_dtype(self.dtype()) }
+        };
+        let output = self.try_mul(&as_tensor(&scale)?)?;
+        match kind {
+            DropoutKind::Alpha => output.try_add(&as_tensor(&shift)?),
+            _ => Ok(output),
         }
     }
 
-    /// Aplica Tanh (apenas LibTorch)
-    pub fn tanh(&self) -> Self {
+    // ==================== LOSS FUNCTIONS ====================
+
+    /// Calcula uma perda no backend de `self`; `operands` (alvos e demais entradas) e
+    /// `weights` (opcionais) são convertidos para esse backend quando necessário
+    fn try_loss(
+        &self,
+        operands: &[&UnifiedTensor],
+        weights: &[Option<&UnifiedTensor>],
+        torch_op: impl FnOnce(&LibTorchTensor, &[&LibTorchTensor], &[Option<&LibTorchTensor>]) -> Result<LibTorchTensor>,
+        tf_op: impl FnOnce(&FlowTensors, &[&FlowTensors], &[Option<&FlowTensors>]) -> Result<FlowTensors>,
+    ) -> Result<Self> {
+        fn torch(tensor: &UnifiedTensor) -> &LibTorchTensor {
+            match tensor {
+                UnifiedTensor::LibTorch(t) => t,
+                UnifiedTensor::TensorFlow(_) => unreachable!("operandos convertidos para o mesmo backend"),
+            }
+        }
+        fn flow(tensor: &UnifiedTensor) -> &FlowTensors {
+            match tensor {
+                UnifiedTensor::TensorFlow(t) => t,
+                UnifiedTensor::LibTorch(_) => unreachable!("operandos convertidos para o mesmo backend"),
+            }
+        }
+
+        let backend = self.backend();
+        let convert = |t: &UnifiedTensor| -> Result<Option<UnifiedTensor>> {
+            if t.backend() == backend { Ok(None) } else { t.try_to_backend(backend).map(Some) }
+        };
+        let converted_operands = operands.iter().map(|t| convert(t)).collect::<Result<Vec<_>>>()?;
+        let converted_weights = weights
+            .iter()
+            .map(|w| w.map(convert).transpose().map(Option::flatten))
+            .collect::<Result<Vec<_>>>()?;
+        let operands: Vec<&UnifiedTensor> =
+            operands.iter().zip(&converted_operands).map(|(t, c)| c.as_ref().unwrap_or(*t)).collect();
+        let weights: Vec<Option<&UnifiedTensor>> =
+            weights.iter().zip(&converted_weights).map(|(w, c)| c.as_ref().or(*w)).collect();
+
         match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.tanh()),
-            UnifiedTensor::TensorFlow(_) => {
-                // Implementação manual para TensorFlow
-                self.map(|x| x.tanh())
+            UnifiedTensor::LibTorch(t) => {
+                let operands: Vec<_> = operands.iter().map(|u| torch(u)).collect();
+                let weights: Vec<_> = weights.iter().map(|w| w.map(torch)).collect();
+                Ok(UnifiedTensor::LibTorch(torch_op(t, &operands, &weights)?))
+            }
+            UnifiedTensor::TensorFlow(t) => {
+                let operands: Vec<_> = operands.iter().map(|u| flow(u)).collect();
+                let weights: Vec<_> = weights.iter().map(|w| w.map(flow)).collect();
+                Ok(UnifiedTensor::TensorFlow(tf_op(t, &operands, &weights)?))
             }
         }
     }
 
+    /// Erro quadrático médio entre `self` e `target`
+    pub fn try_mse_loss(&self, target: &UnifiedTensor) -> Result<Self> {
+        self.try_mse_loss_with(target, Reduction::Mean)
+    }
+
+    /// Erro quadrático médio entre `self` e `target`
+    pub fn mse_loss(&self, target: &UnifiedTensor) -> Self {
+        self.try_mse_loss(target).expect("Failed to calculate MSE loss")
+    }
+
+    /// Erro quadrático entre `self` e `target`, com a redução escolhida
+    pub fn try_mse_loss_with(&self, target: &UnifiedTensor, reduction: Reduction) -> Result<Self> {
+        self.try_loss(
+            &[target],
+            &[],
+            |t, o, _| t.try_mse_loss_with(o[0], reduction),
+            |t, o, _| t.try_mse_loss_with(o[0], reduction),
+        )
+    }
+
+    /// Erro quadrático entre `self` e `target`, com a redução escolhida
+    pub fn mse_loss_with(&self, target: &UnifiedTensor, reduction: Reduction) -> Self {
+        self.try_mse_loss_with(target, reduction).expect("Failed to calculate MSE loss")
+    }
+
+    /// Cross entropy média sobre logits `[N, C, *]` e alvos inteiros `[N, *]`
+    pub fn try_cross_entropy_loss(&self, target: &UnifiedTensor) -> Result<Self> {
+        self.try_cross_entropy_loss_with(target, None, None, Reduction::Mean)
+    }
+
+    /// Cross entropy média sobre logits `[N, C, *]` e alvos inteiros `[N, *]`
+    pub fn cross_entropy_loss(&self, target: &UnifiedTensor) -> Self {
+        self.try_cross_entropy_loss(target).expect("Failed to calculate cross entropy loss")
+    }
+
+    /// Cross entropy com um peso por classe em `weight`; alvos iguais a `ignore_index`
+    /// não contam
+    pub fn try_cross_entropy_loss_with(
+        &self,
+        target: &UnifiedTensor,
+        weight: Option<&UnifiedTensor>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Result<Self> {
+        self.try_loss(
+            &[target],
+            &[weight],
+            |t, o, w| t.try_cross_entropy_loss_with(o[0], w[0], ignore_index, reduction),
+            |t, o, w| t.try_cross_entropy_loss_with(o[0], w[0], ignore_index, reduction),
+        )
+    }
+
+    /// Cross entropy com pesos por classe, `ignore_index` e a redução escolhida
+    pub fn cross_entropy_loss_with(
+        &self,
+        target: &UnifiedTensor,
+        weight: Option<&UnifiedTensor>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Self {
+        self.try_cross_entropy_loss_with(target, weight, ignore_index, reduction)
+            .expect("Failed to calculate cross entropy loss")
+    }
+
+    /// Erro absoluto |self - target|
+    pub fn try_l1_loss(&self, target: &UnifiedTensor, reduction: Reduction) -> Result<Self> {
+        self.try_loss(
+            &[target],
+            &[],
+            |t, o, _| t.try_l1_loss(o[0], reduction),
+            |t, o, _| t.try_l1_loss(o[0], reduction),
+        )
+    }
+
+    /// Erro absoluto |self - target|
+    pub fn l1_loss(&self, target: &UnifiedTensor, reduction: Reduction) -> Self {
+        self.try_l1_loss(target, reduction).expect("Failed to calculate L1 loss")
+    }
+
+    /// Quadrática (dividida por `beta`) para erros menores que `beta`, linear acima
+    pub fn try_smooth_l1_loss(&self, target: &UnifiedTensor, beta: f64, reduction: Reduction) -> Result<Self> {
+        self.try_loss(
+            &[target],
+            &[],
+            |t, o, _| t.try_smooth_l1_loss(o[0], beta, reduction),
+            |t, o, _| t.try_smooth_l1_loss(o[0], beta, reduction),
+        )
+    }
+
+    /// Quadrática (dividida por `beta`) para erros menores que `beta`, linear acima
+    pub fn smooth_l1_loss(&self, target: &UnifiedTensor, beta: f64, reduction: Reduction) -> Self {
+        self.try_smooth_l1_loss(target, beta, reduction).expect("Failed to calculate smooth L1 loss")
+    }
+
+    /// Huber: quadrática para erros menores que `delta`, linear com inclinação `delta` acima
+    pub fn try_huber_loss(&self, target: &UnifiedTensor, delta: f64, reduction: Reduction) -> Result<Self> {
+        self.try_loss(
+            &[target],
+            &[],
+            |t, o, _| t.try_huber_loss(o[0], delta, reduction),
+            |t, o, _| t.try_huber_loss(o[0], delta, reduction),
+        )
+    }
+
+    /// Huber: quadrática para erros menores que `delta`, linear com inclinação `delta` acima
+    pub fn huber_loss(&self, target: &UnifiedTensor, delta: f64, reduction: Reduction) -> Self {
+        self.try_huber_loss(target, delta, reduction).expect("Failed to calculate Huber loss")
+    }
+
+    /// Binary cross entropy sobre probabilidades em `[0, 1]`; `weight` pondera cada elemento
+    pub fn try_bce_loss(&self, target: &UnifiedTensor, weight: Option<&UnifiedTensor>, reduction: Reduction) -> Result<Self> {
+        self.try_loss(
+            &[target],
+            &[weight],
+            |t, o, w| t.try_bce_loss(o[0], w[0], reduction),
+            |t, o, w| t.try_bce_loss(o[0], w[0], reduction),
+        )
+    }
+
+    /// Binary cross entropy sobre probabilidades em `[0, 1]`
+    pub fn bce_loss(&self, target: &UnifiedTensor, weight: Option<&UnifiedTensor>, reduction: Reduction) -> Self {
+        self.try_bce_loss(target, weight, reduction).expect("Failed to calculate BCE loss")
+    }
+
+    /// Sigmoid seguida de binary cross entropy; `pos_weight` pesa os exemplos positivos
+    pub fn try_bce_with_logits_loss(
+        &self,
+        target: &UnifiedTensor,
+        weight: Option<&UnifiedTensor>,
+        pos_weight: Option<&UnifiedTensor>,
+        reduction: Reduction,
+    ) -> Result<Self> {
+        self.try_loss(
+            &[target],
+            &[weight, pos_weight],
+            |t, o, w| t.try_bce_with_logits_loss(o[0], w[0], w[1], reduction),
+            |t, o, w| t.try_bce_with_logits_loss(o[0], w[0], w[1], reduction),
+        )
+    }
+
+    /// Sigmoid seguida de binary cross entropy; `pos_weight` pesa os exemplos positivos
+    pub fn bce_with_logits_loss(
+        &self,
+        target: &UnifiedTensor,
+        weight: Option<&UnifiedTensor>,
+        pos_weight: Option<&UnifiedTensor>,
+        reduction: Reduction,
+    ) -> Self {
+        self.try_bce_with_logits_loss(target, weight, pos_weight, reduction)
+            .expect("Failed to calculate BCE with logits loss")
+    }
+
+    /// Negative log likelihood sobre log-probabilidades `[N, C, *]` e alvos inteiros `[N, *]`
+    pub fn try_nll_loss(
+        &self,
+        target: &UnifiedTensor,
+        weight: Option<&UnifiedTensor>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Result<Self> {
+        self.try_loss(
+            &[target],
+            &[weight],
+            |t, o, w| t.try_nll_loss(o[0], w[0], ignore_index, reduction),
+            |t, o, w| t.try_nll_loss(o[0], w[0], ignore_index, reduction),
+        )
+    }
+
+    /// Negative log likelihood sobre log-probabilidades `[N, C, *]` e alvos inteiros `[N, *]`
+    pub fn nll_loss(
+        &self,
+        target: &UnifiedTensor,
+        weight: Option<&UnifiedTensor>,
+        ignore_index: Option<i64>,
+        reduction: Reduction,
+    ) -> Self {
+        self.try_nll_loss(target, weight, ignore_index, reduction).expect("Failed to calculate NLL loss")
+    }
+
+    /// Divergência KL de `target` para a distribuição de `self` (em log-probabilidades)
+    pub fn try_kl_div_loss(&self, target: &UnifiedTensor, log_target: bool, reduction: Reduction) -> Result<Self> {
+        self.try_loss(
+            &[target],
+            &[],
+            |t, o, _| t.try_kl_div_loss(o[0], log_target, reduction),
+            |t, o, _| t.try_kl_div_loss(o[0], log_target, reduction),
+        )
+    }
+
+    /// Divergência KL de `target` para a distribuição de `self` (em log-probabilidades)
+    pub fn kl_div_loss(&self, target: &UnifiedTensor, log_target: bool, reduction: Reduction) -> Self {
+        self.try_kl_div_loss(target, log_target, reduction).expect("Failed to calculate KL divergence loss")
+    }
+
+    /// Perda de similaridade de cosseno entre pares rotulados com 1 ou -1
+    pub fn try_cosine_embedding_loss(
+        &self,
+        other: &UnifiedTensor,
+        target: &UnifiedTensor,
+        margin: f64,
+        reduction: Reduction,
+    ) -> Result<Self> {
+        self.try_loss(
+            &[other, target],
+            &[],
+            |t, o, _| t.try_cosine_embedding_loss(o[0], o[1], margin, reduction),
+            |t, o, _| t.try_cosine_embedding_loss(o[0], o[1], margin, reduction),
+        )
+    }
+
+    /// Perda de similaridade de cosseno entre pares rotulados com 1 ou -1
+    pub fn cosine_embedding_loss(&self, other: &UnifiedTensor, target: &UnifiedTensor, margin: f64, reduction: Reduction) -> Self {
+        self.try_cosine_embedding_loss(other, target, margin, reduction)
+            .expect("Failed to calculate cosine embedding loss")
+    }
+
+    /// `max(0, -target · (self - other) + margin)`
+    pub fn try_margin_ranking_loss(
+        &self,
+        other: &UnifiedTensor,
+        target: &UnifiedTensor,
+        margin: f64,
+        reduction: Reduction,
+    ) -> Result<Self> {
+        self.try_loss(
+            &[other, target],
+            &[],
+            |t, o, _| t.try_margin_ranking_loss(o[0], o[1], margin, reduction),
+            |t, o, _| t.try_margin_ranking_loss(o[0], o[1], margin, reduction),
+        )
+    }
+
+    /// `max(0, -target · (self - other) + margin)`
+    pub fn margin_ranking_loss(&self, other: &UnifiedTensor, target: &UnifiedTensor, margin: f64, reduction: Reduction) -> Self {
+        self.try_margin_ranking_loss(other, target, margin, reduction)
+            .expect("Failed to calculate margin ranking loss")
+    }
+
+    /// `max(0, d(self, positive) - d(self, negative) + margin)`, com `self` como âncora
+    pub fn try_triplet_margin_loss(
+        &self,
+        positive: &UnifiedTensor,
+        negative: &UnifiedTensor,
+        margin: f64,
+        p: f64,
+        reduction: Reduction,
+    ) -> Result<Self> {
+        self.try_loss(
+            &[positive, negative],
+            &[],
+            |t, o, _| t.try_triplet_margin_loss(o[0], o[1], margin, p, reduction),
+            |t, o, _| t.try_triplet_margin_loss(o[0], o[1], margin, p, reduction),
+        )
+    }
+
+    /// `max(0, d(self, positive) - d(self, negative) + margin)`, com `self` como âncora
+    pub fn triplet_margin_loss(
+        &self,
+        positive: &UnifiedTensor,
+        negative: &UnifiedTensor,
+        margin: f64,
+        p: f64,
+        reduction: Reduction,
+    ) -> Self {
+        self.try_triplet_margin_loss(positive, negative, margin, p, reduction)
+            .expect("Failed to calculate triplet margin loss")
+    }
+
+    /// Connectionist Temporal Classification sobre log-probabilidades `[T, N, C]`
+    pub fn try_ctc_loss(
+        &self,
+        targets: &UnifiedTensor,
+        input_lengths: &[i64],
+        target_lengths: &[i64],
+        blank: i64,
+        reduction: Reduction,
+    ) -> Result<Self> {
+        self.try_loss(
+            &[targets],
+            &[],
+            |t, o, _| t.try_ctc_loss(o[0], input_lengths, target_lengths, blank, reduction),
+            |t, o, _| t.try_ctc_loss(o[0], input_lengths, target_lengths, blank, reduction),
+        )
+    }
+
+    /// Connectionist Temporal Classification sobre log-probabilidades `[T, N, C]`
+    pub fn ctc_loss(
+        &self,
+        targets: &UnifiedTensor,
+        input_lengths: &[i64],
+        target_lengths: &[i64],
+        blank: i64,
+        reduction: Reduction,
+    ) -> Self {
+        self.try_ctc_loss(targets, input_lengths, target_lengths, blank, reduction)
+            .expect("Failed to calculate CTC loss")
+    }
+
     // ==================== MATHEMATICAL FUNCTIONS ====================
-    
+
+    /// Aplica seno elemento a elemento
+    pub fn try_sin(&self) -> Result<Self> {
+        match self {
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_sin()?)),
+            UnifiedTensor::TensorFlow(_) => self.try_map(|x| x.sin()),
+        }
+    }
+
     /// Aplica seno elemento a elemento
     pub fn sin(&self) -> Self {
+        self.try_sin().expect("Failed to apply sin")
+    }
+
+    /// Aplica cosseno elemento a elemento
+    pub fn try_cos(&self) -> Result<Self> {
         match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.sin()),
-            UnifiedTensor::TensorFlow(_) => self.map(|x| x.sin()),
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_cos()?)),
+            UnifiedTensor::TensorFlow(_) => self.try_map(|x| x.cos()),
         }
     }
 
     /// Aplica cosseno elemento a elemento
     pub fn cos(&self) -> Self {
+        self.try_cos().expect("Failed to apply cos")
+    }
+
+    /// Aplica exponencial elemento a elemento
+    pub fn try_exp(&self) -> Result<Self> {
         match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.cos()),
-            UnifiedTensor::TensorFlow(_) => self.map(|x| x.cos()),
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_exp()?)),
+            UnifiedTensor::TensorFlow(_) => self.try_map(|x| x.exp()),
         }
     }
 
     /// Aplica exponencial elemento a elemento
     pub fn exp(&self) -> Self {
+        self.try_exp().expect("Failed to apply exp")
+    }
+
+    /// Aplica logaritmo natural elemento a elemento
+    pub fn try_log(&self) -> Result<Self> {
         match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.exp()),
-            UnifiedTensor::TensorFlow(_) => self.map(|x| x.exp()),
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_log()?)),
+            UnifiedTensor::TensorFlow(_) => self.try_map(|x| x.ln()),
         }
     }
 
     /// Aplica logaritmo natural elemento a elemento
     pub fn log(&self) -> Self {
+        self.try_log().expect("Failed to apply log")
+    }
+
+    /// Aplica raiz quadrada elemento a elemento
+    pub fn try_sqrt(&self) -> Result<Self> {
         match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.log()),
-            UnifiedTensor::TensorFlow(_) => self.map(|x| x.ln()),
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_sqrt()?)),
+            UnifiedTensor::TensorFlow(_) => self.try_map(|x| x.sqrt()),
         }
     }
 
     /// Aplica raiz quadrada elemento a elemento
     pub fn sqrt(&self) -> Self {
+        self.try_sqrt().expect("Failed to apply sqrt")
+    }
+
+    /// Aplica valor absoluto elemento a elemento
+    pub fn try_abs(&self) -> Result<Self> {
         match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.sqrt()),
-            UnifiedTensor::TensorFlow(_) => self.map(|x| x.sqrt()),
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_abs()?)),
+            UnifiedTensor::TensorFlow(_) => self.try_map(|x| x.abs()),
         }
     }
 
     /// Aplica valor absoluto elemento a elemento
     pub fn abs(&self) -> Self {
+        self.try_abs().expect("Failed to apply abs")
+    }
+
+    /// Eleva cada elemento a uma potência
+    pub fn try_pow(&self, exponent: f32) -> Result<Self> {
         match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.abs()),
-            UnifiedTensor::TensorFlow(_) => self.map(|x| x.abs()),
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_pow(exponent)?)),
+            UnifiedTensor::TensorFlow(_) => self.try_map(|x| x.powf(exponent)),
         }
     }
 
     /// Eleva cada elemento a uma potência
     pub fn pow(&self, exponent: f32) -> Self {
-        match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.pow(exponent)),
-            UnifiedTensor::TensorFlow(_) => self.map(|x| x.powf(exponent)),
-        }
+        self.try_pow(exponent).expect("Failed to apply pow")
     }
 
     // ==================== TENSOR CREATION ====================
-    
+
     /// Cria tensor com distribuição normal
-    pub fn randn(rows: i32, cols: i32, backend: Backend, _device: Device) -> Self {
+    pub fn try_randn(shape: &[i64], backend: Backend, _device: Device) -> Result<Self> {
         match backend {
-            Backend::LibTorch => UnifiedTensor::LibTorch(LibTorchTensor::randn(rows, cols)),
+            Backend::LibTorch => Ok(UnifiedTensor::LibTorch(LibTorchTensor::try_randn(shape)?)),
             Backend::TensorFlow => {
-                use rand::Rng;
                 use rand_distr::{Normal, Distribution};
                 let normal = Normal::new(0.0, 1.0).unwrap();
-                let mut rng = rand::thread_rng();
-                let values: Vec<f32> = (0..(rows * cols))
-                    .map(|_| normal.sample(&mut rng))
-                    .collect();
-                let dims = vec![rows as i64, cols as i64];
-                UnifiedTensor::TensorFlow(
-                    FlowTensors::new(&values, &dims).expect("Failed to create TensorFlow tensor")
-                )
+                let values: Vec<f32> = with_rng(|rng| (0..shape::numel(shape)).map(|_| normal.sample(rng)).collect());
+                Ok(UnifiedTensor::TensorFlow(FlowTensors::try_new(&values, shape)?))
             }
         }
     }
 
+    /// Cria tensor com distribuição normal
+    pub fn randn(shape: &[i64], backend: Backend, device: Device) -> Self {
+        UnifiedTensor::try_randn(shape, backend, device).expect("Failed to create tensor")
+    }
+
     /// Cria matriz identidade
-    pub fn eye(size: i32, backend: Backend, _device: Device) -> Self {
+    pub fn try_eye(size: i32, backend: Backend, _device: Device) -> Result<Self> {
         match backend {
-            Backend::LibTorch => UnifiedTensor::LibTorch(LibTorchTensor::eye(size)),
+            Backend::LibTorch => Ok(UnifiedTensor::LibTorch(LibTorchTensor::try_eye(size)?)),
             Backend::TensorFlow => {
-                let mut values = vec![0.0f32; (size * size) as usize];
+                let mut values = vec![0.0f32; (size.max(0) * size.max(0)) as usize];
                 for i in 0..size {
                     values[(i * size + i) as usize] = 1.0;
                 }
                 let dims = vec![size as i64, size as i64];
-                UnifiedTensor::TensorFlow(
-                    FlowTensors::new(&values, &dims).expect("Failed to create TensorFlow tensor")
-                )
+                Ok(UnifiedTensor::TensorFlow(FlowTensors::try_new(&values, &dims)?))
             }
         }
     }
 
+    /// Cria matriz identidade
+    pub fn eye(size: i32, backend: Backend, device: Device) -> Self {
+        UnifiedTensor::try_eye(size, backend, device).expect("Failed to create tensor")
+    }
+
     /// Cria tensor de zeros com a mesma forma
-    pub fn zeros_like(&self) -> Self {
+    pub fn try_zeros_like(&self) -> Result<Self> {
         match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.zeros_like()),
-            UnifiedTensor::TensorFlow(_) => {
-                let (rows, cols) = self.shape();
-                UnifiedTensor::zeros(rows, cols, Backend::TensorFlow, Device::CPU)
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_zeros_like()?)),
+            UnifiedTensor::TensorFlow(t) => {
+                UnifiedTensor::try_zeros(t.dims(), Backend::TensorFlow, Device::CPU)
             }
         }
     }
 
+    /// Cria tensor de zeros com a mesma forma
+    pub fn zeros_like(&self) -> Self {
+        self.try_zeros_like().expect("Failed to create tensor")
+    }
+
     /// Cria tensor de ones com a mesma forma
-    pub fn ones_like(&self) -> Self {
+    pub fn try_ones_like(&self) -> Result<Self> {
         match self {
-            UnifiedTensor::LibTorch(t) => UnifiedTensor::LibTorch(t.ones_like()),
-            UnifiedTensor::TensorFlow(_) => {
-                let (rows, cols) = self.shape();
-                UnifiedTensor::ones(rows, cols, Backend::TensorFlow, Device::CPU)
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_ones_like()?)),
+            UnifiedTensor::TensorFlow(t) => {
+                UnifiedTensor::try_ones(t.dims(), Backend::TensorFlow, Device::CPU)
             }
         }
     }
 
+    /// Cria tensor de ones com a mesma forma
+    pub fn ones_like(&self) -> Self {
+        self.try_ones_like().expect("Failed to create tensor")
+    }
+
     // ==================== STATISTICAL FUNCTIONS ====================
-    
+
+    /// Calcula o desvio padrão
+    pub fn try_std(&self) -> Result<f32> {
+        match self {
+            UnifiedTensor::LibTorch(t) => t.try_std(),
+            UnifiedTensor::TensorFlow(_) => Ok(self.try_var()?.sqrt()),
+        }
+    }
+
     /// Calcula o desvio padrão
     pub fn std(&self) -> f32 {
+        self.try_std().expect("Failed to calculate std")
+    }
+
+    /// Calcula a variância
+    pub fn try_var(&self) -> Result<f32> {
         match self {
-            UnifiedTensor::LibTorch(t) => t.std(),
+            UnifiedTensor::LibTorch(t) => t.try_var(),
             UnifiedTensor::TensorFlow(_) => {
-                let mean = self.mean();
-                let variance = self.as_slice()
+                let mean = self.try_mean()?;
+                let data = self.try_as_slice()?;
+                Ok(data
                     .iter()
                     .map(|&x| (x - mean).powi(2))
-                    .sum::<f32>() / self.as_slice().len() as f32;
-                variance.sqrt()
+                    .sum::<f32>() / data.len() as f32)
             }
         }
     }
 
     /// Calcula a variância
     pub fn var(&self) -> f32 {
+        self.try_var().expect("Failed to calculate var")
+    }
+
+    /// Índice (i64, tensor 0-D) do valor máximo no tensor achatado
+    pub fn try_argmax(&self) -> Result<Self> {
+        self.try_reduce_all(LibTorchTensor::try_argmax_dim, FlowTensors::try_argmax_dim)
+    }
+
+    /// Índice (i64, tensor 0-D) do valor máximo no tensor achatado
+    pub fn argmax(&self) -> Self {
+        self.try_argmax().expect("Failed to calculate argmax")
+    }
+
+    /// Índice do valor máximo no tensor achatado, como número
+    pub fn try_argmax_index(&self) -> Result<i64> {
+        let index = self.try_argmax()?;
+        Ok(index.try_data_as::<i64>()?[0])
+    }
+
+    /// Índice do valor máximo no tensor achatado, como número
+    pub fn argmax_index(&self) -> i64 {
+        self.try_argmax_index().expect("Failed to calculate argmax")
+    }
+
+    /// Índice (i64, tensor 0-D) do valor mínimo no tensor achatado
+    pub fn try_argmin(&self) -> Result<Self> {
+        self.try_reduce_all(LibTorchTensor::try_argmin_dim, FlowTensors::try_argmin_dim)
+    }
+
+    /// Índice (i64, tensor 0-D) do valor mínimo no tensor achatado
+    pub fn argmin(&self) -> Self {
+        self.try_argmin().expect("Failed to calculate argmin")
+    }
+
+    /// Índice do valor mínimo no tensor achatado, como número
+    pub fn try_argmin_index(&self) -> Result<i64> {
+        let index = self.try_argmin()?;
+        Ok(index.try_data_as::<i64>()?[0])
+    }
+
+    /// Índice do valor mínimo no tensor achatado, como número
+    pub fn argmin_index(&self) -> i64 {
+        self.try_argmin_index().expect("Failed to calculate argmin")
+    }
+
+    /// Calcula o produto de todos os elementos
+    pub fn try_prod(&self) -> Result<f32> {
         match self {
-            UnifiedTensor::LibTorch(t) => t.var(),
-            UnifiedTensor::TensorFlow(_) => {
-                let mean = self.mean();
-                self.as_slice()
-                    .iter()
-                    .map(|&x| (x - mean).powi(2))
-                    .sum::<f32>() / self.as_slice().len() as f32
-            }
+            UnifiedTensor::LibTorch(t) => t.try_prod(),
+            UnifiedTensor::TensorFlow(t) => t.try_prod(),
         }
     }
 
-    /// Retorna o índice do valor máximo
-    pub fn argmax(&self) -> i32 {
+    /// Calcula o produto de todos os elementos
+    pub fn prod(&self) -> f32 {
+        self.try_prod().expect("Failed to calculate prod")
+    }
+
+    /// Calcula log(sum(exp(x))) de forma numericamente estável
+    pub fn try_logsumexp(&self) -> Result<f32> {
         match self {
-            UnifiedTensor::LibTorch(t) => t.argmax(),
-            UnifiedTensor::TensorFlow(_) => {
-                let data = self.as_slice();
-                let (max_idx, _) = data.iter()
-                    .enumerate()
-                    .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
-                    .unwrap();
-                max_idx as i32
-            }
+            UnifiedTensor::LibTorch(t) => t.try_logsumexp(),
+            UnifiedTensor::TensorFlow(t) => t.try_logsumexp(),
         }
     }
 
-    /// Retorna o índice do valor mínimo
-    pub fn argmin(&self) -> i32 {
+    /// Calcula log(sum(exp(x))) de forma numericamente estável
+    pub fn logsumexp(&self) -> f32 {
+        self.try_logsumexp().expect("Failed to calculate logsumexp")
+    }
+
+    /// Calcula a norma euclidiana (L2) de todos os elementos
+    pub fn try_norm(&self) -> Result<f32> {
         match self {
-            UnifiedTensor::LibTorch(t) => t.argmin(),
-            UnifiedTensor::TensorFlow(_) => {
-                let data = self.as_slice();
-                let (min_idx, _) = data.iter()
-                    .enumerate()
-                    .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
-                    .unwrap();
-                min_idx as i32
-            }
+            UnifiedTensor::LibTorch(t) => t.try_norm(),
+            UnifiedTensor::TensorFlow(t) => t.try_norm(),
         }
     }
-}
 
-// Implementação de operadores aritméticos
-impl Add for UnifiedTensor {
-    type Output = UnifiedTensor;
+    /// Calcula a norma euclidiana (L2) de todos os elementos
+    pub fn norm(&self) -> f32 {
+        self.try_norm().expect("Failed to calculate norm")
+    }
+
+    /// Verifica se algum elemento é diferente de zero
+    pub fn try_any(&self) -> Result<bool> {
+        match self {
+            UnifiedTensor::LibTorch(t) => t.try_any(),
+            UnifiedTensor::TensorFlow(t) => t.try_any(),
+        }
+    }
 
-    fn add(self, other: UnifiedTensor) -> UnifiedTensor {
-        let (rows1, cols1) = self.shape();
-        let (rows2, cols2) = other.shape();
+    /// Verifica se algum elemento é diferente de zero
+    pub fn any(&self) -> bool {
+        self.try_any().expect("Failed to calculate any")
+    }
 
-        if rows1 != rows2 || cols1 != cols2 {
-            panic!("Cannot add tensors with different dimensions!");
+    /// Verifica se todos os elementos são diferentes de zero
+    pub fn try_all(&self) -> Result<bool> {
+        match self {
+            UnifiedTensor::LibTorch(t) => t.try_all(),
+            UnifiedTensor::TensorFlow(t) => t.try_all(),
         }
+    }
 
-        let result: Vec<f32> = self.as_slice()
-            .iter()
-            .zip(other.as_slice().iter())
-            .map(|(a, b)| a + b)
-            .collect();
+    /// Verifica se todos os elementos são diferentes de zero
+    pub fn all(&self) -> bool {
+        self.try_all().expect("Failed to calculate all")
+    }
+
+    // ==================== REDUCTIONS ALONG A DIMENSION ====================
 
-        UnifiedTensor::from_values(&result, rows1, cols1, self.backend(), Device::CPU)
+    /// Executa uma redução ao longo de `dim` no backend do tensor
+    fn try_reduce_dim(
+        &self,
+        dim: i64,
+        keepdim: bool,
+        torch_op: fn(&LibTorchTensor, i64, bool) -> Result<LibTorchTensor>,
+        tf_op: fn(&FlowTensors, i64, bool) -> Result<FlowTensors>,
+    ) -> Result<Self> {
+        match self {
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(torch_op(t, dim, keepdim)?)),
+            UnifiedTensor::TensorFlow(t) => Ok(UnifiedTensor::TensorFlow(tf_op(t, dim, keepdim)?)),
+        }
+    }
+
+    /// Soma ao longo de `dim`; com `keepdim` a dimensão reduzida fica com tamanho 1
+    pub fn try_sum_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_sum_dim, FlowTensors::try_sum_dim)
+    }
+
+    /// Soma ao longo de `dim`; com `keepdim` a dimensão reduzida fica com tamanho 1
+    pub fn sum_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_sum_dim(dim, keepdim).expect("Failed to calculate sum")
     }
-}
 
-impl Sub for UnifiedTensor {
-    type Output = UnifiedTensor;
+    /// Média ao longo de `dim`
+    pub fn try_mean_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_mean_dim, FlowTensors::try_mean_dim)
+    }
 
-    fn sub(self, other: UnifiedTensor) -> UnifiedTensor {
-        let (rows1, cols1) = self.shape();
-        let (rows2, cols2) = other.shape();
+    /// Média ao longo de `dim`
+    pub fn mean_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_mean_dim(dim, keepdim).expect("Failed to calculate mean")
+    }
+
+    /// Valor máximo ao longo de `dim`
+    pub fn try_max_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_max_dim, FlowTensors::try_max_dim)
+    }
+
+    /// Valor máximo ao longo de `dim`
+    pub fn max_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_max_dim(dim, keepdim).expect("Failed to calculate max")
+    }
+
+    /// Valor mínimo ao longo de `dim`
+    pub fn try_min_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_min_dim, FlowTensors::try_min_dim)
+    }
+
+    /// Valor mínimo ao longo de `dim`
+    pub fn min_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_min_dim(dim, keepdim).expect("Failed to calculate min")
+    }
+
+    /// Desvio padrão (amostral) ao longo de `dim`
+    pub fn try_std_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_std_dim, FlowTensors::try_std_dim)
+    }
+
+    /// Desvio padrão (amostral) ao longo de `dim`
+    pub fn std_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_std_dim(dim, keepdim).expect("Failed to calculate std")
+    }
+
+    /// Variância (amostral) ao longo de `dim`
+    pub fn try_var_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_var_dim, FlowTensors::try_var_dim)
+    }
 
-        if rows1 != rows2 || cols1 != cols2 {
-            panic!("Cannot subtract tensors with different dimensions!");
+    /// Variância (amostral) ao longo de `dim`
+    pub fn var_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_var_dim(dim, keepdim).expect("Failed to calculate var")
+    }
+
+    /// Índices (i64) do valor máximo ao longo de `dim`
+    pub fn try_argmax_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_argmax_dim, FlowTensors::try_argmax_dim)
+    }
+
+    /// Índices (i64) do valor máximo ao longo de `dim`
+    pub fn argmax_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_argmax_dim(dim, keepdim).expect("Failed to calculate argmax")
+    }
+
+    /// Índices (i64) do valor mínimo ao longo de `dim`
+    pub fn try_argmin_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_argmin_dim, FlowTensors::try_argmin_dim)
+    }
+
+    /// Índices (i64) do valor mínimo ao longo de `dim`
+    pub fn argmin_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_argmin_dim(dim, keepdim).expect("Failed to calculate argmin")
+    }
+
+    /// Produto ao longo de `dim`
+    pub fn try_prod_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_prod_dim, FlowTensors::try_prod_dim)
+    }
+
+    /// Produto ao longo de `dim`
+    pub fn prod_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_prod_dim(dim, keepdim).expect("Failed to calculate prod")
+    }
+
+    /// log(sum(exp(x))) ao longo de `dim`
+    pub fn try_logsumexp_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_logsumexp_dim, FlowTensors::try_logsumexp_dim)
+    }
+
+    /// log(sum(exp(x))) ao longo de `dim`
+    pub fn logsumexp_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_logsumexp_dim(dim, keepdim).expect("Failed to calculate logsumexp")
+    }
+
+    /// Norma euclidiana (L2) ao longo de `dim`
+    pub fn try_norm_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_norm_dim, FlowTensors::try_norm_dim)
+    }
+
+    /// Norma euclidiana (L2) ao longo de `dim`
+    pub fn norm_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_norm_dim(dim, keepdim).expect("Failed to calculate norm")
+    }
+
+    /// Tensor bool indicando se algum elemento ao longo de `dim` é diferente de zero
+    pub fn try_any_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_any_dim, FlowTensors::try_any_dim)
+    }
+
+    /// Tensor bool indicando se algum elemento ao longo de `dim` é diferente de zero
+    pub fn any_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_any_dim(dim, keepdim).expect("Failed to calculate any")
+    }
+
+    /// Tensor bool indicando se todos os elementos ao longo de `dim` são diferentes de zero
+    pub fn try_all_dim(&self, dim: i64, keepdim: bool) -> Result<Self> {
+        self.try_reduce_dim(dim, keepdim, LibTorchTensor::try_all_dim, FlowTensors::try_all_dim)
+    }
+
+    /// Tensor bool indicando se todos os elementos ao longo de `dim` são diferentes de zero
+    pub fn all_dim(&self, dim: i64, keepdim: bool) -> Self {
+        self.try_all_dim(dim, keepdim).expect("Failed to calculate all")
+    }
+
+    // ==================== VIEWS AND INDEXING ====================
+
+    /// Indica se os elementos estão contíguos (views do LibTorch podem não estar)
+    pub fn is_contiguous(&self) -> bool {
+        match self {
+            UnifiedTensor::LibTorch(t) => t.is_contiguous(),
+            UnifiedTensor::TensorFlow(_) => true,
         }
+    }
 
-        let result: Vec<f32> = self.as_slice()
-            .iter()
-            .zip(other.as_slice().iter())
-            .map(|(a, b)| a - b)
-            .collect();
+    /// Materializa uma view em um tensor contíguo
+    pub fn try_contiguous(&self) -> Result<Self> {
+        match self {
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_contiguous()?)),
+            UnifiedTensor::TensorFlow(_) => self.try_clone_tensor(),
+        }
+    }
 
-        UnifiedTensor::from_values(&result, rows1, cols1, self.backend(), Device::CPU)
+    /// Materializa uma view em um tensor contíguo
+    pub fn contiguous(&self) -> Self {
+        self.try_contiguous().expect("Failed to make tensor contiguous")
     }
-}
 
-impl Mul for UnifiedTensor {
-    type Output = UnifiedTensor;
+    /// `length` elementos de `dim` a partir de `start` (view no LibTorch, cópia no TensorFlow)
+    pub fn try_narrow(&self, dim: i64, start: i64, length: i64) -> Result<Self> {
+        match self {
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_narrow(dim, start, length)?)),
+            UnifiedTensor::TensorFlow(t) => Ok(UnifiedTensor::TensorFlow(t.try_narrow(dim, start, length)?)),
+        }
+    }
 
-    fn mul(self, other: UnifiedTensor) -> UnifiedTensor {
-        let (rows1, cols1) = self.shape();
-        let (rows2, cols2) = other.shape();
+    /// `length` elementos de `dim` a partir de `start` (view no LibTorch, cópia no TensorFlow)
+    pub fn narrow(&self, dim: i64, start: i64, length: i64) -> Self {
+        self.try_narrow(dim, start, length).expect("Failed to narrow tensor")
+    }
 
-        if rows1 != rows2 || cols1 != cols2 {
-            panic!("Cannot multiply tensors with different dimensions!");
+    /// Posição `index` de `dim`, que é removida da forma
+    pub fn try_select(&self, dim: i64, index: i64) -> Result<Self> {
+        match self {
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_select(dim, index)?)),
+            UnifiedTensor::TensorFlow(t) => Ok(UnifiedTensor::TensorFlow(t.try_select(dim, index)?)),
         }
+    }
 
-        let result: Vec<f32> = self.as_slice()
-            .iter()
-            .zip(other.as_slice().iter())
-            .map(|(a, b)| a * b)
-            .collect();
+    /// Posição `index` de `dim`, que é removida da forma
+    pub fn select(&self, dim: i64, index: i64) -> Self {
+        self.try_select(dim, index).expect("Failed to select from tensor")
+    }
 
-        UnifiedTensor::from_values(&result, rows1, cols1, self.backend(), Device::CPU)
+    /// `start..end` com passo `step` ao longo de `dim`; use `i64::MAX` como `end` para ir até o fim
+    pub fn try_slice(&self, dim: i64, start: i64, end: i64, step: i64) -> Result<Self> {
+        match self {
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(t.try_slice(dim, start, end, step)?)),
+            UnifiedTensor::TensorFlow(t) => Ok(UnifiedTensor::TensorFlow(t.try_slice(dim, start, end, step)?)),
+        }
+    }
+
+    /// `start..end` com passo `step` ao longo de `dim`; use `i64::MAX` como `end` para ir até o fim
+    pub fn slice(&self, dim: i64, start: i64, end: i64, step: i64) -> Self {
+        self.try_slice(dim, start, end, step).expect("Failed to slice tensor")
     }
-}
 
-impl Div for UnifiedTensor {
-    type Output = UnifiedTensor;
+    /// Copia as posições de `dim` listadas em `index`, convertendo-o para o backend de `self`
+    pub fn try_index_select(&self, dim: i64, index: &UnifiedTensor) -> Result<Self> {
+        self.try_binary(
+            index,
+            |t, i| t.try_index_select(dim, i),
+            |t, i| t.try_index_select(dim, i),
+        )
+    }
+
+    /// Copia as posições de `dim` listadas em `index`, convertendo-o para o backend de `self`
+    pub fn index_select(&self, dim: i64, index: &UnifiedTensor) -> Self {
+        self.try_index_select(dim, index).expect("Failed in index_select")
+    }
 
-    fn div(self, other: UnifiedTensor) -> UnifiedTensor {
-        let (rows1, cols1) = self.shape();
-        let (rows2, cols2) = other.shape();
+    /// Copia para um tensor 1-D os elementos em que `mask` (bool) é verdadeiro
+    pub fn try_masked_select(&self, mask: &UnifiedTensor) -> Result<Self> {
+        self.try_binary(mask, LibTorchTensor::try_masked_select, FlowTensors::try_masked_select)
+    }
 
-        if rows1 != rows2 || cols1 != cols2 {
-            panic!("Cannot divide tensors with different dimensions!");
+    /// Copia para um tensor 1-D os elementos em que `mask` (bool) é verdadeiro
+    pub fn masked_select(&self, mask: &UnifiedTensor) -> Self {
+        self.try_masked_select(mask).expect("Failed in masked_select")
+    }
+
+    /// Lê o elemento em `index` (uma posição por dimensão; negativos contam a partir do fim)
+    pub fn try_get<T: Element>(&self, index: &[i64]) -> Result<T> {
+        match self {
+            UnifiedTensor::LibTorch(t) => t.try_get(index),
+            UnifiedTensor::TensorFlow(t) => t.try_get(index),
         }
+    }
 
-        let result: Vec<f32> = self.as_slice()
-            .iter()
-            .zip(other.as_slice().iter())
-            .map(|(a, b)| {
-                if *b == 0.0 {
-                    panic!("Division by zero!");
-                }
-                a / b
-            })
-            .collect();
+    /// Lê o elemento em `index` (uma posição por dimensão; negativos contam a partir do fim)
+    pub fn get<T: Element>(&self, index: &[i64]) -> T {
+        self.try_get(index).expect("Invalid tensor index")
+    }
 
-        UnifiedTensor::from_values(&result, rows1, cols1, self.backend(), Device::CPU)
+    // ==================== ARITHMETIC ====================
+
+    /// Executa uma op binária no backend de `self`, convertendo `other` se necessário
+    fn try_binary(
+        &self,
+        other: &UnifiedTensor,
+        torch_op: impl Fn(&LibTorchTensor, &LibTorchTensor) -> Result<LibTorchTensor>,
+        tf_op: impl Fn(&FlowTensors, &FlowTensors) -> Result<FlowTensors>,
+    ) -> Result<Self> {
+        let converted;
+        let other = if other.backend() == self.backend() {
+            other
+        } else {
+            converted = other.try_to_backend(self.backend())?;
+            &converted
+        };
+
+        match (self, other) {
+            (UnifiedTensor::LibTorch(a), UnifiedTensor::LibTorch(b)) => Ok(UnifiedTensor::LibTorch(torch_op(a, b)?)),
+            (UnifiedTensor::TensorFlow(a), UnifiedTensor::TensorFlow(b)) => Ok(UnifiedTensor::TensorFlow(tf_op(a, b)?)),
+            _ => unreachable!("operandos convertidos para o mesmo backend"),
+        }
+    }
+
+    /// Executa uma op com operando escalar no backend do tensor
+    fn try_scalar_op(
+        &self,
+        value: f32,
+        torch_op: fn(&LibTorchTensor, f32) -> Result<LibTorchTensor>,
+        tf_op: fn(&FlowTensors, f32) -> Result<FlowTensors>,
+    ) -> Result<Self> {
+        match self {
+            UnifiedTensor::LibTorch(t) => Ok(UnifiedTensor::LibTorch(torch_op(t, value)?)),
+            UnifiedTensor::TensorFlow(t) => Ok(UnifiedTensor::TensorFlow(tf_op(t, value)?)),
+        }
+    }
+
+    /// Soma elemento a elemento, com broadcasting
+    pub fn try_add(&self, other: &UnifiedTensor) -> Result<Self> {
+        self.try_binary(other, LibTorchTensor::try_add, FlowTensors::try_add)
+    }
+
+    /// Subtração elemento a elemento, com broadcasting
+    pub fn try_sub(&self, other: &UnifiedTensor) -> Result<Self> {
+        self.try_binary(other, LibTorchTensor::try_sub, FlowTensors::try_sub)
+    }
+
+    /// Multiplicação elemento a elemento, com broadcasting
+    pub fn try_mul(&self, other: &UnifiedTensor) -> Result<Self> {
+        self.try_binary(other, LibTorchTensor::try_mul, FlowTensors::try_mul)
+    }
+
+    /// Divisão elemento a elemento, com broadcasting
+    pub fn try_div(&self, other: &UnifiedTensor) -> Result<Self> {
+        self.try_binary(other, LibTorchTensor::try_div, FlowTensors::try_div)
+    }
+
+    /// Produto de matrizes com as regras de `torch.matmul`
+    pub fn try_matmul(&self, other: &UnifiedTensor) -> Result<Self> {
+        self.try_binary(other, LibTorchTensor::try_matmul, FlowTensors::try_matmul)
+    }
+
+    /// Produto de matrizes com as regras de `torch.matmul`
+    pub fn matmul(&self, other: &UnifiedTensor) -> Self {
+        self.try_matmul(other).expect("Invalid dimensions for matrix multiplication")
+    }
+
+    /// Produto de matrizes em lote: `[b, n, k] x [b, k, m] -> [b, n, m]`
+    pub fn try_bmm(&self, other: &UnifiedTensor) -> Result<Self> {
+        self.try_binary(other, LibTorchTensor::try_bmm, FlowTensors::try_bmm)
+    }
+
+    /// Produto de matrizes em lote: `[b, n, k] x [b, k, m] -> [b, n, m]`
+    pub fn bmm(&self, other: &UnifiedTensor) -> Self {
+        self.try_bmm(other).expect("Invalid dimensions for batched matrix multiplication")
+    }
+
+    /// Soma um escalar a cada elemento
+    pub fn try_add_scalar(&self, value: f32) -> Result<Self> {
+        self.try_scalar_op(value, LibTorchTensor::try_add_scalar, FlowTensors::try_add_scalar)
+    }
+
+    /// Subtrai um escalar de cada elemento
+    pub fn try_sub_scalar(&self, value: f32) -> Result<Self> {
+        self.try_scalar_op(value, LibTorchTensor::try_sub_scalar, FlowTensors::try_sub_scalar)
+    }
+
+    /// Calcula `value - x` para cada elemento
+    pub fn try_rsub_scalar(&self, value: f32) -> Result<Self> {
+        self.try_scalar_op(value, LibTorchTensor::try_rsub_scalar, FlowTensors::try_rsub_scalar)
+    }
+
+    /// Multiplica cada elemento por um escalar
+    pub fn try_mul_scalar(&self, value: f32) -> Result<Self> {
+        self.try_scalar_op(value, LibTorchTensor::try_mul_scalar, FlowTensors::try_mul_scalar)
+    }
+
+    /// Divide cada elemento por um escalar
+    pub fn try_div_scalar(&self, value: f32) -> Result<Self> {
+        self.try_scalar_op(value, LibTorchTensor::try_div_scalar, FlowTensors::try_div_scalar)
+    }
+
+    /// Calcula `value / x` para cada elemento
+    pub fn try_rdiv_scalar(&self, value: f32) -> Result<Self> {
+        self.try_scalar_op(value, LibTorchTensor::try_rdiv_scalar, FlowTensors::try_rdiv_scalar)
+    }
+
+    // ==================== IN-PLACE ====================
+
+    /// Executa uma op no lugar no backend de `self`, convertendo `other` se necessário
+    fn try_in_place(
+        &mut self,
+        other: &UnifiedTensor,
+        torch_op: fn(&mut LibTorchTensor, &LibTorchTensor) -> Result<()>,
+        tf_op: fn(&mut FlowTensors, &FlowTensors) -> Result<()>,
+    ) -> Result<()> {
+        let converted;
+        let other = if other.backend() == self.backend() {
+            other
+        } else {
+            converted = other.try_to_backend(self.backend())?;
+            &converted
+        };
+
+        match (self, other) {
+            (UnifiedTensor::LibTorch(a), UnifiedTensor::LibTorch(b)) => torch_op(a, b),
+            (UnifiedTensor::TensorFlow(a), UnifiedTensor::TensorFlow(b)) => tf_op(a, b),
+            _ => unreachable!("operandos convertidos para o mesmo backend"),
+        }
+    }
+
+    /// Executa uma op com operando escalar no lugar
+    fn try_scalar_in_place(
+        &mut self,
+        value: f32,
+        torch_op: fn(&mut LibTorchTensor, f32) -> Result<()>,
+        tf_op: fn(&mut FlowTensors, f32) -> Result<()>,
+    ) -> Result<()> {
+        match self {
+            UnifiedTensor::LibTorch(t) => torch_op(t, value),
+            UnifiedTensor::TensorFlow(t) => tf_op(t, value),
+        }
+    }
+
+    /// Soma `other` a este tensor no lugar (`+=`)
+    pub fn try_add_in_place(&mut self, other: &UnifiedTensor) -> Result<()> {
+        self.try_in_place(other, LibTorchTensor::try_add_in_place, FlowTensors::try_add_in_place)
+    }
+
+    /// Subtrai `other` deste tensor no lugar (`-=`)
+    pub fn try_sub_in_place(&mut self, other: &UnifiedTensor) -> Result<()> {
+        self.try_in_place(other, LibTorchTensor::try_sub_in_place, FlowTensors::try_sub_in_place)
+    }
+
+    /// Multiplica este tensor por `other` no lugar (`*=`)
+    pub fn try_mul_in_place(&mut self, other: &UnifiedTensor) -> Result<()> {
+        self.try_in_place(other, LibTorchTensor::try_mul_in_place, FlowTensors::try_mul_in_place)
+    }
+
+    /// Divide este tensor por `other` no lugar (`/=`)
+    pub fn try_div_in_place(&mut self, other: &UnifiedTensor) -> Result<()> {
+        self.try_in_place(other, LibTorchTensor::try_div_in_place, FlowTensors::try_div_in_place)
+    }
+
+    /// Soma um escalar a cada elemento, no lugar
+    pub fn try_add_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        self.try_scalar_in_place(value, LibTorchTensor::try_add_scalar_in_place, FlowTensors::try_add_scalar_in_place)
+    }
+
+    /// Subtrai um escalar de cada elemento, no lugar
+    pub fn try_sub_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        self.try_scalar_in_place(value, LibTorchTensor::try_sub_scalar_in_place, FlowTensors::try_sub_scalar_in_place)
+    }
+
+    /// Multiplica cada elemento por um escalar, no lugar
+    pub fn try_mul_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        self.try_scalar_in_place(value, LibTorchTensor::try_mul_scalar_in_place, FlowTensors::try_mul_scalar_in_place)
+    }
+
+    /// Divide cada elemento por um escalar, no lugar
+    pub fn try_div_scalar_in_place(&mut self, value: f32) -> Result<()> {
+        self.try_scalar_in_place(value, LibTorchTensor::try_div_scalar_in_place, FlowTensors::try_div_scalar_in_place)
     }
 }
+
+// Implementação de operadores aritméticos
+impl_arithmetic_ops!(UnifiedTensor);